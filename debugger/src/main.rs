@@ -0,0 +1,250 @@
+//! Renders all characters in all sizes for debugging purposes.
+//!
+//! The specimens get shown in a window by default. With `--output` they get
+//! written to PNG files instead which also works on headless machines. The
+//! window requires the default feature `window` and SDL2. Building with
+//! `--no-default-features` leaves out both and keeps the PNG output.
+
+use clap::Parser;
+use embedded_graphics::{
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle},
+    text::{renderer::TextRenderer, Text, TextStyle},
+};
+#[cfg(feature = "window")]
+use embedded_graphics_simulator::Window;
+use embedded_graphics_simulator::{OutputSettings, OutputSettingsBuilder, SimulatorDisplay};
+use embedded_vintage_fonts::{
+    mapping::CP437_GRAPHICS,
+    proportional::{ProportionalFont, ProportionalTextStyle, ProportionalTextStyleBuilder},
+    registry::{Coverage, Font},
+    *,
+};
+use std::{error::Error, path::PathBuf};
+
+/// Simple "debugger" showing the fonts contained in this crate
+#[derive(Debug, Parser)]
+#[clap(about, version)]
+struct Args {
+    /// Enable striketrough style
+    #[clap(short, long)]
+    strikethrough: bool,
+
+    /// Enable underline style
+    #[clap(short, long)]
+    underline: bool,
+
+    /// UI scale factor
+    #[clap(long, default_value = "2")]
+    scale: u32,
+
+    /// Write the specimens as PNG files to this directory instead of showing
+    /// them in a window
+    #[clap(short, long)]
+    output: Option<PathBuf>,
+}
+
+impl Args {
+    fn mono_style<'a>(&self, font: &'a MonoFont<'a>) -> MonoTextStyle<'a, Rgb888> {
+        let mut character_style = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(Rgb888::WHITE);
+        if self.strikethrough {
+            character_style = character_style.strikethrough_with_color(Rgb888::CSS_TOMATO);
+        }
+        if self.underline {
+            character_style = character_style.underline_with_color(Rgb888::CSS_CORNFLOWER_BLUE);
+        }
+
+        character_style.build()
+    }
+
+    fn proportional_style<'a>(
+        &self,
+        font: &'a ProportionalFont<'a>,
+    ) -> ProportionalTextStyle<'a, Rgb888> {
+        let mut character_style = ProportionalTextStyleBuilder::new()
+            .font(font)
+            .text_color(Rgb888::WHITE);
+        if self.strikethrough {
+            character_style = character_style.strikethrough_with_color(Rgb888::CSS_TOMATO);
+        }
+        if self.underline {
+            character_style = character_style.underline_with_color(Rgb888::CSS_CORNFLOWER_BLUE);
+        }
+
+        character_style.build()
+    }
+}
+
+fn test_text(character_size: Size, cp437: bool) -> String {
+    let mut text = format!("Hello world! jpyJPY {}\n !\"#$%&'()*+,-./0123456789:;<=>?\n@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_\n`abcdefghijklmnopqrstuvwxyz{{|}}~\n\u{a0}¡¢£¤¥¦§¨©ª«¬­®¯°±²³´µ¶·¸¹º»¼½¾¿\nÀÁÂÃÄÅÆÇÈÉÊËÌÍÎÏÐÑÒÓÔÕÖ×ØÙÚÛÜÝÞß\nàáâãäåæçèéêëìíîïÐñòóôõö÷øùúûüýþÿ \u{ffff}", character_size);
+    if cp437 {
+        text.push('\n');
+        text.extend(CP437_GRAPHICS);
+    }
+
+    text
+}
+
+/// Draws the specimen for a font below `position` and returns the position
+/// below the specimen.
+fn draw_specimen<S>(
+    display: &mut SimulatorDisplay<Rgb888>,
+    mut position: Point,
+    character_style: S,
+    test_text: &str,
+    character_size: Size,
+) -> Result<Point, core::convert::Infallible>
+where
+    S: TextRenderer<Color = Rgb888>,
+{
+    position += Point::new(0, character_style.line_height() as i32);
+
+    // Draw the font baseline behind the first line of text
+    Line::new(
+        position.y_axis(),
+        position.y_axis() + display.bounding_box().size.x_axis(),
+    )
+    .into_styled(PrimitiveStyle::with_stroke(Rgb888::RED, 1))
+    .draw(display)?;
+
+    Text::with_text_style(test_text, position, character_style, TextStyle::default())
+        .draw(display)?;
+
+    Ok(position + character_size.y_axis() * test_text.lines().count() as u32)
+}
+
+/// Returns a display just large enough for the specimen of a single font.
+fn specimen_display<S>(
+    character_style: S,
+    test_text: &str,
+    character_size: Size,
+) -> Result<SimulatorDisplay<Rgb888>, core::convert::Infallible>
+where
+    S: TextRenderer<Color = Rgb888> + Copy,
+{
+    let margin = Point::new(10, 10);
+    let position = margin + Point::new(0, character_style.line_height() as i32);
+    let text = Text::with_text_style(test_text, position, character_style, TextStyle::default());
+    let bottom_right = text.bounding_box().bottom_right().unwrap_or(margin) + margin;
+
+    let mut display = SimulatorDisplay::new(Size::new(
+        bottom_right.x as u32 + 1,
+        bottom_right.y as u32 + 1,
+    ));
+    draw_specimen(
+        &mut display,
+        margin,
+        character_style,
+        test_text,
+        character_size,
+    )?;
+
+    Ok(display)
+}
+
+/// Returns the name of `font` in snake case like `font6x8_cp437`.
+fn file_stem(font: Font) -> String {
+    let mut stem = String::new();
+    for (i, c) in font.name().char_indices() {
+        if i > 0 && c.is_ascii_uppercase() {
+            stem.push('_');
+        }
+        stem.push(c.to_ascii_lowercase());
+    }
+
+    stem
+}
+
+fn save(
+    display: &SimulatorDisplay<Rgb888>,
+    output_settings: &OutputSettings,
+    path: PathBuf,
+) -> Result<(), Box<dyn Error>> {
+    display
+        .to_rgb_output_image(output_settings)
+        .save_png(&path)?;
+    println!("wrote {}", path.display());
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let mut display: SimulatorDisplay<Rgb888> = SimulatorDisplay::new(Size::new(900, 980));
+
+    // FONT_6X6 has variable-width glyphs and gets drawn separately below.
+    let fonts: Vec<_> = Font::iter()
+        .filter_map(|font| {
+            let cp437 = font.coverage() == Coverage::Cp437;
+            font.mono_font()
+                .map(|mono_font| (file_stem(font), mono_font, cp437))
+        })
+        .collect();
+
+    let mut position = Point::new(10, 10);
+
+    for (_, font, cp437) in fonts.iter() {
+        let test_text = test_text(font.character_size, *cp437);
+
+        position = draw_specimen(
+            &mut display,
+            position,
+            args.mono_style(font),
+            &test_text,
+            font.character_size,
+        )?;
+    }
+
+    // FONT_6X6 has variable-width glyphs and needs its own character style.
+    let test_text_6x6 = test_text(FONT_6X6.character_size, false);
+    draw_specimen(
+        &mut display,
+        position,
+        args.proportional_style(&FONT_6X6),
+        &test_text_6x6,
+        FONT_6X6.character_size,
+    )?;
+
+    let output_settings = OutputSettingsBuilder::new().scale(args.scale).build();
+
+    match &args.output {
+        Some(directory) => {
+            std::fs::create_dir_all(directory)?;
+            save(&display, &output_settings, directory.join("specimens.png"))?;
+
+            for (name, font, cp437) in fonts.iter() {
+                let test_text = test_text(font.character_size, *cp437);
+                let display =
+                    specimen_display(args.mono_style(font), &test_text, font.character_size)?;
+                save(
+                    &display,
+                    &output_settings,
+                    directory.join(format!("specimen_{}.png", name)),
+                )?;
+            }
+
+            let display = specimen_display(
+                args.proportional_style(&FONT_6X6),
+                &test_text_6x6,
+                FONT_6X6.character_size,
+            )?;
+            save(
+                &display,
+                &output_settings,
+                directory.join("specimen_font6x6.png"),
+            )?;
+        }
+        #[cfg(feature = "window")]
+        None => {
+            Window::new("Embedded Vintag Fonts Debugger", &output_settings).show_static(&display)
+        }
+        #[cfg(not(feature = "window"))]
+        None => return Err("showing a window requires the feature window, use --output".into()),
+    }
+
+    Ok(())
+}
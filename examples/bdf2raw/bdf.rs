@@ -0,0 +1,280 @@
+//! A parser for fonts in the [Glyph Bitmap Distribution
+//! Format](https://en.wikipedia.org/wiki/Glyph_Bitmap_Distribution_Format)
+//! (BDF) version 2.1.
+//!
+//! Only the parts relevant for bitmap fonts get parsed. Metrics for vertical
+//! writing and scalable widths get ignored.
+
+use std::{collections::HashMap, error::Error, fmt, str::FromStr};
+
+/// An error from parsing a BDF font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line in which the error occurred, starting from 1.
+    pub line: usize,
+
+    /// A description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// The size and offset of a bitmap relative to the origin of a glyph.
+///
+/// The origin is the leftmost pixel on the baseline. Positive offsets point to
+/// the right and up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+}
+
+/// A single glyph of a BDF font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    /// The name from `STARTCHAR`.
+    pub name: String,
+
+    /// The code point from `ENCODING` or `None` for unencoded glyphs.
+    pub encoding: Option<u32>,
+
+    /// The horizontal advance from `DWIDTH`.
+    pub device_width: Option<i32>,
+
+    /// The bounding box of the bitmap from `BBX`.
+    pub bounding_box: BoundingBox,
+
+    /// The rows of the bitmap from top to bottom as bytes with the leftmost
+    /// pixel at the most significant bit.
+    pub bitmap: Vec<Vec<u8>>,
+}
+
+impl Glyph {
+    /// Returns `true` if the pixel at column `x` and row `y` of the bitmap is
+    /// set.
+    pub fn is_set(&self, x: u32, y: u32) -> bool {
+        self.bitmap
+            .get(y as usize)
+            .and_then(|row| row.get(x as usize / 8))
+            .is_some_and(|byte| byte & (0x80 >> (x % 8)) != 0)
+    }
+
+    /// Returns an iterator over the set pixels relative to the origin of the
+    /// glyph with the y axis pointing down.
+    pub fn pixels(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        let bbx = self.bounding_box;
+        let top = -(bbx.y + bbx.height as i32);
+
+        (0..bbx.height).flat_map(move |y| {
+            (0..bbx.width)
+                .filter(move |&x| self.is_set(x, y))
+                .map(move |x| (bbx.x + x as i32, top + y as i32))
+        })
+    }
+}
+
+/// A BDF font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Font {
+    /// The name from `FONT`, usually an X logical font description.
+    pub name: String,
+
+    /// The bounding box enclosing all glyphs from `FONTBOUNDINGBOX`.
+    pub bounding_box: BoundingBox,
+
+    /// The properties between `STARTPROPERTIES` and `ENDPROPERTIES` with
+    /// quotes removed from string values.
+    pub properties: HashMap<String, String>,
+
+    /// The glyphs in the order of the file.
+    pub glyphs: Vec<Glyph>,
+}
+
+impl Font {
+    /// Returns the value of the integer property `name`.
+    pub fn integer_property(&self, name: &str) -> Option<i32> {
+        self.properties.get(name)?.parse().ok()
+    }
+
+    /// Returns the distance from the baseline to the top of the font.
+    pub fn ascent(&self) -> i32 {
+        self.integer_property("FONT_ASCENT")
+            .unwrap_or(self.bounding_box.height as i32 + self.bounding_box.y)
+    }
+
+    /// Returns the distance from the baseline to the bottom of the font.
+    pub fn descent(&self) -> i32 {
+        self.integer_property("FONT_DESCENT")
+            .unwrap_or(-self.bounding_box.y)
+    }
+}
+
+impl FromStr for Font {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).font()
+    }
+}
+
+/// Line based parser state.
+struct Parser<'a> {
+    lines: std::iter::Enumerate<std::str::Lines<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            lines: s.lines().enumerate(),
+            line: 0,
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the next line which is neither empty nor a comment, split into
+    /// the keyword and the rest.
+    fn next(&mut self) -> Result<(&'a str, &'a str), ParseError> {
+        for (i, line) in self.lines.by_ref() {
+            self.line = i + 1;
+
+            let line = line.trim();
+            let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            if !keyword.is_empty() && keyword != "COMMENT" {
+                return Ok((keyword, rest.trim()));
+            }
+        }
+
+        Err(self.error("unexpected end of file"))
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        match self.next()? {
+            (keyword, rest) if keyword == expected => Ok(rest),
+            (keyword, _) => Err(self.error(format!("expected {}, found {}", expected, keyword))),
+        }
+    }
+
+    fn numbers<T: FromStr>(&self, s: &str, count: usize) -> Result<Vec<T>, ParseError> {
+        let numbers = s
+            .split_whitespace()
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| self.error(format!("invalid number {}", n)))
+            })
+            .collect::<Result<Vec<T>, _>>()?;
+
+        if numbers.len() < count {
+            return Err(self.error(format!("expected {} numbers", count)));
+        }
+
+        Ok(numbers)
+    }
+
+    fn bounding_box(&self, s: &str) -> Result<BoundingBox, ParseError> {
+        let n = self.numbers::<i32>(s, 4)?;
+        if n[0] < 0 || n[1] < 0 {
+            return Err(self.error("negative bounding box size"));
+        }
+
+        Ok(BoundingBox {
+            width: n[0] as u32,
+            height: n[1] as u32,
+            x: n[2],
+            y: n[3],
+        })
+    }
+
+    fn font(&mut self) -> Result<Font, ParseError> {
+        self.expect("STARTFONT")?;
+
+        let mut font = Font {
+            name: String::new(),
+            bounding_box: BoundingBox::default(),
+            properties: HashMap::new(),
+            glyphs: Vec::new(),
+        };
+
+        loop {
+            match self.next()? {
+                ("FONT", name) => font.name = name.to_string(),
+                ("FONTBOUNDINGBOX", rest) => font.bounding_box = self.bounding_box(rest)?,
+                ("STARTPROPERTIES", _) => loop {
+                    match self.next()? {
+                        ("ENDPROPERTIES", _) => break,
+                        (name, value) => {
+                            let value = value
+                                .strip_prefix('"')
+                                .and_then(|v| v.strip_suffix('"'))
+                                .map(|v| v.replace("\"\"", "\""))
+                                .unwrap_or_else(|| value.to_string());
+                            font.properties.insert(name.to_string(), value);
+                        }
+                    }
+                },
+                ("STARTCHAR", name) => {
+                    let glyph = self.glyph(name, font.bounding_box)?;
+                    font.glyphs.push(glyph);
+                }
+                ("ENDFONT", _) => return Ok(font),
+                // SIZE, CHARS, METRICSSET and the global metrics are not
+                // needed for drawing the glyphs.
+                _ => {}
+            }
+        }
+    }
+
+    fn glyph(&mut self, name: &str, font_bbx: BoundingBox) -> Result<Glyph, ParseError> {
+        let mut glyph = Glyph {
+            name: name.to_string(),
+            encoding: None,
+            device_width: None,
+            bounding_box: font_bbx,
+            bitmap: Vec::new(),
+        };
+
+        loop {
+            match self.next()? {
+                ("ENCODING", rest) => {
+                    // Glyphs outside the encoding of the font have -1 and
+                    // possibly an index from another encoding.
+                    let code = self.numbers::<i64>(rest, 1)?[0];
+                    glyph.encoding = u32::try_from(code).ok();
+                }
+                ("DWIDTH", rest) => glyph.device_width = Some(self.numbers(rest, 1)?[0]),
+                ("BBX", rest) => glyph.bounding_box = self.bounding_box(rest)?,
+                ("BITMAP", _) => {
+                    let bytes = glyph.bounding_box.width.div_ceil(8) as usize;
+
+                    for _ in 0..glyph.bounding_box.height {
+                        let (hex, _) = self.next()?;
+                        let row = (0..bytes)
+                            .map(|i| {
+                                hex.get(2 * i..2 * i + 2)
+                                    .map_or(Some(0), |h| u8::from_str_radix(h, 16).ok())
+                            })
+                            .collect::<Option<Vec<u8>>>()
+                            .ok_or_else(|| self.error(format!("invalid bitmap row {}", hex)))?;
+                        glyph.bitmap.push(row);
+                    }
+                }
+                ("ENDCHAR", _) => return Ok(glyph),
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Conversion from BDF fonts to the glyph sheets and metrics of `MonoFont`.
+
+use crate::bdf;
+use std::fmt;
+
+/// A glyph which does not fit the monospace cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Misfit {
+    /// The character of the glyph.
+    pub c: char,
+
+    /// The name of the glyph from the BDF font.
+    pub name: String,
+
+    /// Why the glyph does not fit.
+    pub reason: String,
+}
+
+impl fmt::Display for Misfit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} ({}): {}", self.c, self.name, self.reason)
+    }
+}
+
+/// A BDF font converted to the data of a `MonoFont`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Converted {
+    /// The 1 bpp glyph sheet in the layout expected by `ImageRaw::new`.
+    pub raw: Vec<u8>,
+
+    /// The width of the glyph sheet in pixels.
+    pub image_width: u32,
+
+    /// The width and height of a character cell.
+    pub character_size: (u32, u32),
+
+    /// The row of the baseline from the top of the cell.
+    pub baseline: u32,
+
+    /// The offset and height of the strikethrough.
+    pub strikethrough: (u32, u32),
+
+    /// The offset and height of the underline.
+    pub underline: (u32, u32),
+
+    /// The characters of the glyph sheet in the format of `StrGlyphMapping`.
+    pub mapping: String,
+
+    /// The glyph index for unsupported characters.
+    pub replacement: usize,
+
+    /// The glyphs which got clipped or have a different advance than the
+    /// cell.
+    pub misfits: Vec<Misfit>,
+}
+
+impl Converted {
+    /// Returns the height of the glyph sheet in pixels.
+    pub fn image_height(&self) -> u32 {
+        let row_bytes = self.image_width.div_ceil(8) as usize;
+
+        (self.raw.len() / row_bytes) as u32
+    }
+
+    /// Returns the Rust source for a `MonoFont` constant named `name` which
+    /// includes the glyph sheet from `raw_path`.
+    pub fn rust_constant(&self, name: &str, raw_path: &str) -> String {
+        let (width, height) = self.character_size;
+
+        format!(
+            "pub const {name}: MonoFont = MonoFont {{
+    image: ImageRaw::new(include_bytes!({raw_path:?}), {image_width}),
+    character_size: Size::new({width}, {height}),
+    character_spacing: 0,
+    baseline: {baseline},
+    strikethrough: DecorationDimensions::new({}, {}),
+    underline: DecorationDimensions::new({}, {}),
+    glyph_mapping: &StrGlyphMapping::new({mapping:?}, {replacement}),
+}};
+",
+            self.strikethrough.0,
+            self.strikethrough.1,
+            self.underline.0,
+            self.underline.1,
+            image_width = self.image_width,
+            baseline = self.baseline,
+            mapping = self.mapping,
+            replacement = self.replacement,
+        )
+    }
+}
+
+/// Returns the `StrGlyphMapping` string for the sorted `chars`.
+///
+/// Runs of three and more consecutive characters become ranges. The null
+/// character always gets written as range as it starts ranges otherwise.
+pub fn mapping_string(chars: &[char]) -> String {
+    let mut mapping = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = chars[i];
+        let mut end = i;
+        while end + 1 < chars.len() && chars[end + 1] as u32 == chars[end] as u32 + 1 {
+            end += 1;
+        }
+
+        if end - i >= 2 || start == '\0' {
+            mapping.push('\0');
+            mapping.push(start);
+            mapping.push(chars[end]);
+            i = end + 1;
+        } else {
+            mapping.push(start);
+            i += 1;
+        }
+    }
+
+    mapping
+}
+
+/// Converts `font` to a glyph sheet with `columns` glyphs per row.
+///
+/// The cell width is the most common advance of the glyphs and the cell
+/// height the sum of ascent and descent of the font. Glyphs without encoding
+/// get skipped. Decorations come from the properties `UNDERLINE_POSITION`,
+/// `UNDERLINE_THICKNESS`, `STRIKEOUT_ASCENT` and `STRIKEOUT_DESCENT` if
+/// present and get derived from the cell otherwise.
+pub fn convert(font: &bdf::Font, columns: u32) -> Result<Converted, String> {
+    let mut glyphs: Vec<(char, &bdf::Glyph)> = font
+        .glyphs
+        .iter()
+        .filter_map(|g| Some((char::from_u32(g.encoding?)?, g)))
+        .collect();
+    glyphs.sort_by_key(|(c, _)| *c);
+    glyphs.dedup_by_key(|(c, _)| *c);
+
+    if glyphs.is_empty() {
+        return Err("the font contains no encoded glyphs".to_string());
+    }
+
+    let ascent = font.ascent();
+    let descent = font.descent();
+    if ascent <= 0 || ascent + descent <= 0 {
+        return Err(format!("invalid ascent {} and descent {}", ascent, descent));
+    }
+
+    let width = cell_width(font, &glyphs);
+    let height = (ascent + descent) as u32;
+    let baseline = ascent as u32 - 1;
+
+    let underline = match (
+        font.integer_property("UNDERLINE_POSITION"),
+        font.integer_property("UNDERLINE_THICKNESS"),
+    ) {
+        (Some(position), thickness) => (
+            (ascent + position.max(0)) as u32,
+            thickness.unwrap_or(1).max(1) as u32,
+        ),
+        (None, _) => (baseline + 2, (height / 8).max(1)),
+    };
+    let strikethrough = match (
+        font.integer_property("STRIKEOUT_ASCENT"),
+        font.integer_property("STRIKEOUT_DESCENT"),
+    ) {
+        (Some(up), Some(down)) if up + down > 0 => {
+            ((ascent - up).max(0) as u32, (up + down) as u32)
+        }
+        _ => (ascent as u32 / 2, (height / 8).max(1)),
+    };
+
+    let columns = columns.max(1);
+    let rows = (glyphs.len() as u32).div_ceil(columns);
+    let image_width = columns * width;
+    let row_bytes = image_width.div_ceil(8) as usize;
+    let mut raw = vec![0; row_bytes * (rows * height) as usize];
+    let mut misfits = Vec::new();
+
+    for (index, (c, glyph)) in glyphs.iter().enumerate() {
+        let cell_x = (index as u32 % columns * width) as i32;
+        let cell_y = (index as u32 / columns * height) as i32;
+        let mut clipped = false;
+
+        for (x, y) in glyph.pixels() {
+            let y = y + ascent;
+            if x < 0 || x >= width as i32 || y < 0 || y >= height as i32 {
+                clipped = true;
+                continue;
+            }
+
+            let x = (cell_x + x) as usize;
+            let y = (cell_y + y) as usize;
+            raw[y * row_bytes + x / 8] |= 0x80 >> (x % 8);
+        }
+
+        let mut reasons = Vec::new();
+        if clipped {
+            reasons.push("pixels outside of the cell got clipped".to_string());
+        }
+        if let Some(advance) = glyph.device_width.filter(|&a| a != width as i32) {
+            reasons.push(format!("advance {} instead of {}", advance, width));
+        }
+        if !reasons.is_empty() {
+            misfits.push(Misfit {
+                c: *c,
+                name: glyph.name.clone(),
+                reason: reasons.join(", "),
+            });
+        }
+    }
+
+    let chars: Vec<char> = glyphs.iter().map(|(c, _)| *c).collect();
+    let replacement = font
+        .integer_property("DEFAULT_CHAR")
+        .and_then(|d| char::from_u32(d as u32))
+        .into_iter()
+        .chain(['?', ' '])
+        .find_map(|c| chars.binary_search(&c).ok())
+        .unwrap_or(0);
+
+    Ok(Converted {
+        raw,
+        image_width,
+        character_size: (width, height),
+        baseline,
+        strikethrough,
+        underline,
+        mapping: mapping_string(&chars),
+        replacement,
+        misfits,
+    })
+}
+
+/// Returns the most common advance of the glyphs or the width of the font
+/// bounding box if there are no advances.
+fn cell_width(font: &bdf::Font, glyphs: &[(char, &bdf::Glyph)]) -> u32 {
+    let mut advances: Vec<i32> = glyphs
+        .iter()
+        .filter_map(|(_, g)| g.device_width)
+        .filter(|&a| a > 0)
+        .collect();
+    advances.sort_unstable();
+
+    advances
+        .chunk_by(|a, b| a == b)
+        .max_by_key(|run| run.len())
+        .map_or(font.bounding_box.width, |run| run[0] as u32)
+}
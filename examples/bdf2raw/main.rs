@@ -0,0 +1,90 @@
+//! Converts a BDF font to a glyph sheet and prints a matching `MonoFont`.
+//!
+//! This writes the glyph sheet as PNG and as raw 1 bpp data like the ones in
+//! `data/` and prints the Rust source for a `MonoFont` constant to stdout.
+//! Glyphs which do not fit the monospace cell get reported to stderr.
+//!
+//! ```text
+//! $ cargo run --example bdf2raw -- --name FONT_5X7 font5x7.bdf data/font5x7
+//! ```
+
+use clap::Parser;
+use std::{error::Error, fs::File, io::BufWriter, path::PathBuf};
+
+mod bdf;
+mod convert;
+
+/// Convert a BDF font to a glyph sheet for a MonoFont
+#[derive(Debug, Parser)]
+#[clap(about, version)]
+struct Args {
+    /// Name of the MonoFont constant
+    #[clap(short, long, default_value = "FONT")]
+    name: String,
+
+    /// Number of glyphs per row of the glyph sheet
+    #[clap(short, long, default_value = "16")]
+    columns: u32,
+
+    /// The BDF font to read
+    input: PathBuf,
+
+    /// Path of the glyph sheet to write without extension, `.png` and
+    /// `_1bpp.raw` get appended
+    output: PathBuf,
+}
+
+fn write_png(path: &PathBuf, converted: &convert::Converted) -> Result<(), Box<dyn Error>> {
+    let width = converted.image_width;
+    let height = converted.image_height();
+    let row_bytes = width.div_ceil(8) as usize;
+
+    let pixels: Vec<u8> = converted
+        .raw
+        .chunks(row_bytes)
+        .flat_map(|row| {
+            (0..width as usize).map(move |x| {
+                if row[x / 8] & (0x80 >> (x % 8)) != 0 {
+                    0xff
+                } else {
+                    0x00
+                }
+            })
+        })
+        .collect();
+
+    let mut encoder = png::Encoder::new(BufWriter::new(File::create(path)?), width, height);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    let font: bdf::Font = std::fs::read_to_string(&args.input)?.parse()?;
+    let converted = convert::convert(&font, args.columns)?;
+
+    let mut png_path = args.output.clone().into_os_string();
+    png_path.push(".png");
+    let mut raw_path = args.output.into_os_string();
+    raw_path.push("_1bpp.raw");
+    let raw_path = PathBuf::from(raw_path);
+
+    write_png(&PathBuf::from(png_path), &converted)?;
+    std::fs::write(&raw_path, &converted.raw)?;
+
+    for misfit in &converted.misfits {
+        eprintln!("warning: {}", misfit);
+    }
+
+    let file_name = raw_path.file_name().unwrap_or_default().to_string_lossy();
+    print!(
+        "{}",
+        converted.rust_constant(&args.name, &format!("../data/{}", file_name))
+    );
+
+    Ok(())
+}
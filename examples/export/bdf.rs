@@ -0,0 +1,97 @@
+//! Export of `MonoFont`s as BDF fonts.
+
+use crate::glyphs;
+use embedded_graphics::mono_font::MonoFont;
+use std::fmt::Write;
+
+/// Returns `font` as BDF font with Unicode encoding.
+///
+/// The ascent of the font ends at the baseline and the descent takes the rows
+/// below it. The decorations get stored in the properties
+/// `UNDERLINE_POSITION`, `UNDERLINE_THICKNESS`, `STRIKEOUT_ASCENT` and
+/// `STRIKEOUT_DESCENT`.
+pub fn to_bdf(font: &MonoFont, family: &str) -> String {
+    let width = font.character_size.width;
+    let height = font.character_size.height;
+    let ascent = font.baseline as i32 + 1;
+    let descent = height as i32 - ascent;
+    let chars = glyphs::characters(font);
+
+    let properties = [
+        ("FAMILY_NAME", format!("{:?}", family)),
+        ("WEIGHT_NAME", "\"Medium\"".to_string()),
+        ("SLANT", "\"R\"".to_string()),
+        ("SPACING", "\"C\"".to_string()),
+        ("PIXEL_SIZE", height.to_string()),
+        ("AVERAGE_WIDTH", (width * 10).to_string()),
+        ("CHARSET_REGISTRY", "\"ISO10646\"".to_string()),
+        ("CHARSET_ENCODING", "\"1\"".to_string()),
+        ("FONT_ASCENT", ascent.to_string()),
+        ("FONT_DESCENT", descent.to_string()),
+        ("DEFAULT_CHAR", ('?' as u32).to_string()),
+        (
+            "UNDERLINE_POSITION",
+            (font.underline.offset as i32 - ascent).to_string(),
+        ),
+        ("UNDERLINE_THICKNESS", font.underline.height.to_string()),
+        (
+            "STRIKEOUT_ASCENT",
+            (ascent - font.strikethrough.offset as i32).to_string(),
+        ),
+        (
+            "STRIKEOUT_DESCENT",
+            (font.strikethrough.offset as i32 + font.strikethrough.height as i32 - ascent)
+                .to_string(),
+        ),
+    ];
+
+    glyphs::write_string(|bdf| write_bdf(bdf, font, family, &properties, &chars, descent))
+}
+
+fn write_bdf(
+    bdf: &mut String,
+    font: &MonoFont,
+    family: &str,
+    properties: &[(&str, String)],
+    chars: &[char],
+    descent: i32,
+) -> std::fmt::Result {
+    let width = font.character_size.width;
+    let height = font.character_size.height;
+
+    writeln!(bdf, "STARTFONT 2.1")?;
+    writeln!(
+        bdf,
+        "FONT -vintage-{}-medium-r-normal--{}-{}-75-75-c-{}-iso10646-1",
+        family,
+        height,
+        height * 10,
+        width * 10
+    )?;
+    writeln!(bdf, "SIZE {} 75 75", height)?;
+    writeln!(bdf, "FONTBOUNDINGBOX {} {} 0 {}", width, height, -descent)?;
+
+    writeln!(bdf, "STARTPROPERTIES {}", properties.len())?;
+    for (name, value) in properties {
+        writeln!(bdf, "{} {}", name, value)?;
+    }
+    writeln!(bdf, "ENDPROPERTIES")?;
+
+    writeln!(bdf, "CHARS {}", chars.len())?;
+    for &c in chars {
+        writeln!(bdf, "STARTCHAR uni{:04X}", c as u32)?;
+        writeln!(bdf, "ENCODING {}", c as u32)?;
+        writeln!(bdf, "SWIDTH {} 0", width * 1000 / height)?;
+        writeln!(bdf, "DWIDTH {} 0", width)?;
+        writeln!(bdf, "BBX {} {} 0 {}", width, height, -descent)?;
+        writeln!(bdf, "BITMAP")?;
+        for row in glyphs::glyph_bytes(font, c) {
+            for byte in row {
+                write!(bdf, "{:02X}", byte)?;
+            }
+            writeln!(bdf)?;
+        }
+        writeln!(bdf, "ENDCHAR")?;
+    }
+    writeln!(bdf, "ENDFONT")
+}
@@ -0,0 +1,182 @@
+//! Export of `MonoFont`s as `GFXfont` for the Adafruit GFX library.
+//!
+//! Adafruit GFX addresses glyphs by single bytes. The characters for the
+//! bytes are given by an [`Encoding`], code page 437 for the fonts with the
+//! box-drawing characters and Latin-1 otherwise. Bytes without a glyph in the
+//! font get the glyph for '?' like when drawing text with embedded-graphics.
+//!
+//! Adafruit GFX draws text with its cursor on the baseline. Text drawn at the
+//! cursor `(x, y)` looks exactly like text drawn by embedded-graphics at
+//! `Point::new(x, y)` with `Baseline::Alphabetic`.
+
+use crate::glyphs;
+use embedded_graphics::{
+    mono_font::MonoFont,
+    primitives::{PointsIter, Rectangle},
+};
+use embedded_vintage_fonts::{byte_text::Encoding, glyph::Glyph};
+use std::fmt::Write;
+
+/// A glyph from the `GFXglyph` array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GfxGlyph {
+    pub bitmap_offset: u16,
+    pub width: u8,
+    pub height: u8,
+    pub x_advance: u8,
+    pub x_offset: i8,
+    pub y_offset: i8,
+}
+
+/// The data of a `GFXfont`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GfxFont {
+    /// The bitmaps of all glyphs.
+    pub bitmap: Vec<u8>,
+
+    /// The glyphs for the bytes from `first` to `last`.
+    pub glyphs: Vec<GfxGlyph>,
+
+    /// The first byte with a glyph.
+    pub first: u8,
+
+    /// The last byte with a glyph.
+    pub last: u8,
+
+    /// The distance between lines.
+    pub y_advance: u8,
+
+    /// The characters of the glyphs.
+    pub chars: Vec<char>,
+}
+
+/// Converts `font` with the characters from `encoding` to a `GFXfont`.
+///
+/// The bitmaps get cropped to their set pixels. Their rows are packed without
+/// padding with the leftmost pixel at the most significant bit, and every
+/// glyph starts at a byte boundary.
+pub fn to_gfx(font: &MonoFont, encoding: Encoding) -> Result<GfxFont, String> {
+    let supported = glyphs::characters(font);
+    let bytes: Vec<u8> = (0x20..=0xff)
+        .filter(|&b| supported.contains(&encoding.decode(b)))
+        .collect();
+    let (first, last) = match (bytes.first(), bytes.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Err("the font has no glyphs for the encoding".to_string()),
+    };
+
+    let x_advance = u8::try_from(font.character_size.width).map_err(|e| e.to_string())?;
+    let y_advance = u8::try_from(font.character_size.height).map_err(|e| e.to_string())?;
+
+    let mut bitmap = Vec::new();
+    let mut chars = Vec::new();
+    let mut gfx_glyphs = Vec::new();
+    for c in (first..=last).map(|b| encoding.decode(b)) {
+        let c = if supported.contains(&c) { c } else { '?' };
+
+        // Characters sharing a glyph also share its bitmap.
+        if let Some(i) = chars.iter().position(|&other| other == c) {
+            chars.push(c);
+            gfx_glyphs.push(gfx_glyphs[i]);
+            continue;
+        }
+
+        let bitmap_offset = u16::try_from(bitmap.len()).map_err(|e| e.to_string())?;
+        let glyph = match glyphs::bounding_box(font, c) {
+            Some(area) => {
+                pack(&mut bitmap, &Glyph::new(font, c), area);
+                GfxGlyph {
+                    bitmap_offset,
+                    width: area.size.width as u8,
+                    height: area.size.height as u8,
+                    x_advance,
+                    x_offset: area.top_left.x as i8,
+                    y_offset: (area.top_left.y - font.baseline as i32) as i8,
+                }
+            }
+            None => GfxGlyph {
+                bitmap_offset,
+                width: 0,
+                height: 0,
+                x_advance,
+                x_offset: 0,
+                y_offset: 0,
+            },
+        };
+
+        chars.push(c);
+        gfx_glyphs.push(glyph);
+    }
+
+    Ok(GfxFont {
+        bitmap,
+        glyphs: gfx_glyphs,
+        first,
+        last,
+        y_advance,
+        chars,
+    })
+}
+
+/// Appends the pixels of `glyph` within `area` to `bitmap`.
+fn pack(bitmap: &mut Vec<u8>, glyph: &Glyph, area: Rectangle) {
+    for (bit, point) in area.points().enumerate() {
+        if bit % 8 == 0 {
+            bitmap.push(0);
+        }
+        if glyph.is_set(point) {
+            *bitmap.last_mut().unwrap() |= 0x80 >> (bit % 8);
+        }
+    }
+}
+
+impl GfxFont {
+    /// Returns the C header defining the `GFXfont` `name`.
+    pub fn to_c_header(&self, name: &str) -> String {
+        glyphs::write_string(|header| self.write_c_header(header, name))
+    }
+
+    fn write_c_header(&self, header: &mut String, name: &str) -> std::fmt::Result {
+        writeln!(header, "// {} from embedded-vintage-fonts", name)?;
+        writeln!(header)?;
+        writeln!(header, "#pragma once")?;
+        writeln!(header)?;
+        writeln!(header, "#include <Adafruit_GFX.h>")?;
+        writeln!(header)?;
+
+        writeln!(header, "const uint8_t {}Bitmaps[] PROGMEM = {{", name)?;
+        for line in self.bitmap.chunks(12) {
+            let bytes: Vec<String> = line.iter().map(|b| format!("0x{:02X}", b)).collect();
+            writeln!(header, "  {},", bytes.join(", "))?;
+        }
+        writeln!(header, "}};")?;
+        writeln!(header)?;
+
+        writeln!(header, "const GFXglyph {}Glyphs[] PROGMEM = {{", name)?;
+        for (i, (glyph, c)) in self.glyphs.iter().zip(&self.chars).enumerate() {
+            writeln!(
+                header,
+                "  {{ {:5}, {:3}, {:3}, {:3}, {:4}, {:4} }}, // 0x{:02X} {:?}",
+                glyph.bitmap_offset,
+                glyph.width,
+                glyph.height,
+                glyph.x_advance,
+                glyph.x_offset,
+                glyph.y_offset,
+                self.first as usize + i,
+                c
+            )?;
+        }
+        writeln!(header, "}};")?;
+        writeln!(header)?;
+
+        writeln!(header, "const GFXfont {} PROGMEM = {{", name)?;
+        writeln!(header, "  (uint8_t  *){}Bitmaps,", name)?;
+        writeln!(header, "  (GFXglyph *){}Glyphs,", name)?;
+        writeln!(
+            header,
+            "  0x{:02X}, 0x{:02X}, {} }};",
+            self.first, self.last, self.y_advance
+        )
+    }
+}
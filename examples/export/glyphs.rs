@@ -0,0 +1,61 @@
+//! Access to the characters and glyph bitmaps of a `MonoFont` for the
+//! exporters.
+
+use embedded_graphics::{mono_font::MonoFont, primitives::Rectangle};
+use embedded_vintage_fonts::glyph::Glyph;
+use std::fmt;
+
+/// Returns the characters from the Basic Multilingual Plane which have a
+/// glyph of their own in `font`, in the order of their code points.
+///
+/// Like [`FallbackTextStyle`](embedded_vintage_fonts::fallback), this treats
+/// all characters drawn with the glyph for '?' except '?' itself as
+/// unsupported.
+pub fn characters(font: &MonoFont) -> Vec<char> {
+    let replacement = font.glyph_mapping.index('?');
+
+    (0..=0xffff)
+        .filter_map(char::from_u32)
+        .filter(|&c| c == '?' || font.glyph_mapping.index(c) != replacement)
+        .collect()
+}
+
+/// Returns the number of bytes per row of a glyph of `font`.
+pub fn row_bytes(font: &MonoFont) -> usize {
+    font.character_size.width.div_ceil(8) as usize
+}
+
+/// Returns the rows of the glyph for `c` from top to bottom as bytes with the
+/// leftmost pixel at the most significant bit and each row padded to full
+/// bytes.
+pub fn glyph_bytes(font: &MonoFont, c: char) -> Vec<Vec<u8>> {
+    let bytes = row_bytes(font);
+    let padding = bytes as u32 * 8 - font.character_size.width;
+
+    Glyph::new(font, c)
+        .rows()
+        .map(|row| (row << padding).to_be_bytes()[4 - bytes..].to_vec())
+        .collect()
+}
+
+/// Returns the smallest area of the glyph for `c` containing all set pixels
+/// or `None` for empty glyphs.
+pub fn bounding_box(font: &MonoFont, c: char) -> Option<Rectangle> {
+    let glyph = Glyph::new(font, c);
+    let mut pixels = glyph.pixels();
+    let first = pixels.next()?;
+    let (min, max) = pixels.fold((first, first), |(min, max), p| {
+        (min.component_min(p), max.component_max(p))
+    });
+
+    Some(Rectangle::with_corners(min, max))
+}
+
+/// Returns the text written by `write`.
+pub fn write_string(write: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut text = String::new();
+    // Writing to a string does not fail.
+    let _ = write(&mut text);
+
+    text
+}
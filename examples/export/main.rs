@@ -0,0 +1,99 @@
+//! Exports the monospace fonts of this crate for use outside of Rust.
+//!
+//! The fonts get written as BDF for desktop applications and X11, as PSF2 for
+//! the Linux console and as C headers for the Adafruit GFX and u8g2
+//! libraries. `FONT_6X6` has variable-width glyphs and is not included.
+//!
+//! ```text
+//! $ cargo run --example export -- --output fonts
+//! ```
+
+use clap::{Parser, ValueEnum};
+use embedded_graphics::mono_font::MonoFont;
+use embedded_vintage_fonts::{byte_text::Encoding, *};
+use std::{error::Error, path::PathBuf};
+
+mod bdf;
+mod gfx;
+mod glyphs;
+mod psf;
+mod u8g2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Glyph Bitmap Distribution Format
+    Bdf,
+    /// PC Screen Font version 2 with Unicode table
+    Psf,
+    /// C header with a GFXfont for Adafruit GFX
+    Gfx,
+    /// C header with a font for u8g2
+    U8g2,
+}
+
+/// Export the monospace fonts to files for other applications
+#[derive(Debug, Parser)]
+#[clap(about, version)]
+struct Args {
+    /// The formats to export, all by default
+    #[clap(short, long, value_enum)]
+    format: Vec<Format>,
+
+    /// The directory for the exported fonts
+    #[clap(short, long)]
+    output: PathBuf,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+    let formats = if args.format.is_empty() {
+        Format::value_variants().to_vec()
+    } else {
+        args.format
+    };
+
+    #[rustfmt::skip]
+    let fonts: [(&str, MonoFont, Encoding); 7] = [
+        ("font6x8", FONT_6X8, Encoding::Latin1),
+        ("font6x8_cp437", FONT_6X8_CP437, Encoding::Cp437),
+        ("font6x12", FONT_6X12, Encoding::Latin1),
+        ("font8x16", FONT_8X16, Encoding::Latin1),
+        ("font8x16_cp437", FONT_8X16_CP437, Encoding::Cp437),
+        ("font12x16", FONT_12X16, Encoding::Latin1),
+        ("font24x32", FONT_24X32, Encoding::Latin1),
+    ];
+
+    std::fs::create_dir_all(&args.output)?;
+
+    for (name, font, encoding) in fonts.iter() {
+        for format in &formats {
+            let (path, data) = match format {
+                Format::Bdf => (
+                    args.output.join(format!("{}.bdf", name)),
+                    bdf::to_bdf(font, name).into_bytes(),
+                ),
+                Format::Psf => (
+                    args.output.join(format!("{}.psf", name)),
+                    psf::to_psf2(font),
+                ),
+                Format::Gfx => (
+                    args.output.join(format!("{}_gfx.h", name)),
+                    gfx::to_gfx(font, *encoding)?.to_c_header(name).into_bytes(),
+                ),
+                Format::U8g2 => {
+                    let name = format!("u8g2_font_vintage_{}", &name["font".len()..]);
+                    let data = u8g2::to_u8g2(font)?;
+                    (
+                        args.output.join(format!("{}.h", name)),
+                        u8g2::to_c_source(&name, &data).into_bytes(),
+                    )
+                }
+            };
+
+            std::fs::write(&path, data)?;
+            println!("wrote {}", path.display());
+        }
+    }
+
+    Ok(())
+}
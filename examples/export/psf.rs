@@ -0,0 +1,84 @@
+//! Export of `MonoFont`s as PC Screen Fonts version 2 for the Linux console.
+
+use crate::glyphs;
+use embedded_graphics::mono_font::MonoFont;
+
+/// The magic number at the start of a PSF2 file.
+pub const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+
+/// The flag for fonts with a Unicode table.
+pub const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+
+/// Terminates the entries of a glyph in the Unicode table.
+pub const PSF2_SEPARATOR: u8 = 0xff;
+
+/// Returns `font` as PSF2 font with a Unicode table.
+///
+/// The Linux console only loads fonts with 256 or 512 glyphs. The Latin-1
+/// characters keep their code as glyph position for consoles without
+/// Unicode support. All other characters take free positions and unused ones
+/// stay empty. The glyph for '?' also stands for the replacement character
+/// U+FFFD.
+pub fn to_psf2(font: &MonoFont) -> Vec<u8> {
+    let slots = glyph_slots(&glyphs::characters(font));
+    let bytes_per_glyph = glyphs::row_bytes(font) * font.character_size.height as usize;
+
+    let mut psf = PSF2_MAGIC.to_vec();
+    for field in [
+        0,
+        32,
+        PSF2_HAS_UNICODE_TABLE,
+        slots.len() as u32,
+        bytes_per_glyph as u32,
+        font.character_size.height,
+        font.character_size.width,
+    ] {
+        psf.extend(field.to_le_bytes());
+    }
+
+    for slot in &slots {
+        match slot.first() {
+            Some(&c) => psf.extend(glyphs::glyph_bytes(font, c).concat()),
+            None => psf.extend(std::iter::repeat_n(0, bytes_per_glyph)),
+        }
+    }
+
+    let mut buffer = [0; 4];
+    for slot in &slots {
+        for c in slot {
+            psf.extend(c.encode_utf8(&mut buffer).as_bytes());
+        }
+        psf.push(PSF2_SEPARATOR);
+    }
+
+    psf
+}
+
+/// Returns the characters for each glyph position.
+fn glyph_slots(chars: &[char]) -> Vec<Vec<char>> {
+    let mut slots = vec![Vec::new(); 256];
+    let mut others = Vec::new();
+
+    for &c in chars {
+        match slots.get_mut(c as usize) {
+            Some(slot) => slot.push(c),
+            None => others.push(c),
+        }
+    }
+
+    if let Some(question_mark) = slots.get_mut('?' as usize).filter(|s| !s.is_empty()) {
+        question_mark.push('\u{fffd}');
+    }
+
+    for c in others {
+        match slots.iter_mut().find(|s| s.is_empty()) {
+            Some(slot) => slot.push(c),
+            None => slots.push(vec![c]),
+        }
+    }
+
+    let glyph_count = if slots.len() <= 256 { 256 } else { 512 };
+    slots.resize(glyph_count.max(slots.len()), Vec::new());
+
+    slots
+}
@@ -0,0 +1,325 @@
+//! Export of `MonoFont`s in the font format of the u8g2 library.
+//!
+//! The glyphs get addressed by their Unicode characters like in the BDF
+//! export. Characters up to U+00FF are available with `drawStr` and all of
+//! them with `drawUTF8`. Unsupported characters are not drawn by u8g2.
+//!
+//! Like X11, u8g2 expects the baseline below the rows of the ascent. Text
+//! drawn at `(x, y)` looks exactly like text drawn by embedded-graphics at
+//! `Point::new(x, y - 1)` with `Baseline::Alphabetic`.
+
+use crate::glyphs;
+use embedded_graphics::{
+    mono_font::MonoFont,
+    primitives::{PointsIter, Rectangle},
+};
+use embedded_vintage_fonts::glyph::Glyph;
+use std::fmt::Write;
+
+/// The size of the font header.
+pub const HEADER_SIZE: usize = 23;
+
+/// A glyph reduced to the values stored in the font.
+struct GlyphData {
+    c: char,
+    width: u32,
+    height: u32,
+    x: i32,
+    y: i32,
+    /// The alternating lengths of runs of unset and set pixels, starting with
+    /// unset pixels.
+    runs: Vec<u32>,
+}
+
+/// Writes bit fields starting at the least significant bit of each byte.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: usize,
+}
+
+impl BitWriter {
+    fn unsigned(&mut self, value: u32, bits: u32) {
+        for i in 0..bits {
+            if self.bits % 8 == 0 {
+                self.bytes.push(0);
+            }
+            if value >> i & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 1 << (self.bits % 8);
+            }
+            self.bits += 1;
+        }
+    }
+
+    /// Writes `value` with an offset of half the range like u8g2 does.
+    fn signed(&mut self, value: i32, bits: u32) {
+        self.unsigned((value + (1 << (bits - 1))) as u32, bits);
+    }
+}
+
+/// Returns the number of bits for storing `value` unsigned.
+fn unsigned_bits(value: u32) -> u32 {
+    32 - value.leading_zeros()
+}
+
+/// Returns the number of bits for storing `value` signed with an offset of half
+/// the range.
+fn signed_bits(value: i32) -> u32 {
+    (1..16)
+        .find(|&bits| value >= -(1 << (bits - 1)) && value < (1 << (bits - 1)))
+        .unwrap_or(16)
+}
+
+/// Returns the run length encoding of `runs` as pairs of unset and set pixel
+/// counts which fit `bits_0` and `bits_1` bits.
+fn pairs(runs: &[u32], bits_0: u32, bits_1: u32) -> Vec<(u32, u32)> {
+    let max_0 = (1 << bits_0) - 1;
+    let max_1 = (1 << bits_1) - 1;
+    let mut pairs = Vec::new();
+
+    for run in runs.chunks(2) {
+        let mut zeros = run[0];
+        let mut ones = run.get(1).copied().unwrap_or(0);
+
+        while zeros > max_0 {
+            pairs.push((max_0, 0));
+            zeros -= max_0;
+        }
+        while ones > max_1 {
+            pairs.push((zeros, max_1));
+            zeros = 0;
+            ones -= max_1;
+        }
+        if zeros > 0 || ones > 0 {
+            pairs.push((zeros, ones));
+        }
+    }
+
+    pairs
+}
+
+impl GlyphData {
+    fn new(font: &MonoFont, c: char) -> Self {
+        let ascent = font.baseline as i32 + 1;
+
+        match glyphs::bounding_box(font, c) {
+            Some(area) => Self {
+                c,
+                width: area.size.width,
+                height: area.size.height,
+                x: area.top_left.x,
+                y: ascent - (area.top_left.y + area.size.height as i32),
+                runs: runs(&Glyph::new(font, c), area),
+            },
+            None => Self {
+                c,
+                width: 0,
+                height: 0,
+                x: 0,
+                y: 0,
+                runs: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns the encoded glyph without the encoding and size prefix.
+    fn encode(&self, header: &Header) -> Vec<u8> {
+        let mut writer = BitWriter::default();
+        writer.unsigned(self.width, header.bits_width);
+        writer.unsigned(self.height, header.bits_height);
+        writer.signed(self.x, header.bits_x);
+        writer.signed(self.y, header.bits_y);
+        writer.signed(header.advance, header.bits_advance);
+
+        if self.width > 0 {
+            let mut previous = None;
+            for pair in pairs(&self.runs, header.bits_0, header.bits_1) {
+                if previous == Some(pair) {
+                    writer.unsigned(1, 1);
+                    continue;
+                }
+                if previous.is_some() {
+                    writer.unsigned(0, 1);
+                }
+                writer.unsigned(pair.0, header.bits_0);
+                writer.unsigned(pair.1, header.bits_1);
+                previous = Some(pair);
+            }
+            writer.unsigned(0, 1);
+        }
+
+        writer.bytes
+    }
+}
+
+/// Returns the lengths of the runs of unset and set pixels of `glyph` within
+/// `area` starting with unset pixels.
+fn runs(glyph: &Glyph, area: Rectangle) -> Vec<u32> {
+    let mut runs = vec![0];
+
+    for point in area.points() {
+        let set = runs.len() % 2 == 0;
+        if glyph.is_set(point) != set {
+            runs.push(0);
+        }
+        *runs.last_mut().unwrap() += 1;
+    }
+
+    runs
+}
+
+/// The bit sizes of the glyph fields.
+struct Header {
+    bits_0: u32,
+    bits_1: u32,
+    bits_width: u32,
+    bits_height: u32,
+    bits_x: u32,
+    bits_y: u32,
+    bits_advance: u32,
+    advance: i32,
+}
+
+/// Converts `font` to a u8g2 font.
+pub fn to_u8g2(font: &MonoFont) -> Result<Vec<u8>, String> {
+    let glyphs: Vec<GlyphData> = glyphs::characters(font)
+        .into_iter()
+        .map(|c| GlyphData::new(font, c))
+        .collect();
+
+    let advance = font.character_size.width as i32;
+    let descent = font.character_size.height as i32 - font.baseline as i32 - 1;
+    let mut header = Header {
+        bits_0: 0,
+        bits_1: 0,
+        bits_width: glyphs
+            .iter()
+            .map(|g| unsigned_bits(g.width))
+            .max()
+            .unwrap_or(1),
+        bits_height: glyphs
+            .iter()
+            .map(|g| unsigned_bits(g.height))
+            .max()
+            .unwrap_or(1),
+        bits_x: glyphs.iter().map(|g| signed_bits(g.x)).max().unwrap_or(1),
+        bits_y: glyphs.iter().map(|g| signed_bits(g.y)).max().unwrap_or(1),
+        bits_advance: signed_bits(advance),
+        advance,
+    };
+
+    // Choose the sizes of the run lengths resulting in the smallest font.
+    let mut best: Option<(usize, u32, u32)> = None;
+    for bits_0 in 1..=8 {
+        for bits_1 in 1..=8 {
+            header.bits_0 = bits_0;
+            header.bits_1 = bits_1;
+            let size = glyphs.iter().map(|g| g.encode(&header).len()).sum();
+            if best.is_none_or(|(best_size, _, _)| size < best_size) {
+                best = Some((size, bits_0, bits_1));
+            }
+        }
+    }
+    if let Some((_, bits_0, bits_1)) = best {
+        header.bits_0 = bits_0;
+        header.bits_1 = bits_1;
+    }
+
+    let mut data = Vec::new();
+    let mut start_upper_a = None;
+    let mut start_lower_a = None;
+
+    for glyph in glyphs.iter().filter(|g| (g.c as u32) < 0x100) {
+        if glyph.c >= 'A' && start_upper_a.is_none() {
+            start_upper_a = Some(data.len());
+        }
+        if glyph.c >= 'a' && start_lower_a.is_none() {
+            start_lower_a = Some(data.len());
+        }
+
+        let encoded = glyph.encode(&header);
+        let size = u8::try_from(encoded.len() + 2)
+            .map_err(|_| format!("the glyph for {:?} is too large", glyph.c))?;
+        data.extend([glyph.c as u8, size]);
+        data.extend(encoded);
+    }
+    let end = data.len();
+    data.extend([0, 0]);
+
+    // The lookup table for the Unicode glyphs has a single entry for all of
+    // them.
+    let start_unicode = data.len();
+    data.extend([0, 4, 0xff, 0xff]);
+    for glyph in glyphs.iter().filter(|g| (g.c as u32) >= 0x100) {
+        let encoded = glyph.encode(&header);
+        let size = u8::try_from(encoded.len() + 3)
+            .map_err(|_| format!("the glyph for {:?} is too large", glyph.c))?;
+        data.extend((glyph.c as u16).to_be_bytes());
+        data.push(size);
+        data.extend(encoded);
+    }
+    data.extend([0, 0]);
+
+    let metric = |c: char, top: bool| -> u8 {
+        glyphs
+            .iter()
+            .find(|g| g.c == c)
+            .map_or(0, |g| if top { g.y + g.height as i32 } else { g.y }) as i8 as u8
+    };
+
+    let mut font_data = vec![
+        glyphs.len().min(0xff) as u8,
+        0,
+        header.bits_0 as u8,
+        header.bits_1 as u8,
+        header.bits_width as u8,
+        header.bits_height as u8,
+        header.bits_x as u8,
+        header.bits_y as u8,
+        header.bits_advance as u8,
+        font.character_size.width as u8,
+        font.character_size.height as u8,
+        0,
+        -descent as i8 as u8,
+        metric('A', true),
+        metric('g', false),
+        metric('(', true),
+        metric(')', false),
+    ];
+    for start in [
+        start_upper_a.unwrap_or(end),
+        start_lower_a.unwrap_or(end),
+        start_unicode,
+    ] {
+        let start = u16::try_from(start).map_err(|_| "the font is too large".to_string())?;
+        font_data.extend(start.to_be_bytes());
+    }
+    debug_assert_eq!(font_data.len(), HEADER_SIZE);
+    font_data.extend(data);
+
+    Ok(font_data)
+}
+
+/// Returns the C source defining the u8g2 font `name` with `data`.
+pub fn to_c_source(name: &str, data: &[u8]) -> String {
+    glyphs::write_string(|source| write_c_source(source, name, data))
+}
+
+fn write_c_source(source: &mut String, name: &str, data: &[u8]) -> std::fmt::Result {
+    writeln!(source, "// {} from embedded-vintage-fonts", name)?;
+    writeln!(source)?;
+    writeln!(source, "#include <u8g2.h>")?;
+    writeln!(source)?;
+    writeln!(
+        source,
+        "const uint8_t {}[{}] U8G2_FONT_SECTION({:?}) = {{",
+        name,
+        data.len(),
+        name
+    )?;
+    for line in data.chunks(12) {
+        let bytes: Vec<String> = line.iter().map(|b| format!("0x{:02X}", b)).collect();
+        writeln!(source, "  {},", bytes.join(", "))?;
+    }
+    writeln!(source, "}};")
+}
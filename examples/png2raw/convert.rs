@@ -0,0 +1,59 @@
+//! Conversion from glyph-sheet PNGs to the raw 1 bpp format of `ImageRaw`.
+//!
+//! This module is shared between the `png2raw` example and the test checking
+//! the raw files in `data/`.
+
+use std::{error::Error, fs::File, path::Path};
+
+/// Reads the glyph sheet from the PNG file at `path` and returns its width in
+/// pixels and the raw data.
+pub fn png_to_raw(path: &Path) -> Result<(u32, Vec<u8>), Box<dyn Error>> {
+    let mut decoder = png::Decoder::new(File::open(path)?);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+
+    let mut buffer = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buffer)?;
+    let pixels = &buffer[..info.buffer_size()];
+
+    let raw = pack(
+        pixels,
+        info.width,
+        info.height,
+        info.line_size,
+        info.color_type.samples(),
+        info.color_type == png::ColorType::GrayscaleAlpha
+            || info.color_type == png::ColorType::Rgba,
+    );
+
+    Ok((info.width, raw))
+}
+
+/// Packs the 8 bit `pixels` into rows of 1 bpp with the leftmost pixel at the
+/// most significant bit and each row padded to full bytes.
+///
+/// A pixel is set if its first color channel is at least half bright. Fully
+/// transparent pixels are never set.
+fn pack(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    line_size: usize,
+    samples: usize,
+    has_alpha: bool,
+) -> Vec<u8> {
+    let row_bytes = (width as usize).div_ceil(8);
+    let mut raw = vec![0; row_bytes * height as usize];
+
+    for (y, line) in pixels.chunks(line_size).take(height as usize).enumerate() {
+        for (x, pixel) in line.chunks(samples).take(width as usize).enumerate() {
+            let transparent = has_alpha && pixel[samples - 1] < 0x80;
+
+            if pixel[0] >= 0x80 && !transparent {
+                raw[y * row_bytes + x / 8] |= 0x80 >> (x % 8);
+            }
+        }
+    }
+
+    raw
+}
@@ -0,0 +1,39 @@
+//! Converts a glyph-sheet PNG to the raw 1 bpp format expected by
+//! `ImageRaw::new`.
+//!
+//! The glyph sheets in `data/` are white glyphs on black background. Writing
+//! the raw file for `data/font6x8.png` for example looks like:
+//!
+//! ```text
+//! $ cargo run --example png2raw -- data/font6x8.png data/font6x8_1bpp.raw
+//! ```
+
+use clap::Parser;
+use std::{error::Error, path::PathBuf};
+
+mod convert;
+
+/// Convert a glyph sheet from PNG to raw 1 bpp data
+#[derive(Debug, Parser)]
+#[clap(about, version)]
+struct Args {
+    /// The PNG file to read
+    input: PathBuf,
+
+    /// The raw file to write
+    output: PathBuf,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
+
+    let (width, raw) = convert::png_to_raw(&args.input)?;
+    std::fs::write(&args.output, raw)?;
+    println!(
+        "wrote {} (image width {} pixels)",
+        args.output.display(),
+        width
+    );
+
+    Ok(())
+}
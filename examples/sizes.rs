@@ -0,0 +1,51 @@
+//! Prints the storage size of the fonts with and without compression.
+
+use embedded_graphics::{geometry::OriginDimensions, mono_font::MonoFont};
+use embedded_vintage_fonts::compressed::{self, CompressedFont};
+
+fn raw_size(font: &MonoFont) -> usize {
+    let size = font.image.size();
+
+    (size.width as usize).div_ceil(8) * size.height as usize
+}
+
+fn main() {
+    let fonts: [(&str, &MonoFont, &CompressedFont); 5] = [
+        (
+            "FONT_6X8",
+            &embedded_vintage_fonts::FONT_6X8,
+            &compressed::FONT_6X8,
+        ),
+        (
+            "FONT_6X12",
+            &embedded_vintage_fonts::FONT_6X12,
+            &compressed::FONT_6X12,
+        ),
+        (
+            "FONT_8X16",
+            &embedded_vintage_fonts::FONT_8X16,
+            &compressed::FONT_8X16,
+        ),
+        (
+            "FONT_12X16",
+            &embedded_vintage_fonts::FONT_12X16,
+            &compressed::FONT_12X16,
+        ),
+        (
+            "FONT_24X32",
+            &embedded_vintage_fonts::FONT_24X32,
+            &compressed::FONT_24X32,
+        ),
+    ];
+
+    println!("| Font | Uncompressed | Compressed |");
+    println!("|------|-------------:|-----------:|");
+    for (name, font, compressed) in fonts {
+        println!(
+            "| {} | {} bytes | {} bytes |",
+            name,
+            raw_size(font),
+            compressed.size_in_bytes()
+        );
+    }
+}
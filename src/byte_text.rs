@@ -0,0 +1,357 @@
+//! Drawing text from single-byte encoded strings.
+//!
+//! [`Text`](embedded_graphics::text::Text) requires UTF-8 strings. Text from
+//! devices and protocols of the era of these fonts often comes as bytes in
+//! Latin-1 or code page 437 instead. [`ByteText`] draws such byte strings with
+//! a [`MonoTextStyle`] by decoding every byte on the fly without converting
+//! the whole string to UTF-8 first.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::Baseline,
+//! };
+//! # #[cfg(feature = "font-6x8-cp437")]
+//! use embedded_vintage_fonts::{
+//!     byte_text::{ByteText, Encoding},
+//!     FONT_6X8_CP437,
+//! };
+//!
+//! # #[cfg(not(feature = "font-6x8-cp437"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8-cp437")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let mut display = MockDisplay::new();
+//! let style = MonoTextStyle::new(&FONT_6X8_CP437, BinaryColor::On);
+//!
+//! // "┌─┐" and "└─┘" from code page 437.
+//! ByteText::with_baseline(
+//!     b"\xda\xc4\xbf\n\xc0\xc4\xd9",
+//!     Encoding::Cp437,
+//!     Point::zero(),
+//!     style,
+//!     Baseline::Top,
+//! )
+//! .draw(&mut display)?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         "                 ",
+//!         "                 ",
+//!         "                 ",
+//!         "  #############  ",
+//!         "  #           #  ",
+//!         "  #           #  ",
+//!         "  #           #  ",
+//!         "  #           #  ",
+//!         "  #           #  ",
+//!         "  #           #  ",
+//!         "  #           #  ",
+//!         "  #############  ",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    mono_font::MonoTextStyle,
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Alignment, Baseline, TextStyle, TextStyleBuilder},
+    transform::Transform,
+    Drawable,
+};
+
+/// The upper half of code page 437 from 0x80 to 0xff.
+const CP437_UPPER_HALF: [char; 128] = [
+    'Ç', 'ü', 'é', 'â', 'ä', 'à', 'å', 'ç', 'ê', 'ë', 'è', 'ï', 'î', 'ì', 'Ä', 'Å', //
+    'É', 'æ', 'Æ', 'ô', 'ö', 'ò', 'û', 'ù', 'ÿ', 'Ö', 'Ü', '¢', '£', '¥', '₧', 'ƒ', //
+    'á', 'í', 'ó', 'ú', 'ñ', 'Ñ', 'ª', 'º', '¿', '⌐', '¬', '½', '¼', '¡', '«', '»', //
+    '░', '▒', '▓', '│', '┤', '╡', '╢', '╖', '╕', '╣', '║', '╗', '╝', '╜', '╛', '┐', //
+    '└', '┴', '┬', '├', '─', '┼', '╞', '╟', '╚', '╔', '╩', '╦', '╠', '═', '╬', '╧', //
+    '╨', '╤', '╥', '╙', '╘', '╒', '╓', '╫', '╪', '┘', '┌', '█', '▄', '▌', '▐', '▀', //
+    'α', 'ß', 'Γ', 'π', 'Σ', 'σ', 'µ', 'τ', 'Φ', 'Θ', 'Ω', 'δ', '∞', 'φ', 'ε', '∩', //
+    '≡', '±', '≥', '≤', '⌠', '⌡', '÷', '≈', '°', '∙', '·', '√', 'ⁿ', '²', '■', '\u{a0}',
+];
+
+/// Single-byte character encoding of a [`ByteText`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// [ISO/IEC 8859-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1).
+    Latin1,
+
+    /// [Code page 437](https://en.wikipedia.org/wiki/Code_page_437) with the
+    /// bytes below 0x80 being ASCII.
+    Cp437,
+}
+
+impl Encoding {
+    /// Returns the character encoded by `byte`.
+    pub const fn decode(self, byte: u8) -> char {
+        match self {
+            Encoding::Latin1 => byte as char,
+            Encoding::Cp437 => {
+                if byte < 0x80 {
+                    byte as char
+                } else {
+                    CP437_UPPER_HALF[byte as usize - 0x80]
+                }
+            }
+        }
+    }
+}
+
+/// A text drawable for single-byte encoded strings.
+///
+/// This works like [`Text`](embedded_graphics::text::Text) but takes a byte
+/// string in the given [`Encoding`]. Lines are separated by `b'\n'` and a
+/// trailing `b'\r'` gets ignored.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ByteText<'a, C> {
+    /// The bytes of the string.
+    pub text: &'a [u8],
+
+    /// The encoding of the string.
+    pub encoding: Encoding,
+
+    /// The position.
+    pub position: Point,
+
+    /// The character style.
+    pub character_style: MonoTextStyle<'a, C>,
+
+    /// The text style.
+    pub text_style: TextStyle,
+}
+
+impl<'a, C> ByteText<'a, C> {
+    /// Creates a text drawable with the default text style.
+    pub const fn new(
+        text: &'a [u8],
+        encoding: Encoding,
+        position: Point,
+        character_style: MonoTextStyle<'a, C>,
+    ) -> Self {
+        Self::with_text_style(
+            text,
+            encoding,
+            position,
+            character_style,
+            TextStyleBuilder::new().build(),
+        )
+    }
+
+    /// Creates a text drawable with the given text style.
+    pub const fn with_text_style(
+        text: &'a [u8],
+        encoding: Encoding,
+        position: Point,
+        character_style: MonoTextStyle<'a, C>,
+        text_style: TextStyle,
+    ) -> Self {
+        Self {
+            text,
+            encoding,
+            position,
+            character_style,
+            text_style,
+        }
+    }
+
+    /// Creates a text drawable with the given baseline.
+    pub const fn with_baseline(
+        text: &'a [u8],
+        encoding: Encoding,
+        position: Point,
+        character_style: MonoTextStyle<'a, C>,
+        baseline: Baseline,
+    ) -> Self {
+        Self::with_text_style(
+            text,
+            encoding,
+            position,
+            character_style,
+            TextStyle::with_baseline(baseline),
+        )
+    }
+
+    /// Creates a text drawable with the given alignment.
+    pub const fn with_alignment(
+        text: &'a [u8],
+        encoding: Encoding,
+        position: Point,
+        character_style: MonoTextStyle<'a, C>,
+        alignment: Alignment,
+    ) -> Self {
+        Self::with_text_style(
+            text,
+            encoding,
+            position,
+            character_style,
+            TextStyle::with_alignment(alignment),
+        )
+    }
+}
+
+impl<'a, C> ByteText<'a, C>
+where
+    C: PixelColor,
+{
+    fn chars(&self, line: &'a [u8]) -> impl Iterator<Item = char> + 'a {
+        let encoding = self.encoding;
+
+        line.iter().map(move |&byte| encoding.decode(byte))
+    }
+
+    fn line_height(&self) -> i32 {
+        let height = self
+            .text_style
+            .line_height
+            .to_absolute(self.character_style.line_height());
+
+        i32::try_from(height).unwrap_or(i32::MAX)
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle::from(&self.character_style)
+    }
+
+    /// Returns the lines of the text with the position for drawing them.
+    fn lines(&self) -> impl Iterator<Item = (&'a [u8], Point)> + '_ {
+        let line_style = self.line_style();
+        let width = self.character_style.font.character_size.width;
+        let mut position = self.position;
+
+        self.text.split(|&byte| byte == b'\n').map(move |line| {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+            let p = match self.text_style.alignment {
+                Alignment::Left => position,
+                Alignment::Right | Alignment::Center => {
+                    let metrics = line_style.measure_chars(
+                        self.chars(line),
+                        Point::zero(),
+                        self.text_style.baseline,
+                        |_| width,
+                    );
+                    let offset = metrics.next_position - Point::new(1, 0);
+
+                    if self.text_style.alignment == Alignment::Right {
+                        position - offset
+                    } else {
+                        position - offset / 2
+                    }
+                }
+            };
+
+            position.y += self.line_height();
+
+            (line, p)
+        })
+    }
+
+    fn draw_glyph<D>(&self, c: char, position: Point, target: &mut D) -> Result<u32, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        render::draw_mono_glyph(
+            self.character_style.font,
+            c,
+            position,
+            self.character_style.text_color,
+            self.character_style.background_color,
+            target,
+        )
+    }
+}
+
+impl<C> Drawable for ByteText<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+    type Output = Point;
+
+    fn draw<D>(&self, target: &mut D) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let line_style = self.line_style();
+        let mut next_position = self.position;
+
+        for (line, position) in self.lines() {
+            next_position = line_style.draw_chars(
+                self.chars(line),
+                position,
+                self.text_style.baseline,
+                target,
+                |c, p, target| self.draw_glyph(c, p, target),
+            )?;
+        }
+
+        Ok(next_position)
+    }
+}
+
+impl<C> Dimensions for ByteText<'_, C>
+where
+    C: PixelColor,
+{
+    fn bounding_box(&self) -> Rectangle {
+        let line_style = self.line_style();
+        let width = self.character_style.font.character_size.width;
+        let mut min_max: Option<(Point, Point)> = None;
+
+        for (line, position) in self.lines() {
+            let metrics = line_style.measure_chars(
+                self.chars(line),
+                position,
+                self.text_style.baseline,
+                |_| width,
+            );
+
+            if let Some(bottom_right) = metrics.bounding_box.bottom_right() {
+                let top_left = metrics.bounding_box.top_left;
+                min_max = Some(match min_max {
+                    Some((min, max)) => {
+                        (min.component_min(top_left), max.component_max(bottom_right))
+                    }
+                    None => (top_left, bottom_right),
+                });
+            }
+        }
+
+        match min_max {
+            Some((min, max)) => Rectangle::with_corners(min, max),
+            None => Rectangle::new(self.position, Size::zero()),
+        }
+    }
+}
+
+impl<C> Transform for ByteText<'_, C>
+where
+    C: Clone,
+{
+    fn translate(&self, by: Point) -> Self {
+        Self {
+            position: self.position + by,
+            ..self.clone()
+        }
+    }
+
+    fn translate_mut(&mut self, by: Point) -> &mut Self {
+        self.position += by;
+
+        self
+    }
+}
@@ -0,0 +1,716 @@
+//! Compressed glyph storage.
+//!
+//! The bitmaps of the fonts from this crate are stored uncompressed at one
+//! bit per pixel. This module provides compressed variants of the monospace
+//! fonts together with the text renderer [`CompressedTextStyle`] which
+//! decodes the glyphs on the fly while drawing. The compressed data gets
+//! generated from the original bitmaps at compile time and only ends up in a
+//! build if it gets used.
+//!
+//! | Font | Uncompressed | Compressed (including index) |
+//! |------|-------------:|-----------------------------:|
+//! | [`FONT_6X8`] | 1200 bytes | 1089 bytes |
+//! | [`FONT_6X12`] | 864 bytes | 548 bytes |
+//! | [`FONT_8X16`] | 3360 bytes | 1669 bytes |
+//! | [`FONT_12X16`] | 4800 bytes | 1946 bytes |
+//! | [`FONT_24X32`] | 19440 bytes | 3659 bytes |
+//!
+//! Run `cargo run --example sizes` for getting this report for the current
+//! state of the fonts.
+//!
+//! There is no compressed variant of [`FONT_6X6`](crate::FONT_6X6). Its
+//! glyphs are packed so densely that the encoding below would save less than
+//! 20 bytes.
+//!
+//! # Encoding
+//!
+//! Each glyph is encoded row by row into a continuous bit stream. A row is
+//! encoded as
+//!
+//! * `0` if it is the same as the previous one (the row before the first one
+//!   of a glyph is considered empty),
+//! * `10` if it is empty,
+//! * `11` followed by the pixels of the row otherwise.
+//!
+//! The glyphs are grouped into blocks of [`GLYPHS_PER_BLOCK`] glyphs and an
+//! index stores the bit offset of every block. Looking up a glyph decodes the
+//! row headers of at most `GLYPHS_PER_BLOCK - 1` preceding glyphs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::Text,
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::compressed::{CompressedTextStyle, FONT_6X8};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let mut display = MockDisplay::new();
+//! let style = CompressedTextStyle::new(&FONT_6X8, BinaryColor::On);
+//!
+//! Text::new("Hi", Point::new(0, FONT_6X8.baseline as i32), style).draw(&mut display)?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         "#   #   #  ",
+//!         "#   #      ",
+//!         "#   #  ##  ",
+//!         "#####   #  ",
+//!         "#   #   #  ",
+//!         "#   #   #  ",
+//!         "#   #  ### ",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    mono_font::{mapping::GlyphMapping, DecorationDimensions},
+    pixelcolor::PixelColor,
+    primitives::Rectangle,
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// Number of glyphs per block of the index of a [`CompressedFont`].
+pub const GLYPHS_PER_BLOCK: usize = 8;
+
+/// Monospace bitmap font with compressed glyphs.
+///
+/// This is the compressed counterpart of
+/// [`MonoFont`](embedded_graphics::mono_font::MonoFont). Use
+/// [`compressed_font`](crate::compressed_font) for creating one from the
+/// bitmap of a `MonoFont`.
+#[derive(Clone, Copy)]
+pub struct CompressedFont<'a> {
+    /// Compressed glyph data.
+    pub data: &'a [u8],
+
+    /// Bit offsets of the blocks of glyphs within [`data`].
+    ///
+    /// [`data`]: CompressedFont::data
+    pub index: &'a [u32],
+
+    /// Size of a single character in pixel.
+    pub character_size: Size,
+
+    /// Spacing between characters.
+    ///
+    /// The spacing defines how many empty pixels are added horizontally
+    /// between adjacent characters on a single line of text.
+    pub character_spacing: u32,
+
+    /// The baseline.
+    ///
+    /// Offset from the top of the glyph bounding box to the baseline.
+    pub baseline: u32,
+
+    /// Strikethrough decoration dimensions.
+    pub strikethrough: DecorationDimensions,
+
+    /// Underline decoration dimensions.
+    pub underline: DecorationDimensions,
+
+    /// Glyph mapping.
+    pub glyph_mapping: &'a dyn GlyphMapping,
+}
+
+impl CompressedFont<'_> {
+    /// Returns the number of bytes occupied by the compressed glyphs and
+    /// their index.
+    pub fn size_in_bytes(&self) -> usize {
+        core::mem::size_of_val(self.data) + core::mem::size_of_val(self.index)
+    }
+
+    /// Returns an iterator over the rows of the glyph for `c`.
+    ///
+    /// Each row is returned as a bit mask with the leftmost pixel at bit
+    /// `character_size.width - 1`.
+    pub fn glyph_rows(&self, c: char) -> GlyphRows<'_> {
+        let width = self.character_size.width;
+        let height = self.character_size.height;
+        let index = self.glyph_mapping.index(c);
+
+        let block_offset = self
+            .index
+            .get(index / GLYPHS_PER_BLOCK)
+            .map_or(usize::MAX, |&offset| offset as usize);
+        let mut reader = BitReader::new(self.data, block_offset);
+
+        for _ in 0..(index % GLYPHS_PER_BLOCK) * height as usize {
+            if reader.bit() && reader.bit() {
+                reader.skip(width as usize);
+            }
+        }
+
+        GlyphRows {
+            reader,
+            width,
+            remaining: height,
+            row: 0,
+        }
+    }
+}
+
+impl PartialEq for CompressedFont<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+            && self.index == other.index
+            && self.character_size == other.character_size
+            && self.character_spacing == other.character_spacing
+            && self.baseline == other.baseline
+            && self.strikethrough == other.strikethrough
+            && self.underline == other.underline
+            && core::ptr::eq(
+                self.glyph_mapping as *const dyn GlyphMapping as *const u8,
+                other.glyph_mapping as *const dyn GlyphMapping as *const u8,
+            )
+    }
+}
+
+impl core::fmt::Debug for CompressedFont<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompressedFont")
+            .field("data", &self.data.len())
+            .field("index", &self.index.len())
+            .field("character_size", &self.character_size)
+            .field("character_spacing", &self.character_spacing)
+            .field("baseline", &self.baseline)
+            .field("strikethrough", &self.strikethrough)
+            .field("underline", &self.underline)
+            .field("glyph_mapping", &"?")
+            .finish_non_exhaustive()
+    }
+}
+
+/// Iterator over the rows of a compressed glyph.
+///
+/// See [`CompressedFont::glyph_rows`].
+#[derive(Debug, Clone)]
+pub struct GlyphRows<'a> {
+    reader: BitReader<'a>,
+    width: u32,
+    remaining: u32,
+    row: u32,
+}
+
+impl Iterator for GlyphRows<'_> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        if self.reader.bit() {
+            self.row = if self.reader.bit() {
+                self.reader.bits(self.width)
+            } else {
+                0
+            };
+        }
+
+        Some(self.row)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8], position: usize) -> Self {
+        Self { data, position }
+    }
+
+    /// Reads the next bit. Reading past the end of the data returns `false`.
+    fn bit(&mut self) -> bool {
+        let bit = self
+            .data
+            .get(self.position / 8)
+            .is_some_and(|byte| byte & (0x80 >> (self.position % 8)) != 0);
+        self.position = self.position.saturating_add(1);
+
+        bit
+    }
+
+    fn bits(&mut self, count: u32) -> u32 {
+        (0..count).fold(0, |value, _| value << 1 | self.bit() as u32)
+    }
+
+    fn skip(&mut self, count: usize) {
+        self.position = self.position.saturating_add(count);
+    }
+}
+
+/// Returns the row `y` of the glyph with `index` from an uncompressed bitmap.
+const fn raw_row(raw: &[u8], image_width: u32, character_size: Size, index: usize, y: u32) -> u32 {
+    let glyphs_per_row = (image_width / character_size.width) as usize;
+    let cell_x = (index % glyphs_per_row) as u32 * character_size.width;
+    let cell_y = (index / glyphs_per_row) as u32 * character_size.height;
+
+    let mut row = 0;
+    let mut x = 0;
+    while x < character_size.width {
+        let bit = ((cell_y + y) * image_width + cell_x + x) as usize;
+        row = row << 1 | ((raw[bit / 8] >> (7 - bit % 8)) & 1) as u32;
+        x += 1;
+    }
+
+    row
+}
+
+/// Encodes the glyphs from `raw` and passes each bit to `emit`.
+///
+/// As closures are not supported in const functions, this is a macro taking
+/// the statement to emit a bit `$bit` at bit offset `$position`. Additionally
+/// `$block` gets executed at the start of each block of glyphs.
+macro_rules! encode {
+    (
+        $raw:expr, $image_width:expr, $character_size:expr, $glyph_count:expr,
+        |$position:ident, $bit:ident| $emit:block,
+        |$block_index:ident| $block:block
+    ) => {{
+        let width = $character_size.width;
+        assert!(
+            width > 0 && width <= 32,
+            "glyphs need to be between 1 and 32 pixels wide"
+        );
+
+        let mut $position: usize = 0;
+        let mut index = 0;
+        while index < $glyph_count {
+            if index % GLYPHS_PER_BLOCK == 0 {
+                let $block_index = index / GLYPHS_PER_BLOCK;
+                $block
+            }
+
+            let mut previous = 0;
+            let mut y = 0;
+            while y < $character_size.height {
+                let row = raw_row($raw, $image_width, $character_size, index, y);
+
+                if row == previous {
+                    let $bit = false;
+                    $emit
+                    $position += 1;
+                } else if row == 0 {
+                    let $bit = true;
+                    $emit
+                    $position += 1;
+                    let $bit = false;
+                    $emit
+                    $position += 1;
+                } else {
+                    let $bit = true;
+                    $emit
+                    $position += 1;
+                    $emit
+                    $position += 1;
+
+                    let mut x = width;
+                    while x > 0 {
+                        x -= 1;
+                        let $bit = (row >> x) & 1 != 0;
+                        $emit
+                        $position += 1;
+                    }
+                }
+
+                previous = row;
+                y += 1;
+            }
+
+            index += 1;
+        }
+
+        $position
+    }};
+}
+
+/// Returns the number of bytes of the compressed glyph data for a bitmap.
+///
+/// `raw` is the uncompressed bitmap of `image_width` pixels holding
+/// `glyph_count` glyphs of `character_size` as used by
+/// [`ImageRaw`](embedded_graphics::image::ImageRaw).
+pub const fn compressed_len(
+    raw: &[u8],
+    image_width: u32,
+    character_size: Size,
+    glyph_count: usize,
+) -> usize {
+    let bits = encode!(
+        raw,
+        image_width,
+        character_size,
+        glyph_count,
+        |position, bit| {
+            let _ = (position, bit);
+        },
+        |block_index| {
+            let _ = block_index;
+        }
+    );
+
+    bits.div_ceil(8)
+}
+
+/// Returns the number of entries of the index for `glyph_count` glyphs.
+pub const fn index_len(glyph_count: usize) -> usize {
+    glyph_count.div_ceil(GLYPHS_PER_BLOCK)
+}
+
+/// Compresses the glyphs from a bitmap.
+///
+/// `N` needs to be [`compressed_len`] for the same parameters.
+pub const fn compress<const N: usize>(
+    raw: &[u8],
+    image_width: u32,
+    character_size: Size,
+    glyph_count: usize,
+) -> [u8; N] {
+    let mut data = [0; N];
+
+    encode!(
+        raw,
+        image_width,
+        character_size,
+        glyph_count,
+        |position, bit| {
+            if bit {
+                data[position / 8] |= 0x80 >> (position % 8);
+            }
+        },
+        |block_index| {
+            let _ = block_index;
+        }
+    );
+
+    data
+}
+
+/// Returns the index for the glyphs compressed by [`compress`].
+///
+/// `N` needs to be [`index_len`] for `glyph_count`.
+pub const fn compress_index<const N: usize>(
+    raw: &[u8],
+    image_width: u32,
+    character_size: Size,
+    glyph_count: usize,
+) -> [u32; N] {
+    let mut index = [0; N];
+
+    encode!(
+        raw,
+        image_width,
+        character_size,
+        glyph_count,
+        |position, bit| {
+            let _ = (position, bit);
+        },
+        |block_index| {
+            index[block_index] = position as u32;
+        }
+    );
+
+    index
+}
+
+/// Creates a [`CompressedFont`] from a `MonoFont` and its bitmap at compile
+/// time.
+///
+/// The arguments are the `MonoFont` constant, its raw bitmap as passed to
+/// [`ImageRaw::new`](embedded_graphics::image::ImageRaw::new) together with
+/// the width of the image, and the number of glyphs in the bitmap.
+///
+/// ```rust
+/// use embedded_graphics::{
+///     geometry::Size,
+///     image::ImageRaw,
+///     mono_font::{mapping::ASCII, DecorationDimensions, MonoFont},
+/// };
+/// use embedded_vintage_fonts::{compressed::CompressedFont, compressed_font};
+///
+/// const RAW: &[u8] = &[0b1000_0000, 0b0100_0000];
+/// const FONT: MonoFont = MonoFont {
+///     image: ImageRaw::new(RAW, 8),
+///     character_size: Size::new(2, 2),
+///     character_spacing: 0,
+///     baseline: 1,
+///     strikethrough: DecorationDimensions::new(1, 1),
+///     underline: DecorationDimensions::new(2, 1),
+///     glyph_mapping: &ASCII,
+/// };
+/// const COMPRESSED: CompressedFont = compressed_font!(FONT, RAW, 8, 4);
+///
+/// assert!(COMPRESSED.glyph_rows(' ').eq([0b10, 0b01]));
+/// ```
+#[macro_export]
+macro_rules! compressed_font {
+    ($font:expr, $raw:expr, $image_width:expr, $glyph_count:expr $(,)?) => {{
+        const LEN: usize = $crate::compressed::compressed_len(
+            $raw,
+            $image_width,
+            $font.character_size,
+            $glyph_count,
+        );
+        const DATA: [u8; LEN] =
+            $crate::compressed::compress($raw, $image_width, $font.character_size, $glyph_count);
+        const INDEX_LEN: usize = $crate::compressed::index_len($glyph_count);
+        const INDEX: [u32; INDEX_LEN] = $crate::compressed::compress_index(
+            $raw,
+            $image_width,
+            $font.character_size,
+            $glyph_count,
+        );
+
+        $crate::compressed::CompressedFont {
+            data: &DATA,
+            index: &INDEX,
+            character_size: $font.character_size,
+            character_spacing: $font.character_spacing,
+            baseline: $font.baseline,
+            strikethrough: $font.strikethrough,
+            underline: $font.underline,
+            glyph_mapping: $font.glyph_mapping,
+        }
+    }};
+}
+
+/// Compressed variant of [`FONT_24X32`](crate::FONT_24X32).
+#[cfg(feature = "font-24x32")]
+pub const FONT_24X32: CompressedFont = compressed_font!(
+    crate::FONT_24X32,
+    crate::FONT_24X32_RAW,
+    crate::FONT_24X32_IMAGE_WIDTH,
+    crate::mapping::ISO_8859_1.glyph_count(),
+);
+
+/// Compressed variant of [`FONT_12X16`](crate::FONT_12X16).
+#[cfg(feature = "font-12x16")]
+pub const FONT_12X16: CompressedFont = compressed_font!(
+    crate::FONT_12X16,
+    crate::FONT_12X16_RAW,
+    crate::FONT_12X16_IMAGE_WIDTH,
+    crate::mapping::ISO_8859_1.glyph_count(),
+);
+
+/// Compressed variant of [`FONT_8X16`](crate::FONT_8X16).
+#[cfg(feature = "font-8x16")]
+pub const FONT_8X16: CompressedFont = compressed_font!(
+    crate::FONT_8X16,
+    crate::FONT_8X16_RAW,
+    crate::FONT_8X16_IMAGE_WIDTH,
+    crate::mapping::ISO_8859_1.glyph_count(),
+);
+
+/// Compressed variant of [`FONT_6X12`](crate::FONT_6X12).
+#[cfg(feature = "font-6x12")]
+pub const FONT_6X12: CompressedFont = compressed_font!(
+    crate::FONT_6X12,
+    crate::FONT_6X12_RAW,
+    crate::FONT_6X12_IMAGE_WIDTH,
+    crate::mapping::ASCII.glyph_count(),
+);
+
+/// Compressed variant of [`FONT_6X8`](crate::FONT_6X8).
+#[cfg(feature = "font-6x8")]
+pub const FONT_6X8: CompressedFont = compressed_font!(
+    crate::FONT_6X8,
+    crate::FONT_6X8_RAW,
+    crate::FONT_6X8_IMAGE_WIDTH,
+    crate::mapping::ISO_8859_1.glyph_count(),
+);
+
+const NULL_FONT: CompressedFont = CompressedFont {
+    data: &[],
+    index: &[],
+    character_size: Size::zero(),
+    character_spacing: 0,
+    baseline: 0,
+    strikethrough: DecorationDimensions::new(0, 0),
+    underline: DecorationDimensions::new(0, 0),
+    glyph_mapping: &crate::mapping::ASCII,
+};
+
+/// Style properties for text using a [`CompressedFont`].
+///
+/// This is the counterpart of
+/// [`MonoTextStyle`](embedded_graphics::mono_font::MonoTextStyle) for
+/// compressed fonts. Use [`new`](CompressedTextStyle::new) for a text color on
+/// a transparent background or [`CompressedTextStyleBuilder`] for more complex
+/// styles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct CompressedTextStyle<'a, C> {
+    /// Text color.
+    pub text_color: Option<C>,
+
+    /// Background color.
+    pub background_color: Option<C>,
+
+    /// Underline color.
+    pub underline_color: DecorationColor<C>,
+
+    /// Strikethrough color.
+    pub strikethrough_color: DecorationColor<C>,
+
+    /// Font.
+    pub font: &'a CompressedFont<'a>,
+}
+
+impl<'a, C> CompressedTextStyle<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a text style with transparent background.
+    pub const fn new(font: &'a CompressedFont<'a>, text_color: C) -> Self {
+        CompressedTextStyleBuilder::new()
+            .font(font)
+            .text_color(text_color)
+            .build()
+    }
+
+    /// Returns `true` if the style is transparent.
+    pub fn is_transparent(&self) -> bool {
+        self.text_color.is_none()
+            && self.background_color.is_none()
+            && self.underline_color.is_none()
+            && self.strikethrough_color.is_none()
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle {
+            text_color: self.text_color,
+            background_color: self.background_color,
+            underline_color: self.underline_color,
+            strikethrough_color: self.strikethrough_color,
+            character_height: self.font.character_size.height,
+            character_spacing: self.font.character_spacing,
+            baseline: self.font.baseline,
+            strikethrough: self.font.strikethrough,
+            underline: self.font.underline,
+        }
+    }
+
+    fn draw_glyph<D>(&self, c: char, position: Point, target: &mut D) -> Result<u32, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let width = self.font.character_size.width;
+        let area = Rectangle::new(position, self.font.character_size);
+
+        let pixels = self
+            .font
+            .glyph_rows(c)
+            .flat_map(|row| (0..width).rev().map(move |x| (row >> x) & 1 != 0));
+        render::draw_binary(
+            target,
+            &area,
+            pixels,
+            self.text_color,
+            self.background_color,
+        )?;
+
+        Ok(width)
+    }
+}
+
+impl<C> TextRenderer for CompressedTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_chars(text.chars(), position, baseline, target, |c, p, target| {
+                self.draw_glyph(c, p, target)
+            })
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.font.character_size.width;
+
+        self.line_style()
+            .measure_chars(text.chars(), position, baseline, |_| width)
+    }
+
+    fn line_height(&self) -> u32 {
+        self.font.character_size.height
+    }
+}
+
+impl<C> CharacterStyle for CompressedTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+
+    fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+        self.underline_color = underline_color;
+    }
+
+    fn set_strikethrough_color(&mut self, strikethrough_color: DecorationColor<Self::Color>) {
+        self.strikethrough_color = strikethrough_color;
+    }
+}
+
+render::text_style_builder!(
+    /// Text style builder for [`CompressedFont`]s.
+    ///
+    /// This builder provides the same methods as
+    /// [`MonoTextStyleBuilder`](embedded_graphics::mono_font::MonoTextStyleBuilder).
+    CompressedTextStyleBuilder,
+    CompressedTextStyle,
+    CompressedFont,
+    NULL_FONT
+);
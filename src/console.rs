@@ -0,0 +1,287 @@
+//! Text console for log output and panic screens.
+//!
+//! [`Console`] prints text to a [`DrawTarget`] like a terminal does. It keeps
+//! track of the cursor, wraps lines at the right edge of the display and
+//! scrolls up when the bottom is reached. Text gets printed with
+//! [`Console::print`] or with the `write!` macro as `Console` implements
+//! [`core::fmt::Write`].
+//!
+//! A draw target cannot be read back. So the console keeps the characters on
+//! the display in a grid of `COLUMNS` x `ROWS` characters for redrawing them
+//! when scrolling. This grid is part of the console and needs no allocation.
+//! Choose its size to fit the display. A grid larger than the display gets
+//! only used partially.
+//!
+//! # Example
+//!
+//! ```rust
+//! use core::fmt::Write;
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     pixelcolor::BinaryColor,
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{console::Console, FONT_6X8};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> core::fmt::Result {
+//! let mut display = MockDisplay::new();
+//! display.set_allow_overdraw(true);
+//!
+//! // The 64 x 64 pixel mock display fits 10 x 8 characters.
+//! let mut console: Console<_, 10, 8> =
+//!     Console::new(display, &FONT_6X8, BinaryColor::On, BinaryColor::Off);
+//! writeln!(console, "{} + {} = {}", 1, 2, 1 + 2)?;
+//!
+//! assert_eq!(console.cursor(), (0, 1));
+//! assert!(console.line(0).eq("1 + 2 = 3 ".chars()));
+//! # Ok(())
+//! # }
+//! ```
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline},
+};
+
+/// The distance between tab stops in characters.
+pub const TAB_WIDTH: usize = 8;
+
+/// A text console drawing to a [`DrawTarget`] with a [`MonoFont`].
+///
+/// The console fills the area of the target starting at the top left corner
+/// of its bounding box with as many characters as fit into it and into the
+/// grid of `COLUMNS` x `ROWS` characters.
+///
+/// Printable characters get drawn at the cursor which advances to the right.
+/// The following control characters are supported:
+///
+/// - `'\n'` moves the cursor to the start of the next line.
+/// - `'\r'` moves the cursor to the start of the current line.
+/// - `'\t'` moves the cursor to the next tab stop every [`TAB_WIDTH`]
+///   characters.
+/// - `'\u{8}'` (backspace) moves the cursor one character to the left and
+///   erases it.
+///
+/// All other control characters get ignored.
+#[derive(Debug)]
+pub struct Console<'a, D, const COLUMNS: usize, const ROWS: usize>
+where
+    D: DrawTarget,
+{
+    target: D,
+    style: MonoTextStyle<'a, D::Color>,
+    chars: [[char; COLUMNS]; ROWS],
+    columns: usize,
+    rows: usize,
+    column: usize,
+    row: usize,
+}
+
+impl<'a, D, const COLUMNS: usize, const ROWS: usize> Console<'a, D, COLUMNS, ROWS>
+where
+    D: DrawTarget,
+{
+    /// Creates a console drawing to `target` with `font` in `text_color` on
+    /// `background_color`.
+    ///
+    /// The console assumes `target` to be blank and does not draw anything
+    /// before printing. Call [`clear`](Self::clear) for clearing it first.
+    pub fn new(
+        target: D,
+        font: &'a MonoFont<'a>,
+        text_color: D::Color,
+        background_color: D::Color,
+    ) -> Self {
+        let area = target.bounding_box();
+        let cell = font.character_size + Size::new(font.character_spacing, 0);
+        let columns = area.size.width.checked_div(cell.width).unwrap_or(0) as usize;
+        let rows = area.size.height.checked_div(cell.height).unwrap_or(0) as usize;
+
+        let style = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(text_color)
+            .background_color(background_color)
+            .build();
+
+        Self {
+            target,
+            style,
+            chars: [[' '; COLUMNS]; ROWS],
+            columns: columns.min(COLUMNS),
+            rows: rows.min(ROWS),
+            column: 0,
+            row: 0,
+        }
+    }
+
+    /// Returns the number of columns and rows in use.
+    pub fn size(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    /// Returns the column and row of the cursor.
+    ///
+    /// The column equals the number of columns after printing a character to
+    /// the last one. The line gets wrapped when printing the next character.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.column, self.row)
+    }
+
+    /// Returns an iterator over the characters of `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is not less than the number of rows in use.
+    pub fn line(&self, row: usize) -> impl Iterator<Item = char> + '_ {
+        assert!(row < self.rows);
+        self.chars[row][..self.columns].iter().copied()
+    }
+
+    /// Returns a reference to the draw target.
+    pub fn target(&self) -> &D {
+        &self.target
+    }
+
+    /// Returns a mutable reference to the draw target.
+    ///
+    /// Drawing to it directly is not taken into account by the console.
+    pub fn target_mut(&mut self) -> &mut D {
+        &mut self.target
+    }
+
+    /// Returns the draw target.
+    pub fn release(self) -> D {
+        self.target
+    }
+
+    /// Clears the console and moves the cursor to the top left corner.
+    pub fn clear(&mut self) -> Result<(), D::Error> {
+        self.chars = [[' '; COLUMNS]; ROWS];
+        self.column = 0;
+        self.row = 0;
+
+        match self.style.background_color {
+            Some(color) => self.target.clear(color),
+            None => Ok(()),
+        }
+    }
+
+    /// Prints `text` at the cursor.
+    pub fn print(&mut self, text: &str) -> Result<(), D::Error> {
+        text.chars().try_for_each(|c| self.print_char(c))
+    }
+
+    /// Prints the single character `c` at the cursor.
+    pub fn print_char(&mut self, c: char) -> Result<(), D::Error> {
+        if self.columns == 0 || self.rows == 0 {
+            return Ok(());
+        }
+
+        match c {
+            '\n' => self.new_line(),
+            '\r' => {
+                self.column = 0;
+                Ok(())
+            }
+            '\t' => {
+                let stop = (self.column / TAB_WIDTH + 1) * TAB_WIDTH;
+                self.column = stop.min(self.columns);
+                Ok(())
+            }
+            '\u{8}' => {
+                if self.column > 0 {
+                    self.column -= 1;
+                    self.set(self.column, self.row, ' ')?;
+                }
+                Ok(())
+            }
+            c if c.is_control() => Ok(()),
+            c => {
+                if self.column >= self.columns {
+                    self.new_line()?;
+                }
+                self.set(self.column, self.row, c)?;
+                self.column += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// Moves the cursor to the start of the next line and scrolls if needed.
+    fn new_line(&mut self) -> Result<(), D::Error> {
+        self.column = 0;
+        if self.row + 1 < self.rows {
+            self.row += 1;
+            return Ok(());
+        }
+
+        // Scroll up by one line and redraw only the characters which change.
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                let c = if row + 1 < self.rows {
+                    self.chars[row + 1][column]
+                } else {
+                    ' '
+                };
+                self.set(column, row, c)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the character at `column` and `row` and draws it if it changed.
+    fn set(&mut self, column: usize, row: usize, c: char) -> Result<(), D::Error> {
+        if self.chars[row][column] == c {
+            return Ok(());
+        }
+        self.chars[row][column] = c;
+
+        let font = self.style.font;
+        let cell = font.character_size + Size::new(font.character_spacing, 0);
+        let position = self.target.bounding_box().top_left
+            + Point::new(
+                (column as u32 * cell.width) as i32,
+                (row as u32 * cell.height) as i32,
+            );
+
+        let mut buffer = [0u8; 4];
+        self.style.draw_string(
+            c.encode_utf8(&mut buffer),
+            position,
+            Baseline::Top,
+            &mut self.target,
+        )?;
+
+        // Erase the spacing after the character for fonts having one.
+        match self.style.background_color {
+            Some(color) if font.character_spacing > 0 => {
+                let spacing = Rectangle::new(
+                    position + font.character_size.x_axis(),
+                    Size::new(font.character_spacing, font.character_size.height),
+                );
+                self.target.fill_solid(&spacing, color)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<D, const COLUMNS: usize, const ROWS: usize> core::fmt::Write for Console<'_, D, COLUMNS, ROWS>
+where
+    D: DrawTarget,
+{
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.print(s).map_err(|_| core::fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.print_char(c).map_err(|_| core::fmt::Error)
+    }
+}
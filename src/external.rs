@@ -0,0 +1,849 @@
+//! Fonts loaded at runtime from external storage.
+//!
+//! Large fonts like [`FONT_24X32`](crate::FONT_24X32) take a considerable
+//! part of the internal flash of small MCUs. [`ExternalFont`] keeps only the
+//! header and the character mapping of a font in RAM and reads the glyphs
+//! from a [`FontStorage`], for example an external SPI flash, when they get
+//! drawn with an [`ExternalTextStyle`].
+//!
+//! The font data is stored in a container which [`write_container`] creates
+//! from any [`MonoFont`], for example on the host when preparing a flash
+//! image. With the feature `embedded-storage`, [`NorFlashStorage`] reads the
+//! container from any flash implementing
+//! [`ReadNorFlash`](embedded_storage::nor_flash::ReadNorFlash).
+//!
+//! # Container Format
+//!
+//! All numbers are stored in little endian byte order. The container starts
+//! with a header of [`HEADER_LEN`] bytes:
+//!
+//! | Offset | Size | Content |
+//! |-------:|-----:|---------|
+//! | 0 | 4 | [`MAGIC`] |
+//! | 4 | 2 | [`VERSION`] |
+//! | 6 | 2 | Character width |
+//! | 8 | 2 | Character height |
+//! | 10 | 2 | Character spacing |
+//! | 12 | 2 | Baseline |
+//! | 14 | 2 | Strikethrough offset |
+//! | 16 | 2 | Strikethrough height |
+//! | 18 | 2 | Underline offset |
+//! | 20 | 2 | Underline height |
+//! | 22 | 2 | Number of character ranges |
+//! | 24 | 2 | Number of glyphs |
+//! | 26 | 2 | Index of the replacement glyph |
+//!
+//! The character ranges follow with [`RANGE_LEN`] bytes each:
+//!
+//! | Offset | Size | Content |
+//! |-------:|-----:|---------|
+//! | 0 | 4 | Code point of the first character |
+//! | 4 | 2 | Number of characters |
+//! | 6 | 2 | Index of the glyph for the first character |
+//!
+//! The consecutive characters of a range have consecutive glyphs. Characters
+//! outside of all ranges get the replacement glyph.
+//!
+//! The glyphs come last. Every glyph is stored row by row from top to bottom
+//! with every row starting at a new byte and the leftmost pixel of a row in
+//! the most significant bit.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! use embedded_vintage_fonts::external::{
+//!     container_len, write_container, ExternalFont, ExternalTextStyle, FontStorage,
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::FONT_6X8;
+//!
+//! /// A stand-in for an external flash.
+//! struct Memory(Vec<u8>);
+//!
+//! impl FontStorage for Memory {
+//!     type Error = ();
+//!
+//!     fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+//!         let start = offset as usize;
+//!         let data = self.0.get(start..start + bytes.len()).ok_or(())?;
+//!         bytes.copy_from_slice(data);
+//!         Ok(())
+//!     }
+//! }
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut container = vec![0; container_len(&FONT_6X8)];
+//! write_container(&FONT_6X8, &mut container)?;
+//!
+//! let font = ExternalFont::new(Memory(container), 0)?;
+//! assert_eq!(font.header().character_size, Size::new(6, 8));
+//!
+//! let mut display = MockDisplay::new();
+//! let style = ExternalTextStyle::new(&font, BinaryColor::On);
+//! Text::with_baseline("Flash", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+//!
+//! let mut expected = MockDisplay::new();
+//! let style = MonoTextStyle::new(&FONT_6X8, BinaryColor::On);
+//! Text::with_baseline("Flash", Point::zero(), style, Baseline::Top).draw(&mut expected)?;
+//! assert_eq!(display, expected);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::GetPixel,
+    mono_font::{mapping::GlyphMapping, DecorationDimensions, MonoFont},
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::Rectangle,
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// The magic bytes at the start of a container.
+pub const MAGIC: [u8; 4] = *b"EVFC";
+
+/// The version of the container format.
+pub const VERSION: u16 = 1;
+
+/// Number of bytes of the header of a container.
+pub const HEADER_LEN: usize = 28;
+
+/// Number of bytes of a character range of a container.
+pub const RANGE_LEN: usize = 8;
+
+/// Maximum number of character ranges of an [`ExternalFont`].
+///
+/// The code page 437 variants of the fonts from this crate need 19 ranges.
+pub const MAX_RANGES: usize = 32;
+
+/// Maximum number of bytes of a glyph of an [`ExternalFont`].
+///
+/// This allows glyphs of up to 64 x 64 pixels.
+pub const MAX_GLYPH_LEN: usize = 512;
+
+/// Maximum read size of a flash supported by [`NorFlashStorage`].
+#[cfg(feature = "embedded-storage")]
+pub const MAX_READ_SIZE: usize = 64;
+
+/// Storage for reading fonts from.
+pub trait FontStorage {
+    /// Errors from reading the storage.
+    type Error;
+
+    /// Reads `bytes.len()` bytes starting at `offset`.
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl<S: FontStorage + ?Sized> FontStorage for &mut S {
+    type Error = S::Error;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        (**self).read(offset, bytes)
+    }
+}
+
+/// Adapter for reading fonts from a
+/// [`ReadNorFlash`](embedded_storage::nor_flash::ReadNorFlash).
+///
+/// Reads not aligned to the read size of the flash go through a buffer of
+/// [`MAX_READ_SIZE`] bytes.
+#[cfg(feature = "embedded-storage")]
+#[derive(Debug)]
+pub struct NorFlashStorage<F> {
+    flash: F,
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<F> NorFlashStorage<F>
+where
+    F: embedded_storage::nor_flash::ReadNorFlash,
+{
+    /// Creates an adapter for `flash`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the read size of the flash exceeds [`MAX_READ_SIZE`].
+    pub fn new(flash: F) -> Self {
+        assert!(F::READ_SIZE <= MAX_READ_SIZE);
+
+        Self { flash }
+    }
+
+    /// Returns the flash.
+    pub fn release(self) -> F {
+        self.flash
+    }
+}
+
+#[cfg(feature = "embedded-storage")]
+impl<F> FontStorage for NorFlashStorage<F>
+where
+    F: embedded_storage::nor_flash::ReadNorFlash,
+{
+    type Error = F::Error;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        let read_size = F::READ_SIZE.max(1);
+        if offset as usize % read_size == 0 && bytes.len() % read_size == 0 {
+            return self.flash.read(offset, bytes);
+        }
+
+        // Read whole aligned chunks and copy the requested part of them.
+        let mut chunk = [0; MAX_READ_SIZE];
+        let chunk_len = MAX_READ_SIZE / read_size * read_size;
+        let mut offset = offset as usize;
+        let mut bytes = bytes;
+        while !bytes.is_empty() {
+            let start = offset / read_size * read_size;
+            let len = chunk_len.min(self.flash.capacity().saturating_sub(start));
+            self.flash.read(start as u32, &mut chunk[..len])?;
+
+            let skip = offset - start;
+            let count = len.saturating_sub(skip).min(bytes.len());
+            let (head, tail) = bytes.split_at_mut(count);
+            head.copy_from_slice(&chunk[skip..skip + count]);
+
+            if count == 0 {
+                // Let the flash report reading past its end.
+                return self.flash.read(offset as u32, tail);
+            }
+
+            offset += count;
+            bytes = tail;
+        }
+
+        Ok(())
+    }
+}
+
+/// The header of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    /// Size of a single character in pixel.
+    pub character_size: Size,
+
+    /// Spacing between characters.
+    pub character_spacing: u32,
+
+    /// Offset from the top of the glyph bounding box to the baseline.
+    pub baseline: u32,
+
+    /// Strikethrough decoration dimensions.
+    pub strikethrough: DecorationDimensions,
+
+    /// Underline decoration dimensions.
+    pub underline: DecorationDimensions,
+
+    /// Number of character ranges.
+    pub range_count: u16,
+
+    /// Number of glyphs.
+    pub glyph_count: u16,
+
+    /// Index of the glyph for characters outside of all ranges.
+    pub replacement_glyph: u16,
+}
+
+impl Header {
+    /// Returns the number of bytes of a row of a glyph.
+    pub const fn row_len(&self) -> usize {
+        self.character_size.width.div_ceil(8) as usize
+    }
+
+    /// Returns the number of bytes of a glyph.
+    pub const fn glyph_len(&self) -> usize {
+        self.row_len() * self.character_size.height as usize
+    }
+
+    /// Returns the number of bytes of the container.
+    pub const fn container_len(&self) -> usize {
+        HEADER_LEN
+            + self.range_count as usize * RANGE_LEN
+            + self.glyph_count as usize * self.glyph_len()
+    }
+
+    fn parse<E>(bytes: &[u8; HEADER_LEN]) -> Result<Self, LoadError<E>> {
+        let field = |offset: usize| u16::from_le_bytes([bytes[offset], bytes[offset + 1]]);
+
+        if bytes[..4] != MAGIC {
+            return Err(LoadError::InvalidMagic);
+        }
+        if field(4) != VERSION {
+            return Err(LoadError::UnsupportedVersion(field(4)));
+        }
+
+        Ok(Self {
+            character_size: Size::new(field(6).into(), field(8).into()),
+            character_spacing: field(10).into(),
+            baseline: field(12).into(),
+            strikethrough: DecorationDimensions::new(field(14).into(), field(16).into()),
+            underline: DecorationDimensions::new(field(18).into(), field(20).into()),
+            range_count: field(22),
+            glyph_count: field(24),
+            replacement_glyph: field(26),
+        })
+    }
+
+    fn to_bytes(self) -> Result<[u8; HEADER_LEN], WriteError> {
+        let fields = [
+            VERSION.into(),
+            self.character_size.width,
+            self.character_size.height,
+            self.character_spacing,
+            self.baseline,
+            self.strikethrough.offset,
+            self.strikethrough.height,
+            self.underline.offset,
+            self.underline.height,
+            self.range_count.into(),
+            self.glyph_count.into(),
+            self.replacement_glyph.into(),
+        ];
+
+        let mut bytes = [0; HEADER_LEN];
+        bytes[..4].copy_from_slice(&MAGIC);
+        for (chunk, field) in bytes[4..].chunks_exact_mut(2).zip(fields) {
+            let field = u16::try_from(field).map_err(|_| WriteError::Unsupported)?;
+            chunk.copy_from_slice(&field.to_le_bytes());
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// Consecutive characters with consecutive glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct CharRange {
+    first: u32,
+    len: u16,
+    glyph: u16,
+}
+
+impl CharRange {
+    fn parse(bytes: &[u8; RANGE_LEN]) -> Self {
+        Self {
+            first: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            len: u16::from_le_bytes([bytes[4], bytes[5]]),
+            glyph: u16::from_le_bytes([bytes[6], bytes[7]]),
+        }
+    }
+
+    fn to_bytes(self) -> [u8; RANGE_LEN] {
+        let mut bytes = [0; RANGE_LEN];
+        bytes[..4].copy_from_slice(&self.first.to_le_bytes());
+        bytes[4..6].copy_from_slice(&self.len.to_le_bytes());
+        bytes[6..].copy_from_slice(&self.glyph.to_le_bytes());
+
+        bytes
+    }
+
+    fn glyph(&self, c: char) -> Option<u16> {
+        let offset = (c as u32).checked_sub(self.first)?;
+
+        (offset < self.len.into()).then(|| self.glyph + offset as u16)
+    }
+
+    fn end(&self) -> usize {
+        usize::from(self.glyph) + usize::from(self.len)
+    }
+}
+
+/// An error from loading an [`ExternalFont`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadError<E> {
+    /// Reading the storage failed.
+    Storage(E),
+    /// The data does not start with [`MAGIC`].
+    InvalidMagic,
+    /// The container has a version other than [`VERSION`].
+    UnsupportedVersion(u16),
+    /// The font has more than [`MAX_RANGES`] character ranges.
+    TooManyRanges,
+    /// The glyphs of the font take more than [`MAX_GLYPH_LEN`] bytes.
+    GlyphTooLarge,
+}
+
+impl<E: fmt::Debug> fmt::Display for LoadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Storage(error) => write!(f, "reading the font failed: {:?}", error),
+            Self::InvalidMagic => f.write_str("not a font container"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported font container version {}", version)
+            }
+            Self::TooManyRanges => f.write_str("too many character ranges"),
+            Self::GlyphTooLarge => f.write_str("glyphs too large"),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for LoadError<E> {}
+
+/// Monospace bitmap font with glyphs read from a [`FontStorage`].
+///
+/// This is the counterpart of [`MonoFont`] for fonts stored in a container.
+/// The glyphs get read from the storage one by one while drawing text with an
+/// [`ExternalTextStyle`].
+pub struct ExternalFont<S: FontStorage> {
+    storage: RefCell<S>,
+    error: Cell<Option<S::Error>>,
+    header: Header,
+    ranges: [CharRange; MAX_RANGES],
+    glyphs_offset: u32,
+}
+
+impl<S: FontStorage> ExternalFont<S> {
+    /// Loads the font from the container at `offset` of `storage`.
+    ///
+    /// This reads the header and the character ranges. The glyphs stay in
+    /// the storage.
+    pub fn new(mut storage: S, offset: u32) -> Result<Self, LoadError<S::Error>> {
+        let mut bytes = [0; HEADER_LEN];
+        storage
+            .read(offset, &mut bytes)
+            .map_err(LoadError::Storage)?;
+        let header = Header::parse(&bytes)?;
+
+        let range_count = usize::from(header.range_count);
+        if range_count > MAX_RANGES {
+            return Err(LoadError::TooManyRanges);
+        }
+        if header.character_size.width > 64 || header.glyph_len() > MAX_GLYPH_LEN {
+            return Err(LoadError::GlyphTooLarge);
+        }
+
+        let mut ranges = [CharRange::default(); MAX_RANGES];
+        let mut range_offset = offset + HEADER_LEN as u32;
+        for range in &mut ranges[..range_count] {
+            let mut bytes = [0; RANGE_LEN];
+            storage
+                .read(range_offset, &mut bytes)
+                .map_err(LoadError::Storage)?;
+            *range = CharRange::parse(&bytes);
+            range_offset += RANGE_LEN as u32;
+        }
+
+        Ok(Self {
+            storage: RefCell::new(storage),
+            error: Cell::new(None),
+            header,
+            ranges,
+            glyphs_offset: range_offset,
+        })
+    }
+
+    /// Returns the header of the font.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the index of the glyph for `c`.
+    pub fn glyph_index(&self, c: char) -> u16 {
+        self.ranges[..usize::from(self.header.range_count)]
+            .iter()
+            .find_map(|range| range.glyph(c))
+            .unwrap_or(self.header.replacement_glyph)
+    }
+
+    /// Reads the glyph for `c` into `bitmap`.
+    ///
+    /// This reads [`Header::glyph_len`] bytes with the rows of the glyph as
+    /// they are stored in the container.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bitmap` is shorter than [`Header::glyph_len`].
+    pub fn read_glyph(&self, c: char, bitmap: &mut [u8]) -> Result<(), S::Error> {
+        let len = self.header.glyph_len();
+        let offset = self.glyphs_offset + u32::from(self.glyph_index(c)) * len as u32;
+
+        self.storage.borrow_mut().read(offset, &mut bitmap[..len])
+    }
+
+    /// Returns the first error from reading a glyph while drawing text since
+    /// the last call.
+    ///
+    /// Errors from the storage can't be returned from drawing text. The
+    /// affected glyphs get drawn without any set pixels instead.
+    pub fn take_error(&self) -> Option<S::Error> {
+        self.error.take()
+    }
+
+    /// Returns the storage.
+    pub fn release(self) -> S {
+        self.storage.into_inner()
+    }
+
+    fn store_error(&self, error: S::Error) {
+        let previous = self.error.take();
+        self.error.set(previous.or(Some(error)));
+    }
+}
+
+impl<S: FontStorage> fmt::Debug for ExternalFont<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalFont")
+            .field("header", &self.header)
+            .field("glyphs_offset", &self.glyphs_offset)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Style properties for text using an [`ExternalFont`].
+///
+/// This is the counterpart of
+/// [`MonoTextStyle`](embedded_graphics::mono_font::MonoTextStyle) for
+/// external fonts. Use [`new`](ExternalTextStyle::new) for a text color on a
+/// transparent background and set the other properties directly.
+#[non_exhaustive]
+pub struct ExternalTextStyle<'a, S: FontStorage, C> {
+    /// Text color.
+    pub text_color: Option<C>,
+
+    /// Background color.
+    pub background_color: Option<C>,
+
+    /// Underline color.
+    pub underline_color: DecorationColor<C>,
+
+    /// Strikethrough color.
+    pub strikethrough_color: DecorationColor<C>,
+
+    /// Font.
+    pub font: &'a ExternalFont<S>,
+}
+
+impl<'a, S, C> ExternalTextStyle<'a, S, C>
+where
+    S: FontStorage,
+    C: PixelColor,
+{
+    /// Creates a text style with transparent background.
+    pub const fn new(font: &'a ExternalFont<S>, text_color: C) -> Self {
+        Self {
+            text_color: Some(text_color),
+            background_color: None,
+            underline_color: DecorationColor::None,
+            strikethrough_color: DecorationColor::None,
+            font,
+        }
+    }
+
+    /// Returns `true` if the style is transparent.
+    pub fn is_transparent(&self) -> bool {
+        self.text_color.is_none()
+            && self.background_color.is_none()
+            && self.underline_color.is_none()
+            && self.strikethrough_color.is_none()
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        let header = &self.font.header;
+
+        LineStyle {
+            text_color: self.text_color,
+            background_color: self.background_color,
+            underline_color: self.underline_color,
+            strikethrough_color: self.strikethrough_color,
+            character_height: header.character_size.height,
+            character_spacing: header.character_spacing,
+            baseline: header.baseline,
+            strikethrough: header.strikethrough,
+            underline: header.underline,
+        }
+    }
+
+    fn draw_glyph<D>(&self, c: char, position: Point, target: &mut D) -> Result<u32, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let header = &self.font.header;
+        let size = header.character_size;
+        let row_len = header.row_len();
+
+        let mut bitmap = [0; MAX_GLYPH_LEN];
+        if let Err(error) = self.font.read_glyph(c, &mut bitmap) {
+            bitmap.fill(0);
+            self.font.store_error(error);
+        }
+
+        let pixels = (0..size.height as usize).flat_map(|y| {
+            let row = &bitmap[y * row_len..][..row_len];
+            (0..size.width as usize).map(move |x| row[x / 8] & (0x80 >> (x % 8)) != 0)
+        });
+        render::draw_binary(
+            target,
+            &Rectangle::new(position, size),
+            pixels,
+            self.text_color,
+            self.background_color,
+        )?;
+
+        Ok(size.width)
+    }
+}
+
+impl<S: FontStorage, C: Clone> Clone for ExternalTextStyle<'_, S, C> {
+    fn clone(&self) -> Self {
+        Self {
+            text_color: self.text_color.clone(),
+            background_color: self.background_color.clone(),
+            underline_color: self.underline_color.clone(),
+            strikethrough_color: self.strikethrough_color.clone(),
+            font: self.font,
+        }
+    }
+}
+
+impl<S: FontStorage, C: Copy> Copy for ExternalTextStyle<'_, S, C> {}
+
+impl<S: FontStorage, C: fmt::Debug> fmt::Debug for ExternalTextStyle<'_, S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalTextStyle")
+            .field("text_color", &self.text_color)
+            .field("background_color", &self.background_color)
+            .field("underline_color", &self.underline_color)
+            .field("strikethrough_color", &self.strikethrough_color)
+            .field("font", &self.font)
+            .finish()
+    }
+}
+
+impl<S, C> TextRenderer for ExternalTextStyle<'_, S, C>
+where
+    S: FontStorage,
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_chars(text.chars(), position, baseline, target, |c, p, target| {
+                self.draw_glyph(c, p, target)
+            })
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.font.header.character_size.width;
+
+        self.line_style()
+            .measure_chars(text.chars(), position, baseline, |_| width)
+    }
+
+    fn line_height(&self) -> u32 {
+        self.font.header.character_size.height
+    }
+}
+
+impl<S, C> CharacterStyle for ExternalTextStyle<'_, S, C>
+where
+    S: FontStorage,
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+
+    fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+        self.underline_color = underline_color;
+    }
+
+    fn set_strikethrough_color(&mut self, strikethrough_color: DecorationColor<Self::Color>) {
+        self.strikethrough_color = strikethrough_color;
+    }
+}
+
+/// An error from writing a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteError {
+    /// The buffer is smaller than [`container_len`] bytes.
+    BufferTooSmall,
+    /// A dimension of the font or its number of glyphs exceeds the range of
+    /// the container format.
+    Unsupported,
+}
+
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall => f.write_str("buffer too small for the font container"),
+            Self::Unsupported => f.write_str("font not supported by the container format"),
+        }
+    }
+}
+
+impl core::error::Error for WriteError {}
+
+/// Iterator over the character ranges of a glyph mapping.
+///
+/// This checks every character and treats the ones mapped to the replacement
+/// glyph as unmapped unless they continue a range.
+struct CharRanges<'a> {
+    mapping: &'a dyn GlyphMapping,
+    replacement: usize,
+    next: u32,
+}
+
+impl<'a> CharRanges<'a> {
+    fn new(mapping: &'a dyn GlyphMapping) -> Self {
+        Self {
+            mapping,
+            replacement: mapping.index(char::REPLACEMENT_CHARACTER),
+            next: 0,
+        }
+    }
+}
+
+impl Iterator for CharRanges<'_> {
+    /// The first character, the number of characters and the first glyph.
+    type Item = (char, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, glyph) = (self.next..=char::MAX as u32)
+            .filter_map(char::from_u32)
+            .map(|c| (c, self.mapping.index(c)))
+            .find(|&(_, glyph)| glyph != self.replacement)?;
+
+        let len = (first as u32 + 1..=char::MAX as u32)
+            .map_while(char::from_u32)
+            .zip(glyph + 1..)
+            .take_while(|&(c, glyph)| self.mapping.index(c) == glyph)
+            .count()
+            + 1;
+        self.next = first as u32 + len as u32;
+
+        Some((first, len, glyph))
+    }
+}
+
+/// Returns the header for the container of `font`.
+fn header(font: &MonoFont<'_>) -> Result<Header, WriteError> {
+    let mut range_count = 0usize;
+    let mut glyph_count = font.glyph_mapping.index(char::REPLACEMENT_CHARACTER) + 1;
+    for (_, len, glyph) in CharRanges::new(font.glyph_mapping) {
+        range_count += len.div_ceil(usize::from(u16::MAX));
+        glyph_count = glyph_count.max(glyph + len);
+    }
+
+    let count = |count: usize| u16::try_from(count).map_err(|_| WriteError::Unsupported);
+    Ok(Header {
+        character_size: font.character_size,
+        character_spacing: font.character_spacing,
+        baseline: font.baseline,
+        strikethrough: font.strikethrough,
+        underline: font.underline,
+        range_count: count(range_count)?,
+        glyph_count: count(glyph_count)?,
+        replacement_glyph: count(font.glyph_mapping.index(char::REPLACEMENT_CHARACTER))?,
+    })
+}
+
+/// Returns the number of bytes of the container for `font`.
+///
+/// The container covers all characters `font` has glyphs for. Like
+/// [`write_container`], this checks the glyph mapping for every character.
+///
+/// # Panics
+///
+/// Panics if `font` is not supported by the container format.
+pub fn container_len(font: &MonoFont<'_>) -> usize {
+    header(font)
+        .expect("font not supported by the container format")
+        .container_len()
+}
+
+/// Writes the container for `font` to `buffer` and returns its length.
+///
+/// The character ranges get derived from the glyph mapping of the font.
+/// Characters mapped to the same glyph as [`char::REPLACEMENT_CHARACTER`] are
+/// left out as they get the replacement glyph anyway.
+pub fn write_container(font: &MonoFont<'_>, buffer: &mut [u8]) -> Result<usize, WriteError> {
+    let header = header(font)?;
+    let header_bytes = header.to_bytes()?;
+    let len = header.container_len();
+    let buffer = buffer.get_mut(..len).ok_or(WriteError::BufferTooSmall)?;
+    buffer.fill(0);
+
+    let (header_buffer, data) = buffer.split_at_mut(HEADER_LEN);
+    header_buffer.copy_from_slice(&header_bytes);
+
+    let (range_bytes, glyph_bytes) = data.split_at_mut(usize::from(header.range_count) * RANGE_LEN);
+    let ranges = CharRanges::new(font.glyph_mapping).flat_map(|(first, len, glyph)| {
+        // Split ranges exceeding the number of characters of a single range.
+        (0..len)
+            .step_by(usize::from(u16::MAX))
+            .map(move |offset| CharRange {
+                first: first as u32 + offset as u32,
+                len: (len - offset).min(usize::from(u16::MAX)) as u16,
+                glyph: (glyph + offset) as u16,
+            })
+    });
+    for (bytes, range) in range_bytes.chunks_exact_mut(RANGE_LEN).zip(ranges) {
+        debug_assert!(range.end() <= usize::from(header.glyph_count));
+        bytes.copy_from_slice(&range.to_bytes());
+    }
+
+    let size = font.character_size;
+    let row_len = header.row_len();
+    for (index, glyph) in glyph_bytes.chunks_exact_mut(header.glyph_len()).enumerate() {
+        let cell = render::glyph_cell(&font.image, size, index);
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let point = cell.top_left + Point::new(x as i32, y as i32);
+                if font.image.pixel(point) == Some(BinaryColor::On) {
+                    glyph[y as usize * row_len + x as usize / 8] |= 0x80 >> (x % 8);
+                }
+            }
+        }
+    }
+
+    Ok(len)
+}
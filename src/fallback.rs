@@ -0,0 +1,212 @@
+//! Transliterating fallback for unsupported characters.
+//!
+//! The fonts from this crate draw the glyph for '?' for every character they
+//! do not support. [`FallbackTextStyle`] replaces such characters with
+//! similar looking ones from ASCII before drawing them. For example "Łódź"
+//! becomes "Lódz" with [`FONT_6X8`](crate::FONT_6X8) and "Lodz" with the ASCII
+//! only [`FONT_6X12`](crate::FONT_6X12). See [`transliterate`] for the
+//! supported replacements.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{renderer::TextRenderer, Baseline},
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{fallback::FallbackTextStyle, FONT_6X8};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() {
+//! let style = FallbackTextStyle::new(MonoTextStyle::new(&FONT_6X8, BinaryColor::On));
+//!
+//! // The euro sign gets drawn as "EUR".
+//! let metrics = style.measure_string("3 €", Point::zero(), Baseline::Top);
+//! assert_eq!(metrics.bounding_box.size.width, 5 * 6);
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    mono_font::{MonoFont, MonoTextStyle},
+    pixelcolor::PixelColor,
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// Replacements for the Latin-1 letters from 'À' to 'ÿ' and Latin Extended-A.
+#[rustfmt::skip]
+const LATIN: [&str; 0x180 - 0xc0] = [
+    "A", "A", "A", "A", "A", "A", "AE", "C", "E", "E", "E", "E", "I", "I", "I", "I", // À..=Ï
+    "D", "N", "O", "O", "O", "O", "O", "x", "O", "U", "U", "U", "U", "Y", "Th", "ss", // Ð..=ß
+    "a", "a", "a", "a", "a", "a", "ae", "c", "e", "e", "e", "e", "i", "i", "i", "i", // à..=ï
+    "d", "n", "o", "o", "o", "o", "o", "/", "o", "u", "u", "u", "u", "y", "th", "y", // ð..=ÿ
+    "A", "a", "A", "a", "A", "a", "C", "c", "C", "c", "C", "c", "C", "c", "D", "d", // Ā..=ď
+    "D", "d", "E", "e", "E", "e", "E", "e", "E", "e", "E", "e", "G", "g", "G", "g", // Đ..=ğ
+    "G", "g", "G", "g", "H", "h", "H", "h", "I", "i", "I", "i", "I", "i", "I", "i", // Ġ..=į
+    "I", "i", "IJ", "ij", "J", "j", "K", "k", "k", "L", "l", "L", "l", "L", "l", "L", // İ..=Ŀ
+    "l", "L", "l", "N", "n", "N", "n", "N", "n", "'n", "N", "n", "O", "o", "O", "o", // ŀ..=ŏ
+    "O", "o", "OE", "oe", "R", "r", "R", "r", "R", "r", "S", "s", "S", "s", "S", "s", // Ő..=ş
+    "S", "s", "T", "t", "T", "t", "T", "t", "U", "u", "U", "u", "U", "u", "U", "u", // Š..=ů
+    "U", "u", "U", "u", "W", "w", "Y", "y", "Y", "Z", "z", "Z", "z", "Z", "z", "s", // Ű..=ſ
+];
+
+/// Returns an ASCII replacement for `c`.
+///
+/// Supported are
+///
+/// * the letters from Latin-1 and Latin Extended-A which get replaced by their
+///   base letter ('Ł' becomes "L") or spelled out ('ß' becomes "ss"),
+/// * typographic quotes, dashes, spaces and the ellipsis ('“' becomes "\"",
+///   '—' becomes "--" and '…' becomes "..."),
+/// * some symbols like the euro sign which becomes "EUR".
+///
+/// Returns `None` for all other characters.
+pub fn transliterate(c: char) -> Option<&'static str> {
+    let replacement = match c {
+        'À'..='ſ' => LATIN[c as usize - 0xc0],
+        '\u{a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' => " ",
+        '«' => "<<",
+        '»' => ">>",
+        '©' => "(C)",
+        '®' => "(R)",
+        '\u{ad}' | '‐' | '‑' | '‒' | '–' | '−' => "-",
+        '—' | '―' => "--",
+        '‘' | '’' | '‚' | '‛' | '′' => "'",
+        '“' | '”' | '„' | '‟' | '″' => "\"",
+        '‹' => "<",
+        '›' => ">",
+        '…' => "...",
+        '•' | '·' => "*",
+        '€' => "EUR",
+        '™' => "TM",
+        _ => return None,
+    };
+
+    Some(replacement)
+}
+
+/// Returns `true` if `font` has a glyph for `c`.
+///
+/// The fonts from this crate use the glyph for '?' for unsupported
+/// characters.
+fn is_supported(font: &MonoFont<'_>, c: char) -> bool {
+    c == '?' || font.glyph_mapping.index(c) != font.glyph_mapping.index('?')
+}
+
+/// Text style drawing unsupported characters with their [`transliterate`]d
+/// replacements.
+///
+/// Characters the font supports get drawn as they are. Only characters the
+/// font would draw as '?' get replaced. This works with all fonts using a
+/// [`MonoTextStyle`] which fall back to '?' like the ones from this crate.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FallbackTextStyle<'a, C> {
+    /// The style of the font.
+    pub style: MonoTextStyle<'a, C>,
+}
+
+impl<'a, C> FallbackTextStyle<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a style drawing text with `style` and transliterating
+    /// unsupported characters.
+    pub const fn new(style: MonoTextStyle<'a, C>) -> Self {
+        Self { style }
+    }
+
+    /// Returns the characters to draw for `text`.
+    fn chars<'t>(&self, text: &'t str) -> impl Iterator<Item = char> + 't
+    where
+        'a: 't,
+    {
+        let font = self.style.font;
+
+        text.char_indices().flat_map(move |(i, c)| {
+            let original = &text[i..i + c.len_utf8()];
+            let replacement = if is_supported(font, c) {
+                None
+            } else {
+                transliterate(c)
+            };
+
+            replacement.unwrap_or(original).chars()
+        })
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle::from(&self.style)
+    }
+}
+
+impl<C> TextRenderer for FallbackTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style().draw_chars(
+            self.chars(text),
+            position,
+            baseline,
+            target,
+            |c, p, target| {
+                render::draw_mono_glyph(
+                    self.style.font,
+                    c,
+                    p,
+                    self.style.text_color,
+                    self.style.background_color,
+                    target,
+                )
+            },
+        )
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.style.font.character_size.width;
+
+        self.line_style()
+            .measure_chars(self.chars(text), position, baseline, |_| width)
+    }
+
+    fn line_height(&self) -> u32 {
+        self.style.font.character_size.height
+    }
+}
+
+render::forward_character_style!(FallbackTextStyle);
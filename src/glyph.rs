@@ -0,0 +1,165 @@
+//! Access to the bitmaps of individual glyphs.
+//!
+//! [`Glyph`] provides the pixels of the glyph for a single character. It
+//! implements [`ImageDrawable`] and can be drawn with
+//! [`Image`](embedded_graphics::image::Image) like any other image, for
+//! example for building icons from the glyphs of a font.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     image::Image,
+//!     mock_display::MockDisplay,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{glyph::Glyph, FONT_6X8};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let glyph = Glyph::new(&FONT_6X8, 'T');
+//!
+//! assert_eq!(glyph.size(), Size::new(6, 8));
+//! assert_eq!(glyph.rows().next(), Some(0b111110));
+//! assert_eq!(glyph.pixels().count(), 11);
+//!
+//! let mut display = MockDisplay::new();
+//! Image::new(&glyph, Point::zero()).draw(&mut display)?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         "#####.",
+//!         "..#...",
+//!         "..#...",
+//!         "..#...",
+//!         "..#...",
+//!         "..#...",
+//!         "..#...",
+//!         "......",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    image::{GetPixel, ImageDrawable, ImageRaw},
+    mono_font::MonoFont,
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+};
+
+/// The bitmap of a single glyph.
+///
+/// The coordinates of the pixels are relative to the top left corner of the
+/// glyph.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Glyph<'a> {
+    image: ImageRaw<'a, BinaryColor>,
+    area: Rectangle,
+}
+
+impl<'a> Glyph<'a> {
+    /// Looks up the glyph for `c` in `font`.
+    ///
+    /// Unsupported characters get the replacement glyph of the font like when
+    /// drawing text.
+    pub fn new(font: &MonoFont<'a>, c: char) -> Self {
+        let area = render::glyph_cell(
+            &font.image,
+            font.character_size,
+            font.glyph_mapping.index(c),
+        );
+
+        Self::from_image(font.image, area)
+    }
+
+    /// Creates the glyph occupying `area` of `image`.
+    pub(crate) const fn from_image(image: ImageRaw<'a, BinaryColor>, area: Rectangle) -> Self {
+        Self { image, area }
+    }
+
+    /// Returns `true` if the pixel at `point` is set.
+    ///
+    /// Points outside of the glyph are never set.
+    pub fn is_set(&self, point: Point) -> bool {
+        self.pixel(point) == Some(BinaryColor::On)
+    }
+
+    /// Returns an iterator over the set pixels.
+    pub fn pixels(&self) -> impl Iterator<Item = Point> + '_ {
+        self.bounding_box()
+            .points()
+            .filter(move |&p| self.is_set(p))
+    }
+
+    /// Returns an iterator over the rows of the glyph as bit masks.
+    ///
+    /// The leftmost pixel of a row is at bit `width - 1` and the rightmost one
+    /// at bit 0. Rows of glyphs wider than 32 pixels get truncated to their
+    /// leftmost 32 pixels with the leftmost one at bit 31.
+    pub fn rows(&self) -> impl Iterator<Item = u32> + '_ {
+        let size = self.size();
+        let width = size.width.min(u32::BITS);
+
+        (0..size.height as i32).map(move |y| {
+            (0..width as i32).fold(0, |row, x| {
+                row << 1 | u32::from(self.is_set(Point::new(x, y)))
+            })
+        })
+    }
+
+    /// Returns the area of the glyph relative to its top left corner.
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(Point::zero(), self.area.size)
+    }
+}
+
+impl OriginDimensions for Glyph<'_> {
+    fn size(&self) -> Size {
+        self.area.size
+    }
+}
+
+impl GetPixel for Glyph<'_> {
+    type Color = BinaryColor;
+
+    fn pixel(&self, point: Point) -> Option<Self::Color> {
+        if self.bounding_box().contains(point) {
+            self.image.pixel(self.area.top_left + point)
+        } else {
+            None
+        }
+    }
+}
+
+impl ImageDrawable for Glyph<'_> {
+    type Color = BinaryColor;
+
+    fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.draw_sub_image(target, &self.bounding_box())
+    }
+
+    fn draw_sub_image<D>(&self, target: &mut D, area: &Rectangle) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let area = area.intersection(&self.bounding_box());
+        let pixels = area
+            .points()
+            .map(|p| self.pixel(p).unwrap_or(BinaryColor::Off));
+
+        target.fill_contiguous(&Rectangle::new(Point::zero(), area.size), pixels)
+    }
+}
@@ -19,8 +19,12 @@
 //!     prelude::*,
 //!     text::Text,
 //! };
+//! # #[cfg(feature = "font-6x8")]
 //! use embedded_vintage_fonts::FONT_6X8;
 //!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
 //! # fn main() -> Result<(), core::convert::Infallible> {
 //! let mut display = MockDisplay::new();
 //! let style = MonoTextStyle::new(&FONT_6X8, BinaryColor::On);
@@ -49,82 +53,380 @@
 //! Most fonts from this crate provide support for [ISO/IEC
 //! 8859-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) (Latin-1).
 //! [`FONT_6X12`] supports only [ASCII](https://en.wikipedia.org/wiki/ASCII)
-//! characters.
+//! characters. [`FONT_6X6`] supports ASCII and the range from '¡' to '¿' of
+//! Latin-1 where the currency sign '¤' got replaced by the euro sign '€'.
+//!
+//! Unsupported characters get drawn as '?'. Use
+//! [`FallbackTextStyle`](fallback::FallbackTextStyle) for drawing similar
+//! looking ASCII characters instead, like "Lodz" for "Łódź".
+//!
+//! [`FONT_6X8_CP437`] and [`FONT_8X16_CP437`] extend [`FONT_6X8`] and
+//! [`FONT_8X16`] with the box-drawing, block and shade characters from [code
+//! page 437](https://en.wikipedia.org/wiki/Code_page_437) for drawing DOS-style
+//! frames and bars:
+//!
+//! ```text
+//! ╔═══╤═══╗
+//! ║ ░ │ ▓ ║
+//! ╚═══╧═══╝
+//! ```
+//!
+//! # Cargo Features
+//!
+//! Every font is included by a cargo feature of its own: `font-6x6`,
+//! `font-6x8`, `font-6x8-cp437`, `font-6x12`, `font-8x16`, `font-8x16-cp437`,
+//! `font-12x16` and `font-24x32`. They are all enabled by default. Disable the
+//! default features and enable only the fonts actually used for keeping the
+//! bitmaps of the others out of firmware builds:
+//!
+//! ```toml
+//! [dependencies]
+//! embedded-vintage-fonts = { version = "0.2", default-features = false, features = ["font-6x8"] }
+//! ```
+//!
+//! # Variable-Width Glyphs
+//!
+//! [`FONT_6X6`] uses variable-width glyphs and is a
+//! [`ProportionalFont`]. It gets drawn with
+//! [`ProportionalTextStyle`](proportional::ProportionalTextStyle) instead of
+//! [`MonoTextStyle`](embedded_graphics::mono_font::MonoTextStyle):
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::Text,
+//! };
+//! # #[cfg(feature = "font-6x6")]
+//! use embedded_vintage_fonts::{proportional::ProportionalTextStyle, FONT_6X6};
+//!
+//! # #[cfg(not(feature = "font-6x6"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x6")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let mut display = MockDisplay::new();
+//! let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+//!
+//! Text::new("Hi!", Point::new(0, FONT_6X6.baseline as i32), style)
+//!     .draw(&mut display)?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         "#  # # #",
+//!         "#  #   #",
+//!         "#### # #",
+//!         "#  # #  ",
+//!         "#  # # #",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Compressed Glyphs
+//!
+//! The module [`compressed`] provides variants of the monospace fonts which
+//! store their glyphs compressed and get drawn by
+//! [`CompressedTextStyle`](compressed::CompressedTextStyle).
+//!
+//! # Glyph Subsets
+//!
+//! [`vintage_subset!`] creates a [`MonoFont`] at compile time containing only
+//! the glyphs for a few characters, for example the digits for a clock.
+//!
+//! # External Fonts
+//!
+//! [`ExternalFont`](external::ExternalFont) reads the glyphs of a font from
+//! external storage like an SPI flash while drawing. The fonts get stored in a
+//! container written by [`write_container`](external::write_container). With
+//! the feature `embedded-storage`, they can be read from any NOR flash
+//! implementing the traits from
+//! [embedded-storage](https://docs.rs/embedded-storage).
+//!
+//! # Glyph Bitmaps
+//!
+//! [`Glyph`](glyph::Glyph) gives access to the bitmap of the glyph for a
+//! single character, for example for drawing it as an image.
+//!
+//! # Byte Strings
+//!
+//! [`ByteText`](byte_text::ByteText) draws Latin-1 or code page 437 encoded
+//! byte strings without converting them to UTF-8 first.
+//!
+//! # Rotated Text
+//!
+//! [`Rotated`](rotated::Rotated) draws text with any text style rotated by
+//! 90°, 180° or 270° for displays mounted sideways or upside down.
+//!
+//! # Line Buffers
+//!
+//! [`LineBuffer`](scanline::LineBuffer) renders text row by row or page by
+//! page into a small buffer for streaming it to displays without a
+//! framebuffer.
+//!
+//! The [`page`] module provides [`FONT_6X8`] and [`FONT_8X16`] with glyphs in
+//! the vertical bytes of SSD1306-style displays and copies them straight into
+//! their display memory.
+//!
+//! # Bold and Italic
+//!
+//! [`SyntheticTextStyle`](synthetic::SyntheticTextStyle) derives bold and
+//! italic variants from the glyphs of the monospace fonts.
+//!
+//! # Faster Drawing
+//!
+//! [`SpanTextStyle`](span::SpanTextStyle) draws the monospace fonts with
+//! rectangles for runs of pixels instead of pixel by pixel for displays with
+//! fast fills.
+//!
+//! # Text Console
+//!
+//! [`Console`](console::Console) prints text with line wrapping and scrolling
+//! for log output and panic screens. It implements [`core::fmt::Write`] and
+//! needs no allocation.
+//!
+//! [`Terminal`](terminal::Terminal) additionally interprets ANSI escape
+//! sequences for cursor movement, erasing and colors like a VT100 terminal.
+//!
+//! # Ratatui
+//!
+//! With the feature `ratatui`, [`DrawTargetBackend`](ratatui::DrawTargetBackend)
+//! draws [ratatui](https://ratatui.rs) user interfaces to any draw target with
+//! the monospace fonts.
+//!
+//! # Selecting Fonts at Runtime
+//!
+//! [`Font`](registry::Font) lists all enabled fonts with their e-g 0.6 names,
+//! character sizes and glyph coverage. It parses font names from configuration
+//! files and finds the largest font for a text fitting into a given area.
+
+pub mod byte_text;
+pub mod compressed;
+pub mod console;
+pub mod external;
+pub mod fallback;
+pub mod glyph;
+pub mod mapping;
+pub mod page;
+pub mod proportional;
+#[cfg(feature = "ratatui")]
+pub mod ratatui;
+pub mod registry;
+mod render;
+pub mod rotated;
+pub mod scaled;
+pub mod scanline;
+pub mod span;
+pub mod subset;
+pub mod synthetic;
+pub mod terminal;
 
+// Depending on the enabled font features, not all of these get used.
+#[allow(unused_imports)]
 use embedded_graphics::{
     geometry::Size,
     image::ImageRaw,
-    mono_font::{mapping::StrGlyphMapping, DecorationDimensions, MonoFont},
+    mono_font::{DecorationDimensions, MonoFont},
 };
+#[cfg(feature = "font-6x6")]
+use proportional::ProportionalFont;
 
-const GLYPH_MAPPING: StrGlyphMapping =
-    StrGlyphMapping::new("\0\u{20}\u{7e}\0\u{a1}\u{ff}", '?' as usize - ' ' as usize);
-const GLYPH_MAPPING_6X12: StrGlyphMapping =
-    StrGlyphMapping::new("\0\u{20}\u{7e}", '?' as usize - ' ' as usize);
+/// The glyph sheet of [`FONT_24X32`].
+#[cfg(feature = "font-24x32")]
+pub(crate) const FONT_24X32_RAW: &[u8] = include_bytes!("../data/font24x32_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_24X32`] in pixels.
+#[cfg(feature = "font-24x32")]
+pub(crate) const FONT_24X32_IMAGE_WIDTH: u32 = 960;
+
+/// The glyph sheet of [`FONT_12X16`].
+#[cfg(feature = "font-12x16")]
+pub(crate) const FONT_12X16_RAW: &[u8] = include_bytes!("../data/font12x16_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_12X16`] in pixels.
+#[cfg(feature = "font-12x16")]
+pub(crate) const FONT_12X16_IMAGE_WIDTH: u32 = 480;
+
+/// The glyph sheet of [`FONT_8X16`].
+#[cfg(feature = "font-8x16")]
+pub(crate) const FONT_8X16_RAW: &[u8] = include_bytes!("../data/font8x16_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_8X16`] in pixels.
+#[cfg(feature = "font-8x16")]
+pub(crate) const FONT_8X16_IMAGE_WIDTH: u32 = 240;
+
+/// The glyph sheet of [`FONT_8X16_CP437`].
+#[cfg(feature = "font-8x16-cp437")]
+pub(crate) const FONT_8X16_CP437_RAW: &[u8] = include_bytes!("../data/font8x16_cp437_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_8X16_CP437`] in pixels.
+#[cfg(feature = "font-8x16-cp437")]
+pub(crate) const FONT_8X16_CP437_IMAGE_WIDTH: u32 = 240;
+
+/// The glyph sheet of [`FONT_6X12`].
+#[cfg(feature = "font-6x12")]
+pub(crate) const FONT_6X12_RAW: &[u8] = include_bytes!("../data/font6x12_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_6X12`] in pixels.
+#[cfg(feature = "font-6x12")]
+pub(crate) const FONT_6X12_IMAGE_WIDTH: u32 = 96;
+
+/// The glyph sheet of [`FONT_6X8`].
+#[cfg(feature = "font-6x8")]
+pub(crate) const FONT_6X8_RAW: &[u8] = include_bytes!("../data/font6x8_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_6X8`] in pixels.
+#[cfg(feature = "font-6x8")]
+pub(crate) const FONT_6X8_IMAGE_WIDTH: u32 = 240;
+
+/// The glyph sheet of [`FONT_6X8_CP437`].
+#[cfg(feature = "font-6x8-cp437")]
+pub(crate) const FONT_6X8_CP437_RAW: &[u8] = include_bytes!("../data/font6x8_cp437_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_6X8_CP437`] in pixels.
+#[cfg(feature = "font-6x8-cp437")]
+pub(crate) const FONT_6X8_CP437_IMAGE_WIDTH: u32 = 240;
+
+/// The glyph sheet of [`FONT_6X6`].
+#[cfg(feature = "font-6x6")]
+pub(crate) const FONT_6X6_RAW: &[u8] = include_bytes!("../data/font6x6_1bpp.raw");
+/// The width of the glyph sheet of [`FONT_6X6`] in pixels.
+#[cfg(feature = "font-6x6")]
+pub(crate) const FONT_6X6_IMAGE_WIDTH: u32 = 192;
 
 /// An upscaled version of [`FONT_12X16`] previously known as `Font24x32`.
+///
+/// [`ScaledTextStyle`](scaled::ScaledTextStyle) renders the very same glyphs
+/// from the bitmap of [`FONT_12X16`] scaled by two. Only the underline differs:
+/// it is drawn one pixel lower at offset 30 instead of 29.
+#[cfg(feature = "font-24x32")]
 pub const FONT_24X32: MonoFont = MonoFont {
-    image: ImageRaw::new(include_bytes!("../data/font24x32_1bpp.raw"), 960),
+    image: ImageRaw::new(FONT_24X32_RAW, FONT_24X32_IMAGE_WIDTH),
     character_size: Size::new(24, 32),
     character_spacing: 0,
     baseline: 27,
     strikethrough: DecorationDimensions::new(14, 4),
     underline: DecorationDimensions::new(29, 4),
-    glyph_mapping: &GLYPH_MAPPING,
+    glyph_mapping: &mapping::ISO_8859_1,
 };
 
 /// The 12 x 16 pixel font formerly known `Font12x16`.
 ///
 /// ![Source
 /// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font12x16.png)
+#[cfg(feature = "font-12x16")]
 pub const FONT_12X16: MonoFont = MonoFont {
-    image: ImageRaw::new(include_bytes!("../data/font12x16_1bpp.raw"), 480),
+    image: ImageRaw::new(FONT_12X16_RAW, FONT_12X16_IMAGE_WIDTH),
     character_size: Size::new(12, 16),
     character_spacing: 0,
     baseline: 13,
     strikethrough: DecorationDimensions::new(7, 2),
     underline: DecorationDimensions::new(15, 2),
-    glyph_mapping: &GLYPH_MAPPING,
+    glyph_mapping: &mapping::ISO_8859_1,
 };
 
 /// The 8 x 16 pixel font formerly known `Font8x16`.
 ///
 /// ![Source
 /// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font8x16.png)
+#[cfg(feature = "font-8x16")]
 pub const FONT_8X16: MonoFont = MonoFont {
-    image: ImageRaw::new(include_bytes!("../data/font8x16_1bpp.raw"), 240),
+    image: ImageRaw::new(FONT_8X16_RAW, FONT_8X16_IMAGE_WIDTH),
+    character_size: Size::new(8, 16),
+    character_spacing: 0,
+    baseline: 11,
+    strikethrough: DecorationDimensions::new(6, 2),
+    underline: DecorationDimensions::new(13, 2),
+    glyph_mapping: &mapping::ISO_8859_1,
+};
+
+/// [`FONT_8X16`] with the box-drawing, block and shade characters from code
+/// page 437.
+///
+/// See [`mapping::CP437_GRAPHICS`] for the additional characters.
+///
+/// ![Source
+/// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font8x16_cp437.png)
+#[cfg(feature = "font-8x16-cp437")]
+pub const FONT_8X16_CP437: MonoFont = MonoFont {
+    image: ImageRaw::new(FONT_8X16_CP437_RAW, FONT_8X16_CP437_IMAGE_WIDTH),
     character_size: Size::new(8, 16),
     character_spacing: 0,
     baseline: 11,
     strikethrough: DecorationDimensions::new(6, 2),
     underline: DecorationDimensions::new(13, 2),
-    glyph_mapping: &GLYPH_MAPPING,
+    glyph_mapping: &mapping::CP437,
 };
 
 /// The 6 x 12 pixel font formerly known `Font6x12`.
 ///
 /// ![Source
 /// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font6x12.png)
+#[cfg(feature = "font-6x12")]
 pub const FONT_6X12: MonoFont = MonoFont {
-    image: ImageRaw::new(include_bytes!("../data/font6x12_1bpp.raw"), 96),
+    image: ImageRaw::new(FONT_6X12_RAW, FONT_6X12_IMAGE_WIDTH),
     character_size: Size::new(6, 12),
     character_spacing: 0,
     baseline: 9,
     strikethrough: DecorationDimensions::new(5, 1),
     underline: DecorationDimensions::new(11, 1),
-    glyph_mapping: &GLYPH_MAPPING_6X12,
+    glyph_mapping: &mapping::ASCII,
 };
 
 /// The 6 x 8 pixel font formerly known `Font6x8`.
 ///
 /// ![Source
 /// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font6x8.png)
+#[cfg(feature = "font-6x8")]
 pub const FONT_6X8: MonoFont = MonoFont {
-    image: ImageRaw::new(include_bytes!("../data/font6x8_1bpp.raw"), 240),
+    image: ImageRaw::new(FONT_6X8_RAW, FONT_6X8_IMAGE_WIDTH),
     character_size: Size::new(6, 8),
     character_spacing: 0,
     baseline: 6,
     strikethrough: DecorationDimensions::new(3, 1),
     underline: DecorationDimensions::new(8, 1),
-    glyph_mapping: &GLYPH_MAPPING,
+    glyph_mapping: &mapping::ISO_8859_1,
+};
+
+/// [`FONT_6X8`] with the box-drawing, block and shade characters from code
+/// page 437.
+///
+/// See [`mapping::CP437_GRAPHICS`] for the additional characters.
+///
+/// ![Source
+/// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font6x8_cp437.png)
+#[cfg(feature = "font-6x8-cp437")]
+pub const FONT_6X8_CP437: MonoFont = MonoFont {
+    image: ImageRaw::new(FONT_6X8_CP437_RAW, FONT_6X8_CP437_IMAGE_WIDTH),
+    character_size: Size::new(6, 8),
+    character_spacing: 0,
+    baseline: 6,
+    strikethrough: DecorationDimensions::new(3, 1),
+    underline: DecorationDimensions::new(8, 1),
+    glyph_mapping: &mapping::CP437,
+};
+
+/// Maps characters to glyph indices like `Font6x6::char_offset` did in e-g
+/// 0.6.
+#[cfg(feature = "font-6x6")]
+fn glyph_index_6x6(c: char) -> usize {
+    let fallback = '?' as usize - ' ' as usize;
+
+    match c {
+        ' '..='~' => c as usize - ' ' as usize,
+        '€' => 100,
+        '¡'..='¿' => c as usize - ' ' as usize - 0x20,
+        _ => fallback,
+    }
+}
+
+/// The 6 x 6 pixel font with variable-width glyphs formerly known as
+/// `Font6x6`.
+///
+/// ![Source
+/// image](https://raw.githubusercontent.com/sirhcel/embedded-vintage-fonts/master/data/font6x6.png)
+#[cfg(feature = "font-6x6")]
+pub const FONT_6X6: ProportionalFont = ProportionalFont {
+    image: ImageRaw::new(FONT_6X6_RAW, FONT_6X6_IMAGE_WIDTH),
+    character_size: Size::new(6, 6),
+    character_spacing: 1,
+    baseline: 4,
+    strikethrough: DecorationDimensions::new(2, 1),
+    underline: DecorationDimensions::new(5, 1),
+    glyph_mapping: &glyph_index_6x6,
 };
@@ -0,0 +1,132 @@
+//! Glyph mappings for the fonts from this crate.
+//!
+//! All fonts from e-g 0.6 store their glyphs in the same order: the printable
+//! ASCII characters from ' ' to '~' followed by the Latin-1 characters from
+//! '¡' to 'ÿ' if the font supports them. [`StrGlyphMapping`] looks up
+//! characters by scanning its ranges. The mappings from this module compute
+//! the glyph index for this layout with a few comparisons and a subtraction
+//! instead.
+//!
+//! The code page 437 variants of some fonts append the box-drawing, block and
+//! shade characters from [`CP437_GRAPHICS`] to the Latin-1 glyphs. They use
+//! [`CP437`] for looking up these characters by their Unicode code points.
+//!
+//! [`StrGlyphMapping`]: embedded_graphics::mono_font::mapping::StrGlyphMapping
+
+use embedded_graphics::mono_font::mapping::GlyphMapping;
+
+/// Index of the replacement glyph '?' used for unsupported characters.
+const REPLACEMENT_INDEX: usize = '?' as usize - ' ' as usize;
+
+/// Number of glyphs in the ASCII part of the glyph sheets.
+const ASCII_GLYPHS: usize = '~' as usize - ' ' as usize + 1;
+
+/// Glyph mapping for printable ASCII optionally followed by the upper half of
+/// Latin-1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Latin1Mapping {
+    upper_half: bool,
+}
+
+impl Latin1Mapping {
+    /// Creates a mapping for the printable ASCII characters from ' ' to '~'.
+    pub const fn ascii() -> Self {
+        Self { upper_half: false }
+    }
+
+    /// Creates a mapping for the printable ASCII characters followed by the
+    /// Latin-1 characters from '¡' to 'ÿ'.
+    pub const fn iso_8859_1() -> Self {
+        Self { upper_half: true }
+    }
+
+    /// Returns the number of glyphs covered by this mapping.
+    pub const fn glyph_count(&self) -> usize {
+        if self.upper_half {
+            ASCII_GLYPHS + ('ÿ' as usize - '¡' as usize + 1)
+        } else {
+            ASCII_GLYPHS
+        }
+    }
+
+    /// Returns the glyph index for `c`.
+    ///
+    /// This is the same as [`GlyphMapping::index`] but usable in constants.
+    pub const fn glyph_index(&self, c: char) -> usize {
+        match c {
+            ' '..='~' => c as usize - ' ' as usize,
+            '¡'..='ÿ' if self.upper_half => c as usize - '¡' as usize + ASCII_GLYPHS,
+            _ => REPLACEMENT_INDEX,
+        }
+    }
+}
+
+impl GlyphMapping for Latin1Mapping {
+    fn index(&self, c: char) -> usize {
+        self.glyph_index(c)
+    }
+}
+
+/// Mapping for the printable ASCII characters.
+pub const ASCII: Latin1Mapping = Latin1Mapping::ascii();
+
+/// Mapping for the printable ASCII and Latin-1 characters.
+pub const ISO_8859_1: Latin1Mapping = Latin1Mapping::iso_8859_1();
+
+/// The box-drawing, block and shade characters from code page 437.
+///
+/// They are sorted by their code points which is also the order of their
+/// glyphs after the Latin-1 ones.
+pub const CP437_GRAPHICS: [char; 48] = [
+    '─', '│', '┌', '┐', '└', '┘', '├', '┤', '┬', '┴', '┼', '═', '║', '╒', '╓', '╔', '╕', '╖', '╗',
+    '╘', '╙', '╚', '╛', '╜', '╝', '╞', '╟', '╠', '╡', '╢', '╣', '╤', '╥', '╦', '╧', '╨', '╩', '╪',
+    '╫', '╬', '▀', '▄', '█', '▌', '▐', '░', '▒', '▓',
+];
+
+/// Glyph mapping for Latin-1 followed by the box-drawing, block and shade
+/// characters from code page 437.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cp437Mapping;
+
+impl Cp437Mapping {
+    /// Returns the number of glyphs covered by this mapping.
+    pub const fn glyph_count(&self) -> usize {
+        ISO_8859_1.glyph_count() + CP437_GRAPHICS.len()
+    }
+
+    /// Returns the glyph index for `c`.
+    ///
+    /// This is the same as [`GlyphMapping::index`] but usable in constants.
+    pub const fn glyph_index(&self, c: char) -> usize {
+        if !matches!(c, '\u{2500}'..='\u{259f}') {
+            return ISO_8859_1.glyph_index(c);
+        }
+
+        // Binary search as slice::binary_search is not available in const
+        // functions.
+        let mut low = 0;
+        let mut high = CP437_GRAPHICS.len();
+        while low < high {
+            let middle = (low + high) / 2;
+            if CP437_GRAPHICS[middle] == c {
+                return ISO_8859_1.glyph_count() + middle;
+            } else if (CP437_GRAPHICS[middle] as u32) < c as u32 {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        REPLACEMENT_INDEX
+    }
+}
+
+impl GlyphMapping for Cp437Mapping {
+    fn index(&self, c: char) -> usize {
+        self.glyph_index(c)
+    }
+}
+
+/// Mapping for the printable ASCII and Latin-1 characters followed by the
+/// box-drawing, block and shade characters from code page 437.
+pub const CP437: Cp437Mapping = Cp437Mapping;
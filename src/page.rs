@@ -0,0 +1,198 @@
+//! Glyphs for displays with page-oriented memory.
+//!
+//! Controllers like the SSD1306, SH1106 or ST7565 store 8 vertically adjacent
+//! pixels per byte in pages of 8 rows, with the top pixel in the least
+//! significant bit. [`PageFont`] stores the glyphs of a font in this layout.
+//! [`PageFont::draw_text`] copies them byte by byte into a buffer of pages
+//! without going through a [`DrawTarget`] pixel by pixel.
+//!
+//! The buffer has the same layout as [`VerticalPage`] uses for a
+//! [`LineBuffer`]. So text from [`PageFont`] and graphics drawn through a
+//! line buffer can be mixed.
+//!
+//! The glyph tables get computed at compile time from the bitmaps of the
+//! fonts. They are available for [`FONT_6X8`] and [`FONT_8X16`].
+//!
+//! [`DrawTarget`]: embedded_graphics::draw_target::DrawTarget
+//! [`VerticalPage`]: crate::scanline::VerticalPage
+//! [`LineBuffer`]: crate::scanline::LineBuffer
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::pixelcolor::BinaryColor;
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::page::FONT_6X8;
+//!
+//! # #[cfg(feature = "font-6x8")]
+//! # {
+//! // The display memory of a 128 x 64 pixel SSD1306.
+//! let mut buffer = [0u8; 128 * 8];
+//!
+//! let next = FONT_6X8.draw_text("Hello", 4, 2, BinaryColor::On, &mut buffer, 128);
+//! assert_eq!(next, 4 + 5 * 6);
+//!
+//! // The columns of 'H' in the third page.
+//! assert_eq!(buffer[2 * 128 + 4..][..6], [0x7f, 0x08, 0x08, 0x08, 0x7f, 0x00]);
+//! # }
+//! ```
+
+use core::fmt;
+use embedded_graphics::{
+    geometry::Size, mono_font::mapping::GlyphMapping, pixelcolor::BinaryColor,
+};
+
+/// A monospace font with glyphs stored in pages of vertical bytes.
+///
+/// Every glyph takes `character_size.width` bytes per page for as many pages
+/// as needed for `character_size.height` rows. The glyphs are stored one
+/// after the other and the pages of a glyph from top to bottom.
+#[derive(Clone, Copy)]
+pub struct PageFont<'a> {
+    /// The glyph data.
+    pub data: &'a [u8],
+    /// The size of the character cells.
+    pub character_size: Size,
+    /// The mapping from characters to glyph indices.
+    pub glyph_mapping: &'a dyn GlyphMapping,
+}
+
+impl PageFont<'_> {
+    /// Returns the number of pages of the glyphs.
+    pub const fn pages(&self) -> u32 {
+        self.character_size.height.div_ceil(8)
+    }
+
+    /// Returns the bytes of the glyph for `c`.
+    pub fn glyph(&self, c: char) -> &[u8] {
+        let size = (self.character_size.width * self.pages()) as usize;
+        let start = self.glyph_mapping.index(c) * size;
+
+        self.data.get(start..start + size).unwrap_or_default()
+    }
+
+    /// Draws `text` into `buffer` of pages `width` bytes wide and returns the
+    /// column after the text.
+    ///
+    /// The text starts at column `x` and page `page`. The glyphs overwrite
+    /// the bytes they cover with set pixels in `text_color` and the others
+    /// in the opposite color. Glyphs partially outside of the buffer get
+    /// clipped. Control characters like `'\n'` are not interpreted.
+    pub fn draw_text(
+        &self,
+        text: &str,
+        x: i32,
+        page: i32,
+        text_color: BinaryColor,
+        buffer: &mut [u8],
+        width: u32,
+    ) -> i32 {
+        let glyph_width = self.character_size.width as i32;
+        let buffer_width = width as i32;
+        let buffer_pages = buffer.len().checked_div(width as usize).unwrap_or(0) as i32;
+        let invert = match text_color {
+            BinaryColor::On => 0x00,
+            BinaryColor::Off => 0xff,
+        };
+
+        let mut x = x;
+        for c in text.chars() {
+            // Columns of the glyph within the buffer.
+            let start = (-x).clamp(0, glyph_width);
+            let end = (buffer_width - x).clamp(start, glyph_width);
+
+            if start < end {
+                let glyph = self.glyph(c);
+                let columns = glyph.chunks_exact(self.character_size.width as usize);
+                for (page, columns) in (page..).zip(columns) {
+                    if !(0..buffer_pages).contains(&page) {
+                        continue;
+                    }
+
+                    let offset = (page * buffer_width + x + start) as usize;
+                    let target = &mut buffer[offset..offset + (end - start) as usize];
+                    let source = &columns[start as usize..end as usize];
+                    for (target, source) in target.iter_mut().zip(source) {
+                        *target = source ^ invert;
+                    }
+                }
+            }
+
+            x += glyph_width;
+        }
+
+        x
+    }
+}
+
+impl fmt::Debug for PageFont<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PageFont")
+            .field("data", &format_args!("[u8; {}]", self.data.len()))
+            .field("character_size", &self.character_size)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Converts the glyphs of a row-major 1 bpp bitmap into `N` bytes of glyphs
+/// in vertical pages.
+///
+/// The glyphs are laid out in cells of `size` in an image of `image_width`
+/// pixels like it is done for [`MonoFont`].
+///
+/// [`MonoFont`]: embedded_graphics::mono_font::MonoFont
+#[cfg(any(feature = "font-6x8", feature = "font-8x16"))]
+const fn page_glyphs<const N: usize>(image: &[u8], image_width: u32, size: Size) -> [u8; N] {
+    let glyphs_per_row = image_width / size.width;
+    let bytes_per_row = image_width.div_ceil(8);
+    let pages = size.height.div_ceil(8);
+    let glyph_size = size.width * pages;
+
+    let mut data = [0; N];
+    let mut index = 0;
+    while index < N {
+        let glyph = index as u32 / glyph_size;
+        let page = index as u32 % glyph_size / size.width;
+        let column = index as u32 % size.width;
+
+        let x = glyph % glyphs_per_row * size.width + column;
+        let top = glyph / glyphs_per_row * size.height + page * 8;
+
+        let mut bit = 0;
+        while bit < 8 && page * 8 + bit < size.height {
+            let byte = image[((top + bit) * bytes_per_row + x / 8) as usize];
+            if byte & (0x80 >> (x % 8)) != 0 {
+                data[index] |= 1 << bit;
+            }
+            bit += 1;
+        }
+
+        index += 1;
+    }
+
+    data
+}
+
+/// [`FONT_6X8`](crate::FONT_6X8) with glyphs in vertical pages.
+#[cfg(feature = "font-6x8")]
+pub const FONT_6X8: PageFont = PageFont {
+    data: &page_glyphs::<{ crate::mapping::ISO_8859_1.glyph_count() * 6 }>(
+        crate::FONT_6X8_RAW,
+        crate::FONT_6X8_IMAGE_WIDTH,
+        crate::FONT_6X8.character_size,
+    ),
+    character_size: crate::FONT_6X8.character_size,
+    glyph_mapping: crate::FONT_6X8.glyph_mapping,
+};
+
+/// [`FONT_8X16`](crate::FONT_8X16) with glyphs in vertical pages.
+#[cfg(feature = "font-8x16")]
+pub const FONT_8X16: PageFont = PageFont {
+    data: &page_glyphs::<{ crate::mapping::ISO_8859_1.glyph_count() * 8 * 2 }>(
+        crate::FONT_8X16_RAW,
+        crate::FONT_8X16_IMAGE_WIDTH,
+        crate::FONT_8X16.character_size,
+    ),
+    character_size: crate::FONT_8X16.character_size,
+    glyph_mapping: crate::FONT_8X16.glyph_mapping,
+};
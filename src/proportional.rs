@@ -0,0 +1,311 @@
+//! Support for fonts with variable-width glyphs.
+//!
+//! [`MonoFont`](embedded_graphics::mono_font::MonoFont) and
+//! [`MonoTextStyle`](embedded_graphics::mono_font::MonoTextStyle) only
+//! support glyphs of a fixed width. e-g 0.6 additionally supported fonts with
+//! variable-width glyphs where the empty columns at the right of each glyph
+//! get skipped. This module brings this back with [`ProportionalFont`] and
+//! the text renderer [`ProportionalTextStyle`] which works with
+//! [`Text`](embedded_graphics::text::Text), its alignments and baselines just
+//! like `MonoTextStyle` does.
+
+use crate::{
+    glyph::Glyph,
+    render::{self, LineStyle},
+};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::{GetPixel, ImageRaw},
+    mono_font::{mapping::GlyphMapping, DecorationDimensions},
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::{PointsIter, Rectangle},
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// Bitmap font with variable-width glyphs.
+///
+/// The glyphs are stored in cells of [`character_size`] like the ones of a
+/// [`MonoFont`](embedded_graphics::mono_font::MonoFont). The width of a glyph
+/// is determined from its bitmap by ignoring the empty columns at its right.
+///
+/// [`character_size`]: ProportionalFont::character_size
+#[derive(Clone, Copy)]
+pub struct ProportionalFont<'a> {
+    /// Raw image data containing the font.
+    pub image: ImageRaw<'a, BinaryColor>,
+
+    /// Maximum size of a single character in pixel.
+    pub character_size: Size,
+
+    /// Spacing between characters.
+    ///
+    /// The spacing defines how many empty pixels are added horizontally
+    /// between adjacent characters on a single line of text.
+    pub character_spacing: u32,
+
+    /// The baseline.
+    ///
+    /// Offset from the top of the glyph bounding box to the baseline.
+    pub baseline: u32,
+
+    /// Strikethrough decoration dimensions.
+    pub strikethrough: DecorationDimensions,
+
+    /// Underline decoration dimensions.
+    pub underline: DecorationDimensions,
+
+    /// Glyph mapping.
+    pub glyph_mapping: &'a dyn GlyphMapping,
+}
+
+impl ProportionalFont<'_> {
+    /// Returns the width of the glyph for `c` in pixels.
+    ///
+    /// This is the width up to and including the rightmost set pixel of the
+    /// glyph. Empty glyphs like the one for space are one pixel wide, as they
+    /// were in e-g 0.6.
+    pub fn glyph_width(&self, c: char) -> u32 {
+        let cell = self.glyph_cell(c);
+
+        (0..cell.size.width)
+            .rev()
+            .find(|&x| {
+                (0..cell.size.height).any(|y| {
+                    self.image
+                        .pixel(cell.top_left + Point::new(x as i32, y as i32))
+                        == Some(BinaryColor::On)
+                })
+            })
+            .map_or(1, |x| x + 1)
+    }
+
+    /// Returns the glyph for `c`.
+    ///
+    /// The glyph is [`glyph_width`](ProportionalFont::glyph_width) pixels
+    /// wide.
+    pub fn glyph(&self, c: char) -> Glyph<'_> {
+        let cell = self.glyph_cell(c);
+        let size = Size::new(self.glyph_width(c), cell.size.height);
+
+        Glyph::from_image(self.image, Rectangle::new(cell.top_left, size))
+    }
+
+    /// Returns the area of the cell for the glyph for `c` within [`image`].
+    ///
+    /// [`image`]: ProportionalFont::image
+    fn glyph_cell(&self, c: char) -> Rectangle {
+        render::glyph_cell(
+            &self.image,
+            self.character_size,
+            self.glyph_mapping.index(c),
+        )
+    }
+}
+
+impl PartialEq for ProportionalFont<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.image == other.image
+            && self.character_size == other.character_size
+            && self.character_spacing == other.character_spacing
+            && self.baseline == other.baseline
+            && self.strikethrough == other.strikethrough
+            && self.underline == other.underline
+            && core::ptr::eq(
+                self.glyph_mapping as *const dyn GlyphMapping as *const u8,
+                other.glyph_mapping as *const dyn GlyphMapping as *const u8,
+            )
+    }
+}
+
+impl core::fmt::Debug for ProportionalFont<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ProportionalFont")
+            .field("image", &self.image)
+            .field("character_size", &self.character_size)
+            .field("character_spacing", &self.character_spacing)
+            .field("baseline", &self.baseline)
+            .field("strikethrough", &self.strikethrough)
+            .field("underline", &self.underline)
+            .field("glyph_mapping", &"?")
+            .finish_non_exhaustive()
+    }
+}
+
+const NULL_FONT: ProportionalFont = ProportionalFont {
+    image: ImageRaw::new(&[], 1),
+    character_size: Size::zero(),
+    character_spacing: 0,
+    baseline: 0,
+    strikethrough: DecorationDimensions::new(0, 0),
+    underline: DecorationDimensions::new(0, 0),
+    glyph_mapping: &embedded_graphics::mono_font::mapping::ASCII,
+};
+
+/// Style properties for text using a [`ProportionalFont`].
+///
+/// This is the variable-width counterpart of
+/// [`MonoTextStyle`](embedded_graphics::mono_font::MonoTextStyle). Use
+/// [`new`](ProportionalTextStyle::new) for a text color on a transparent
+/// background or [`ProportionalTextStyleBuilder`] for more complex styles.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct ProportionalTextStyle<'a, C> {
+    /// Text color.
+    pub text_color: Option<C>,
+
+    /// Background color.
+    pub background_color: Option<C>,
+
+    /// Underline color.
+    pub underline_color: DecorationColor<C>,
+
+    /// Strikethrough color.
+    pub strikethrough_color: DecorationColor<C>,
+
+    /// Font.
+    pub font: &'a ProportionalFont<'a>,
+}
+
+impl<'a, C> ProportionalTextStyle<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a text style with transparent background.
+    pub const fn new(font: &'a ProportionalFont<'a>, text_color: C) -> Self {
+        ProportionalTextStyleBuilder::new()
+            .font(font)
+            .text_color(text_color)
+            .build()
+    }
+
+    /// Returns `true` if the style is transparent.
+    pub fn is_transparent(&self) -> bool {
+        self.text_color.is_none()
+            && self.background_color.is_none()
+            && self.underline_color.is_none()
+            && self.strikethrough_color.is_none()
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle {
+            text_color: self.text_color,
+            background_color: self.background_color,
+            underline_color: self.underline_color,
+            strikethrough_color: self.strikethrough_color,
+            character_height: self.font.character_size.height,
+            character_spacing: self.font.character_spacing,
+            baseline: self.font.baseline,
+            strikethrough: self.font.strikethrough,
+            underline: self.font.underline,
+        }
+    }
+
+    fn draw_glyph<D>(&self, c: char, position: Point, target: &mut D) -> Result<u32, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let width = self.font.glyph_width(c);
+        let cell = self.font.glyph_cell(c);
+        let area = Rectangle::new(position, Size::new(width, cell.size.height));
+        let glyph = Rectangle::new(cell.top_left, area.size);
+
+        let pixels = glyph
+            .points()
+            .map(|p| self.font.image.pixel(p) == Some(BinaryColor::On));
+        render::draw_binary(
+            target,
+            &area,
+            pixels,
+            self.text_color,
+            self.background_color,
+        )?;
+
+        Ok(width)
+    }
+}
+
+impl<C> TextRenderer for ProportionalTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_chars(text.chars(), position, baseline, target, |c, p, target| {
+                self.draw_glyph(c, p, target)
+            })
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        self.line_style()
+            .measure_chars(text.chars(), position, baseline, |c| {
+                self.font.glyph_width(c)
+            })
+    }
+
+    fn line_height(&self) -> u32 {
+        self.font.character_size.height
+    }
+}
+
+impl<C> CharacterStyle for ProportionalTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+
+    fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+        self.underline_color = underline_color;
+    }
+
+    fn set_strikethrough_color(&mut self, strikethrough_color: DecorationColor<Self::Color>) {
+        self.strikethrough_color = strikethrough_color;
+    }
+}
+
+render::text_style_builder!(
+    /// Text style builder for [`ProportionalFont`]s.
+    ///
+    /// This builder provides the same methods as
+    /// [`MonoTextStyleBuilder`](embedded_graphics::mono_font::MonoTextStyleBuilder).
+    ProportionalTextStyleBuilder,
+    ProportionalTextStyle,
+    ProportionalFont,
+    NULL_FONT
+);
@@ -0,0 +1,364 @@
+//! Backend for drawing [ratatui](https://ratatui.rs) user interfaces.
+//!
+//! [`DrawTargetBackend`] implements the [`Backend`] trait from
+//! [ratatui-core](https://docs.rs/ratatui-core) for a [`DrawTarget`]. It
+//! draws every cell of the user interface with the glyph of a [`MonoFont`]
+//! and fills the area of the target starting at the top left corner of its
+//! bounding box with as many cells as fit into it.
+//!
+//! The colors of the cells get mapped to the pixel color of the target with
+//! [`TerminalColor`]. [`Color::Reset`] is [`terminal::Color::White`] as
+//! foreground and [`terminal::Color::Black`] as background color like for
+//! [`Terminal`](crate::terminal::Terminal). The modifiers bold, underlined,
+//! crossed out, reversed and hidden are supported. Bold text gets drawn in a
+//! synthetic bold like [`SyntheticTextStyle`] does and underlines below the
+//! character cell get moved to its bottom row. All other modifiers get
+//! ignored.
+//!
+//! This module requires the feature `ratatui`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{mock_display::MockDisplay, pixelcolor::Rgb565};
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{ratatui::DrawTargetBackend, FONT_6X8};
+//! use ratatui_core::{backend::Backend, buffer::Cell, layout::Size, style::Color};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), embedded_vintage_fonts::ratatui::DrawError<core::convert::Infallible>> {
+//! let mut display = MockDisplay::<Rgb565>::new();
+//! display.set_allow_overdraw(true);
+//!
+//! // The 64 x 64 pixel mock display fits 10 x 8 cells.
+//! let mut backend = DrawTargetBackend::new(display, &FONT_6X8);
+//! assert_eq!(backend.size()?, Size::new(10, 8));
+//!
+//! let mut cell = Cell::new("A");
+//! cell.fg = Color::LightGreen;
+//! backend.clear()?;
+//! backend.draw([(0, 0, &cell)].into_iter())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    render,
+    synthetic::SyntheticTextStyle,
+    terminal::{self, TerminalColor},
+};
+use core::fmt;
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{self, Point},
+    mono_font::{MonoFont, MonoTextStyleBuilder},
+    pixelcolor::Rgb888,
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline},
+};
+use ratatui_core::{
+    backend::{Backend, ClearType, WindowSize},
+    buffer::Cell,
+    layout::{Position, Size},
+    style::{Color, Modifier},
+};
+
+/// An error from drawing to the target of a [`DrawTargetBackend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrawError<E>(pub E);
+
+impl<E: fmt::Debug> fmt::Display for DrawError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "drawing to the target failed: {:?}", self.0)
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for DrawError<E> {}
+
+/// A ratatui [`Backend`] drawing to a [`DrawTarget`] with a [`MonoFont`].
+///
+/// The backend keeps track of the cursor position and visibility but does not
+/// draw the cursor. Use [`cursor_visible`](Self::cursor_visible) and
+/// [`Backend::get_cursor_position`] for drawing it to the target if needed.
+///
+/// Every cell gets drawn clipped to its area. So the pixels bold glyphs
+/// extend to the right get cut off at the edge of the cell.
+#[derive(Debug)]
+pub struct DrawTargetBackend<'a, D>
+where
+    D: DrawTarget,
+{
+    target: D,
+    font: MonoFont<'a>,
+    columns: u16,
+    rows: u16,
+    cursor: Position,
+    cursor_visible: bool,
+}
+
+impl<'a, D> DrawTargetBackend<'a, D>
+where
+    D: DrawTarget,
+    D::Color: TerminalColor,
+{
+    /// Creates a backend drawing to `target` with `font`.
+    pub fn new(target: D, font: &MonoFont<'a>) -> Self {
+        let area = target.bounding_box();
+        let cell = font.character_size;
+        let columns = area.size.width.checked_div(cell.width).unwrap_or(0);
+        let rows = area.size.height.checked_div(cell.height).unwrap_or(0);
+
+        Self {
+            target,
+            font: render::underline_within_cell(font),
+            columns: columns.min(u16::MAX.into()) as u16,
+            rows: rows.min(u16::MAX.into()) as u16,
+            cursor: Position::ORIGIN,
+            cursor_visible: true,
+        }
+    }
+
+    /// Returns whether the cursor is shown.
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Returns a reference to the draw target.
+    pub fn target(&self) -> &D {
+        &self.target
+    }
+
+    /// Returns a mutable reference to the draw target.
+    pub fn target_mut(&mut self) -> &mut D {
+        &mut self.target
+    }
+
+    /// Returns the draw target.
+    pub fn release(self) -> D {
+        self.target
+    }
+
+    /// Returns the area of the cell at `x` and `y`.
+    fn cell_area(&self, x: u16, y: u16) -> Rectangle {
+        let size = self.font.character_size;
+        let position = self.target.bounding_box().top_left
+            + Point::new(
+                (u32::from(x) * size.width) as i32,
+                (u32::from(y) * size.height) as i32,
+            );
+
+        Rectangle::new(position, size)
+    }
+
+    fn draw_cell(&mut self, x: u16, y: u16, cell: &Cell) -> Result<(), D::Error> {
+        let area = self.cell_area(x, y);
+        let modifier = cell.modifier;
+
+        let mut text_color = foreground_color::<D::Color>(cell.fg);
+        let mut background_color = background_color::<D::Color>(cell.bg);
+        if modifier.contains(Modifier::REVERSED) {
+            core::mem::swap(&mut text_color, &mut background_color);
+        }
+        if modifier.contains(Modifier::HIDDEN) {
+            text_color = background_color;
+        }
+
+        let mut builder = MonoTextStyleBuilder::new()
+            .font(&self.font)
+            .text_color(text_color)
+            .background_color(background_color);
+        if modifier.contains(Modifier::UNDERLINED) {
+            builder = builder.underline();
+        }
+        if modifier.contains(Modifier::CROSSED_OUT) {
+            builder = builder.strikethrough();
+        }
+
+        let mut style = SyntheticTextStyle::new(builder.build());
+        if modifier.contains(Modifier::BOLD) {
+            style = style.bold();
+        }
+
+        // Only the first character of a cell gets drawn. Further ones are
+        // combining characters not supported by the fonts.
+        let c = cell.symbol().chars().next().unwrap_or(' ');
+        let mut buffer = [0u8; 4];
+        style
+            .draw_string(
+                c.encode_utf8(&mut buffer),
+                area.top_left,
+                Baseline::Top,
+                &mut self.target.clipped(&area),
+            )
+            .map(|_| ())
+    }
+
+    /// Fills the cells from `start` to `end` (exclusive) in row `y` with the
+    /// reset background color.
+    fn clear_cells(&mut self, y: u16, start: u16, end: u16) -> Result<(), D::Error> {
+        let end = end.min(self.columns);
+        if y >= self.rows || start >= end {
+            return Ok(());
+        }
+
+        let first = self.cell_area(start, y);
+        let area = Rectangle::new(
+            first.top_left,
+            geometry::Size::new(u32::from(end - start) * first.size.width, first.size.height),
+        );
+        self.target
+            .fill_solid(&area, background_color(Color::Reset))
+    }
+}
+
+impl<D> Backend for DrawTargetBackend<'_, D>
+where
+    D: DrawTarget,
+    D::Color: TerminalColor,
+    D::Error: fmt::Debug,
+{
+    type Error = DrawError<D::Error>;
+
+    fn draw<'a, I>(&mut self, content: I) -> Result<(), Self::Error>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        for (x, y, cell) in content {
+            if x < self.columns && y < self.rows {
+                self.draw_cell(x, y, cell).map_err(DrawError)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> Result<(), Self::Error> {
+        self.cursor_visible = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> Result<(), Self::Error> {
+        self.cursor_visible = true;
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> Result<Position, Self::Error> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> Result<(), Self::Error> {
+        self.cursor = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Self::Error> {
+        self.target
+            .clear(background_color(Color::Reset))
+            .map_err(DrawError)
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> Result<(), Self::Error> {
+        let Position { x, y } = self.cursor;
+        let columns = self.columns;
+
+        let result = match clear_type {
+            ClearType::All => return self.clear(),
+            ClearType::AfterCursor => self.clear_cells(y, x, columns).and_then(|_| {
+                (y.saturating_add(1)..self.rows).try_for_each(|y| self.clear_cells(y, 0, columns))
+            }),
+            ClearType::BeforeCursor => (0..y)
+                .try_for_each(|y| self.clear_cells(y, 0, columns))
+                .and_then(|_| self.clear_cells(y, 0, x.saturating_add(1))),
+            ClearType::CurrentLine => self.clear_cells(y, 0, columns),
+            ClearType::UntilNewLine => self.clear_cells(y, x, columns),
+        };
+
+        result.map_err(DrawError)
+    }
+
+    fn size(&self) -> Result<Size, Self::Error> {
+        Ok(Size::new(self.columns, self.rows))
+    }
+
+    fn window_size(&mut self) -> Result<WindowSize, Self::Error> {
+        let pixels = self.target.bounding_box().size;
+
+        Ok(WindowSize {
+            columns_rows: Size::new(self.columns, self.rows),
+            pixels: Size::new(
+                pixels.width.min(u16::MAX.into()) as u16,
+                pixels.height.min(u16::MAX.into()) as u16,
+            ),
+        })
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+/// Returns the pixel color for the foreground color `color`.
+fn foreground_color<C: TerminalColor>(color: Color) -> C {
+    match color {
+        Color::Reset => C::from_terminal(terminal::Color::White),
+        color => pixel_color(color),
+    }
+}
+
+/// Returns the pixel color for the background color `color`.
+fn background_color<C: TerminalColor>(color: Color) -> C {
+    match color {
+        Color::Reset => C::from_terminal(terminal::Color::Black),
+        color => pixel_color(color),
+    }
+}
+
+/// Returns the pixel color for all colors except [`Color::Reset`].
+fn pixel_color<C: TerminalColor>(color: Color) -> C {
+    let color = match color {
+        Color::Reset | Color::Black => terminal::Color::Black,
+        Color::Red => terminal::Color::Red,
+        Color::Green => terminal::Color::Green,
+        Color::Yellow => terminal::Color::Yellow,
+        Color::Blue => terminal::Color::Blue,
+        Color::Magenta => terminal::Color::Magenta,
+        Color::Cyan => terminal::Color::Cyan,
+        Color::Gray => terminal::Color::White,
+        Color::DarkGray => terminal::Color::BrightBlack,
+        Color::LightRed => terminal::Color::BrightRed,
+        Color::LightGreen => terminal::Color::BrightGreen,
+        Color::LightYellow => terminal::Color::BrightYellow,
+        Color::LightBlue => terminal::Color::BrightBlue,
+        Color::LightMagenta => terminal::Color::BrightMagenta,
+        Color::LightCyan => terminal::Color::BrightCyan,
+        Color::White => terminal::Color::BrightWhite,
+        Color::Rgb(r, g, b) => return C::from_rgb888(Rgb888::new(r, g, b)),
+        Color::Indexed(index) => match terminal::Color::from_index(index) {
+            Some(color) => color,
+            None => return C::from_rgb888(indexed_rgb888(index)),
+        },
+    };
+
+    C::from_terminal(color)
+}
+
+/// Returns the RGB color of the 256 color palette entries from 16 to 255.
+///
+/// These are a 6 x 6 x 6 color cube followed by 24 shades of gray.
+fn indexed_rgb888(index: u8) -> Rgb888 {
+    const LEVELS: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+
+    if index >= 232 {
+        let level = 8 + 10 * (index - 232);
+        Rgb888::new(level, level, level)
+    } else {
+        let index = index.saturating_sub(16);
+        Rgb888::new(
+            LEVELS[usize::from(index / 36)],
+            LEVELS[usize::from(index / 6 % 6)],
+            LEVELS[usize::from(index % 6)],
+        )
+    }
+}
@@ -0,0 +1,324 @@
+//! Selecting fonts at runtime.
+//!
+//! [`Font`] lists every font from this crate enabled by its cargo feature. It
+//! provides the properties of the fonts for choosing one at runtime, parses
+//! font names from configuration files with [`FromStr`] and picks the largest
+//! font for a text in a given area with [`Font::largest_fitting`].
+//!
+//! Font names get compared ignoring case and all characters except letters
+//! and digits. So the e-g 0.6 name `Font6x8`, the constant name `FONT_6X8`
+//! and the feature name `font-6x8` all name the same font.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::geometry::Size;
+//! # #[cfg(all(feature = "font-6x8", feature = "font-12x16"))]
+//! use embedded_vintage_fonts::registry::{Coverage, Font};
+//!
+//! # #[cfg(not(all(feature = "font-6x8", feature = "font-12x16")))]
+//! # fn main() {}
+//! # #[cfg(all(feature = "font-6x8", feature = "font-12x16"))]
+//! # fn main() -> Result<(), embedded_vintage_fonts::registry::ParseFontError> {
+//! let font: Font = "FONT_12X16".parse()?;
+//! assert_eq!(font, Font::Font12x16);
+//! assert_eq!(font.to_string(), "Font12x16");
+//! assert_eq!(font.character_size(), Size::new(12, 16));
+//! assert_eq!(font.coverage(), Coverage::Latin1);
+//!
+//! // "12:34" needs 60 x 16 pixels with the 12 x 16 pixel font.
+//! assert_eq!(Font::largest_fitting("12:34", Size::new(64, 20)), Some(Font::Font12x16));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::mapping::CP437_GRAPHICS;
+#[cfg(feature = "font-6x6")]
+use crate::proportional::ProportionalFont;
+use core::{fmt, str::FromStr};
+use embedded_graphics::{geometry::Size, mono_font::MonoFont};
+
+/// A font from this crate.
+///
+/// There is a variant for every font enabled by its cargo feature. They are
+/// named like the fonts were in e-g 0.6.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Font {
+    /// [`FONT_6X6`](crate::FONT_6X6)
+    #[cfg(feature = "font-6x6")]
+    Font6x6,
+    /// [`FONT_6X8`](crate::FONT_6X8)
+    #[cfg(feature = "font-6x8")]
+    Font6x8,
+    /// [`FONT_6X8_CP437`](crate::FONT_6X8_CP437)
+    #[cfg(feature = "font-6x8-cp437")]
+    Font6x8Cp437,
+    /// [`FONT_6X12`](crate::FONT_6X12)
+    #[cfg(feature = "font-6x12")]
+    Font6x12,
+    /// [`FONT_8X16`](crate::FONT_8X16)
+    #[cfg(feature = "font-8x16")]
+    Font8x16,
+    /// [`FONT_8X16_CP437`](crate::FONT_8X16_CP437)
+    #[cfg(feature = "font-8x16-cp437")]
+    Font8x16Cp437,
+    /// [`FONT_12X16`](crate::FONT_12X16)
+    #[cfg(feature = "font-12x16")]
+    Font12x16,
+    /// [`FONT_24X32`](crate::FONT_24X32)
+    #[cfg(feature = "font-24x32")]
+    Font24x32,
+}
+
+impl Font {
+    /// All enabled fonts ordered by their size.
+    pub const ALL: &'static [Font] = &[
+        #[cfg(feature = "font-6x6")]
+        Font::Font6x6,
+        #[cfg(feature = "font-6x8")]
+        Font::Font6x8,
+        #[cfg(feature = "font-6x8-cp437")]
+        Font::Font6x8Cp437,
+        #[cfg(feature = "font-6x12")]
+        Font::Font6x12,
+        #[cfg(feature = "font-8x16")]
+        Font::Font8x16,
+        #[cfg(feature = "font-8x16-cp437")]
+        Font::Font8x16Cp437,
+        #[cfg(feature = "font-12x16")]
+        Font::Font12x16,
+        #[cfg(feature = "font-24x32")]
+        Font::Font24x32,
+    ];
+
+    /// Returns an iterator over all enabled fonts ordered by their size.
+    pub fn iter() -> impl Iterator<Item = Font> {
+        Self::ALL.iter().copied()
+    }
+
+    /// Returns the name of the font like it was in e-g 0.6.
+    ///
+    /// The code page 437 variants are named alike although they are new.
+    pub const fn name(self) -> &'static str {
+        match self {
+            #[cfg(feature = "font-6x6")]
+            Font::Font6x6 => "Font6x6",
+            #[cfg(feature = "font-6x8")]
+            Font::Font6x8 => "Font6x8",
+            #[cfg(feature = "font-6x8-cp437")]
+            Font::Font6x8Cp437 => "Font6x8Cp437",
+            #[cfg(feature = "font-6x12")]
+            Font::Font6x12 => "Font6x12",
+            #[cfg(feature = "font-8x16")]
+            Font::Font8x16 => "Font8x16",
+            #[cfg(feature = "font-8x16-cp437")]
+            Font::Font8x16Cp437 => "Font8x16Cp437",
+            #[cfg(feature = "font-12x16")]
+            Font::Font12x16 => "Font12x16",
+            #[cfg(feature = "font-24x32")]
+            Font::Font24x32 => "Font24x32",
+        }
+    }
+
+    /// Returns the size of the character cells.
+    ///
+    /// This is the maximum size of the variable-width glyphs of
+    /// [`FONT_6X6`](crate::FONT_6X6).
+    pub const fn character_size(self) -> Size {
+        match self.mono_font() {
+            Some(font) => font.character_size,
+            #[cfg(feature = "font-6x6")]
+            None => crate::FONT_6X6.character_size,
+            #[cfg(not(feature = "font-6x6"))]
+            None => Size::zero(),
+        }
+    }
+
+    /// Returns the characters the font has glyphs for.
+    pub const fn coverage(self) -> Coverage {
+        match self {
+            #[cfg(feature = "font-6x6")]
+            Font::Font6x6 => Coverage::Latin1Symbols,
+            #[cfg(feature = "font-6x12")]
+            Font::Font6x12 => Coverage::Ascii,
+            #[cfg(feature = "font-6x8-cp437")]
+            Font::Font6x8Cp437 => Coverage::Cp437,
+            #[cfg(feature = "font-8x16-cp437")]
+            Font::Font8x16Cp437 => Coverage::Cp437,
+            #[allow(unreachable_patterns)]
+            _ => Coverage::Latin1,
+        }
+    }
+
+    /// Returns the font as [`MonoFont`].
+    ///
+    /// Returns `None` for [`FONT_6X6`](crate::FONT_6X6) which has
+    /// variable-width glyphs.
+    pub const fn mono_font(self) -> Option<&'static MonoFont<'static>> {
+        match self {
+            #[cfg(feature = "font-6x6")]
+            Font::Font6x6 => None,
+            #[cfg(feature = "font-6x8")]
+            Font::Font6x8 => Some(&crate::FONT_6X8),
+            #[cfg(feature = "font-6x8-cp437")]
+            Font::Font6x8Cp437 => Some(&crate::FONT_6X8_CP437),
+            #[cfg(feature = "font-6x12")]
+            Font::Font6x12 => Some(&crate::FONT_6X12),
+            #[cfg(feature = "font-8x16")]
+            Font::Font8x16 => Some(&crate::FONT_8X16),
+            #[cfg(feature = "font-8x16-cp437")]
+            Font::Font8x16Cp437 => Some(&crate::FONT_8X16_CP437),
+            #[cfg(feature = "font-12x16")]
+            Font::Font12x16 => Some(&crate::FONT_12X16),
+            #[cfg(feature = "font-24x32")]
+            Font::Font24x32 => Some(&crate::FONT_24X32),
+        }
+    }
+
+    /// Returns the font as [`ProportionalFont`] if it has variable-width
+    /// glyphs.
+    #[cfg(feature = "font-6x6")]
+    pub const fn proportional_font(self) -> Option<&'static ProportionalFont<'static>> {
+        match self {
+            Font::Font6x6 => Some(&crate::FONT_6X6),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+
+    /// Returns the size of `text` drawn with this font.
+    ///
+    /// Lines are separated by `'\n'` and get stacked at the height of the
+    /// character cells like [`Text`](embedded_graphics::text::Text) does with
+    /// the default line height.
+    pub fn text_size(self, text: &str) -> Size {
+        let (width, lines) = text.split('\n').fold((0, 0), |(width, lines), line| {
+            (width.max(self.line_width(line)), lines + 1)
+        });
+
+        Size::new(width, lines * self.character_size().height)
+    }
+
+    /// Returns the largest font drawing `text` within `size`.
+    ///
+    /// Fonts are compared by the height and then the width of their character
+    /// cells. Of fonts with equal sizes, the one without code page 437
+    /// characters gets returned. Returns `None` if `text` does not fit with
+    /// any font.
+    pub fn largest_fitting(text: &str, size: Size) -> Option<Font> {
+        let mut largest: Option<Font> = None;
+
+        for font in Self::iter() {
+            let text_size = font.text_size(text);
+            if text_size.width > size.width || text_size.height > size.height {
+                continue;
+            }
+
+            let cell = font.character_size();
+            let larger = match largest {
+                None => true,
+                Some(largest) => {
+                    let largest = largest.character_size();
+                    (cell.height, cell.width) > (largest.height, largest.width)
+                }
+            };
+            if larger {
+                largest = Some(font);
+            }
+        }
+
+        largest
+    }
+
+    fn line_width(self, line: &str) -> u32 {
+        match self.mono_font() {
+            Some(font) => {
+                let count = line.chars().count() as u32;
+                let advance = font.character_size.width + font.character_spacing;
+                (count * advance).saturating_sub(font.character_spacing)
+            }
+            #[cfg(feature = "font-6x6")]
+            None => {
+                let font = &crate::FONT_6X6;
+                line.chars()
+                    .map(|c| font.glyph_width(c) + font.character_spacing)
+                    .sum::<u32>()
+                    .saturating_sub(font.character_spacing)
+            }
+            #[cfg(not(feature = "font-6x6"))]
+            None => 0,
+        }
+    }
+}
+
+impl fmt::Display for Font {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Font {
+    type Err = ParseFontError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        fn normalized(s: &str) -> impl Iterator<Item = char> + '_ {
+            s.chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+        }
+
+        Self::iter()
+            .find(|font| normalized(font.name()).eq(normalized(s)))
+            .ok_or(ParseFontError)
+    }
+}
+
+/// An error from parsing an unknown or disabled font name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseFontError;
+
+impl fmt::Display for ParseFontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown font name")
+    }
+}
+
+impl core::error::Error for ParseFontError {}
+
+/// The characters a font has glyphs for.
+///
+/// Unsupported characters get drawn as '?'.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coverage {
+    /// The printable ASCII characters from ' ' to '~'.
+    Ascii,
+    /// The printable ASCII characters and the symbols and punctuation from
+    /// '¡' to '¿' of Latin-1 with the euro sign '€' in place of the currency
+    /// sign '¤'.
+    Latin1Symbols,
+    /// The printable ASCII characters and the Latin-1 characters from '¡' to
+    /// 'ÿ'.
+    Latin1,
+    /// [`Latin1`](Coverage::Latin1) and the box-drawing, block and shade
+    /// characters from code page 437 listed in [`CP437_GRAPHICS`].
+    Cp437,
+}
+
+impl Coverage {
+    /// Returns whether there is a glyph for `c`.
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            Coverage::Ascii => matches!(c, ' '..='~'),
+            Coverage::Latin1Symbols => {
+                matches!(c, ' '..='~' | '¡'..='£' | '¥'..='¿' | '€')
+            }
+            Coverage::Latin1 => matches!(c, ' '..='~' | '¡'..='ÿ'),
+            Coverage::Cp437 => Coverage::Latin1.contains(c) || CP437_GRAPHICS.contains(&c),
+        }
+    }
+
+    /// Returns whether there are glyphs for all characters of `text`.
+    pub fn contains_all(self, text: &str) -> bool {
+        text.chars().all(|c| self.contains(c))
+    }
+}
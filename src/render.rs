@@ -0,0 +1,519 @@
+//! Helpers shared by the text renderers from this crate.
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    image::{GetPixel, ImageRaw},
+    mono_font::{DecorationDimensions, MonoFont, MonoTextStyle},
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::{PointsIter, Rectangle},
+    text::{renderer::TextMetrics, Baseline, DecorationColor},
+    Pixel,
+};
+
+/// Returns the area of the cell for the glyph with `index` within `image`.
+///
+/// The glyphs are laid out in rows of cells of `character_size` like it is
+/// done for [`MonoFont`](embedded_graphics::mono_font::MonoFont).
+pub(crate) fn glyph_cell(
+    image: &ImageRaw<'_, BinaryColor>,
+    character_size: Size,
+    index: usize,
+) -> Rectangle {
+    let image_width = image.size().width;
+    if character_size.width == 0 || image_width < character_size.width {
+        return Rectangle::zero();
+    }
+
+    let glyphs_per_row = image_width / character_size.width;
+
+    // The glyphs are stored row by row with `glyphs_per_row` glyphs each.
+    let index = index as u32;
+    let row = index / glyphs_per_row;
+
+    // Top left corner of character, in pixels
+    let char_x = (index - (row * glyphs_per_row)) * character_size.width;
+    let char_y = row * character_size.height;
+
+    Rectangle::new(Point::new(char_x as i32, char_y as i32), character_size)
+}
+
+/// Draws a binary image to `area` of `target`.
+///
+/// `pixels` yields the pixels for `area` in row-major order with `true` for
+/// set pixels. These get drawn in the text color and the others in the
+/// background color. Transparent colors are skipped.
+pub(crate) fn draw_binary<D, I>(
+    target: &mut D,
+    area: &Rectangle,
+    pixels: I,
+    text_color: Option<D::Color>,
+    background_color: Option<D::Color>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+    I: IntoIterator<Item = bool>,
+{
+    let pixels = area.points().zip(pixels);
+
+    match (text_color, background_color) {
+        (Some(text_color), Some(background_color)) => target.fill_contiguous(
+            area,
+            pixels.map(|(_, on)| if on { text_color } else { background_color }),
+        ),
+        (Some(text_color), None) => target.draw_iter(
+            pixels
+                .filter(|(_, on)| *on)
+                .map(|(p, _)| Pixel(p, text_color)),
+        ),
+        (None, Some(background_color)) => target.draw_iter(
+            pixels
+                .filter(|(_, on)| !*on)
+                .map(|(p, _)| Pixel(p, background_color)),
+        ),
+        (None, None) => Ok(()),
+    }
+}
+
+/// Draws the glyph for `c` from a [`MonoFont`] with its top left corner at
+/// `position` and returns its width.
+pub(crate) fn draw_mono_glyph<D>(
+    font: &MonoFont<'_>,
+    c: char,
+    position: Point,
+    text_color: Option<D::Color>,
+    background_color: Option<D::Color>,
+    target: &mut D,
+) -> Result<u32, D::Error>
+where
+    D: DrawTarget,
+{
+    let cell = glyph_cell(
+        &font.image,
+        font.character_size,
+        font.glyph_mapping.index(c),
+    );
+    let pixels = cell
+        .points()
+        .map(|p| font.image.pixel(p) == Some(BinaryColor::On));
+
+    draw_binary(
+        target,
+        &Rectangle::new(position, font.character_size),
+        pixels,
+        text_color,
+        background_color,
+    )?;
+
+    Ok(font.character_size.width)
+}
+
+/// Returns `font` with its underline moved into the bottom rows of the cell
+/// if it is below it.
+///
+/// Text drawn cell by cell in a grid would get the underline overdrawn by the
+/// row below.
+pub(crate) fn underline_within_cell<'a>(font: &MonoFont<'a>) -> MonoFont<'a> {
+    let height = font.character_size.height;
+    let underline = font.underline;
+
+    if underline.offset + underline.height > height {
+        let underline_height = underline.height.min(height);
+        MonoFont {
+            underline: DecorationDimensions::new(height - underline_height, underline_height),
+            ..*font
+        }
+    } else {
+        *font
+    }
+}
+
+/// Returns the actual color of a decoration.
+fn decoration_color<C: PixelColor>(
+    decoration: DecorationColor<C>,
+    text_color: Option<C>,
+) -> Option<C> {
+    match decoration {
+        DecorationColor::None => None,
+        DecorationColor::TextColor => text_color,
+        DecorationColor::Custom(color) => Some(color),
+    }
+}
+
+/// Returns the area covered by a decoration of `width` for text at
+/// `position`.
+fn decoration_rectangle(
+    decoration: &DecorationDimensions,
+    position: Point,
+    width: u32,
+) -> Rectangle {
+    Rectangle::new(
+        position + Size::new(0, decoration.offset),
+        Size::new(width, decoration.height),
+    )
+}
+
+/// Returns the vertical offset between the line position and the top edge of
+/// the bounding box for glyphs of `height` with `font_baseline`.
+fn baseline_offset(height: u32, font_baseline: u32, baseline: Baseline) -> i32 {
+    match baseline {
+        Baseline::Top => 0,
+        Baseline::Bottom => height.saturating_sub(1) as i32,
+        Baseline::Middle => (height.saturating_sub(1) / 2) as i32,
+        Baseline::Alphabetic => font_baseline as i32,
+    }
+}
+
+/// Style of a line of text independent of the font representation.
+///
+/// This implements the parts of [`TextRenderer`] shared by the text renderers
+/// from this crate: the layout of glyphs, spacing and decorations along a
+/// line of text.
+///
+/// [`TextRenderer`]: embedded_graphics::text::renderer::TextRenderer
+pub(crate) struct LineStyle<C> {
+    pub text_color: Option<C>,
+    pub background_color: Option<C>,
+    pub underline_color: DecorationColor<C>,
+    pub strikethrough_color: DecorationColor<C>,
+    pub character_height: u32,
+    pub character_spacing: u32,
+    pub baseline: u32,
+    pub strikethrough: DecorationDimensions,
+    pub underline: DecorationDimensions,
+}
+
+impl<C: PixelColor> From<&MonoTextStyle<'_, C>> for LineStyle<C> {
+    fn from(style: &MonoTextStyle<'_, C>) -> Self {
+        let font = style.font;
+
+        Self {
+            text_color: style.text_color,
+            background_color: style.background_color,
+            underline_color: style.underline_color,
+            strikethrough_color: style.strikethrough_color,
+            character_height: font.character_size.height,
+            character_spacing: font.character_spacing,
+            baseline: font.baseline,
+            strikethrough: font.strikethrough,
+            underline: font.underline,
+        }
+    }
+}
+
+impl<C: PixelColor> LineStyle<C> {
+    /// Draws `chars` glyph by glyph with `draw_glyph`.
+    ///
+    /// `draw_glyph` gets called with the character and the top left corner of
+    /// its glyph and returns the width of the drawn glyph.
+    pub fn draw_chars<I, D, F>(
+        &self,
+        chars: I,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+        mut draw_glyph: F,
+    ) -> Result<Point, D::Error>
+    where
+        I: IntoIterator<Item = char>,
+        D: DrawTarget<Color = C>,
+        F: FnMut(char, Point, &mut D) -> Result<u32, D::Error>,
+    {
+        let position = position - Point::new(0, self.baseline_offset(baseline));
+        let spacing = self.character_spacing;
+
+        let mut next = position;
+        let mut chars = chars.into_iter().peekable();
+        while let Some(c) = chars.next() {
+            next.x += draw_glyph(c, next, target)? as i32;
+
+            // Fill space between characters if background color is set.
+            if chars.peek().is_some() && spacing > 0 {
+                if let Some(background_color) = self.background_color {
+                    let size = Size::new(spacing, self.character_height);
+                    target.fill_solid(&Rectangle::new(next, size), background_color)?;
+                }
+                next.x += spacing as i32;
+            }
+        }
+
+        if next.x > position.x {
+            let width = (next.x - position.x) as u32;
+            self.draw_decorations(width, position, target)?;
+        }
+
+        Ok(next + Point::new(0, self.baseline_offset(baseline)))
+    }
+
+    /// Draws whitespace of `width` with background and decorations.
+    pub fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let position = position - Point::new(0, self.baseline_offset(baseline));
+
+        if width != 0 {
+            if let Some(background_color) = self.background_color {
+                target.fill_solid(
+                    &Rectangle::new(position, Size::new(width, self.character_height)),
+                    background_color,
+                )?;
+            }
+
+            self.draw_decorations(width, position, target)?;
+        }
+
+        Ok(position + Point::new(width as i32, self.baseline_offset(baseline)))
+    }
+
+    /// Measures `chars` with glyphs of the widths returned by `glyph_width`.
+    pub fn measure_chars<I, F>(
+        &self,
+        chars: I,
+        position: Point,
+        baseline: Baseline,
+        glyph_width: F,
+    ) -> TextMetrics
+    where
+        I: IntoIterator<Item = char>,
+        F: Fn(char) -> u32,
+    {
+        let bb_position = position - Point::new(0, self.baseline_offset(baseline));
+
+        let bb_width = chars
+            .into_iter()
+            .map(|c| glyph_width(c) + self.character_spacing)
+            .sum::<u32>()
+            .saturating_sub(self.character_spacing);
+
+        let bb_height = if self.underline_color != DecorationColor::None {
+            self.underline.height + self.underline.offset
+        } else {
+            self.character_height
+        };
+
+        let bb_size = Size::new(bb_width, bb_height);
+
+        TextMetrics {
+            bounding_box: Rectangle::new(bb_position, bb_size),
+            next_position: position + bb_size.x_axis(),
+        }
+    }
+
+    fn draw_decorations<D>(
+        &self,
+        width: u32,
+        position: Point,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        if let Some(color) = decoration_color(self.strikethrough_color, self.text_color) {
+            let rect = decoration_rectangle(&self.strikethrough, position, width);
+            target.fill_solid(&rect, color)?;
+        }
+
+        if let Some(color) = decoration_color(self.underline_color, self.text_color) {
+            let rect = decoration_rectangle(&self.underline, position, width);
+            target.fill_solid(&rect, color)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the vertical offset between the line position and the top edge
+    /// of the bounding box.
+    fn baseline_offset(&self, baseline: Baseline) -> i32 {
+        baseline_offset(self.character_height, self.baseline, baseline)
+    }
+}
+
+/// Implements a text style builder with the same methods as
+/// [`MonoTextStyleBuilder`](embedded_graphics::mono_font::MonoTextStyleBuilder)
+/// for a text style with the same public fields as `MonoTextStyle`.
+///
+/// `$null_font` is the font the builder starts with until
+/// [`font`](embedded_graphics::mono_font::MonoTextStyleBuilder::font) gets
+/// called.
+macro_rules! text_style_builder {
+    ($(#[$attr:meta])* $builder:ident, $style:ident, $font:ident, $null_font:expr) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug)]
+        pub struct $builder<'a, C> {
+            style: $style<'a, C>,
+        }
+
+        impl<'a, C> $builder<'a, C>
+        where
+            C: PixelColor,
+        {
+            /// Creates a new text style builder.
+            pub const fn new() -> Self {
+                Self {
+                    style: $style {
+                        font: &$null_font,
+                        background_color: None,
+                        text_color: None,
+                        underline_color: DecorationColor::None,
+                        strikethrough_color: DecorationColor::None,
+                    },
+                }
+            }
+
+            /// Sets the font.
+            pub const fn font<'b>(self, font: &'b $font<'b>) -> $builder<'b, C> {
+                let style = $style {
+                    font,
+                    background_color: self.style.background_color,
+                    text_color: self.style.text_color,
+                    underline_color: self.style.underline_color,
+                    strikethrough_color: self.style.strikethrough_color,
+                };
+
+                $builder { style }
+            }
+
+            /// Enables underline using the text color.
+            pub const fn underline(mut self) -> Self {
+                self.style.underline_color = DecorationColor::TextColor;
+
+                self
+            }
+
+            /// Enables strikethrough using the text color.
+            pub const fn strikethrough(mut self) -> Self {
+                self.style.strikethrough_color = DecorationColor::TextColor;
+
+                self
+            }
+
+            /// Resets the text color to transparent.
+            pub const fn reset_text_color(mut self) -> Self {
+                self.style.text_color = None;
+
+                self
+            }
+
+            /// Resets the background color to transparent.
+            pub const fn reset_background_color(mut self) -> Self {
+                self.style.background_color = None;
+
+                self
+            }
+
+            /// Removes the underline decoration.
+            pub const fn reset_underline(mut self) -> Self {
+                self.style.underline_color = DecorationColor::None;
+
+                self
+            }
+
+            /// Removes the strikethrough decoration.
+            pub const fn reset_strikethrough(mut self) -> Self {
+                self.style.strikethrough_color = DecorationColor::None;
+
+                self
+            }
+
+            /// Sets the text color.
+            pub const fn text_color(mut self, text_color: C) -> Self {
+                self.style.text_color = Some(text_color);
+
+                self
+            }
+
+            /// Sets the background color.
+            pub const fn background_color(mut self, background_color: C) -> Self {
+                self.style.background_color = Some(background_color);
+
+                self
+            }
+
+            /// Enables underline with a custom color.
+            pub const fn underline_with_color(mut self, underline_color: C) -> Self {
+                self.style.underline_color = DecorationColor::Custom(underline_color);
+
+                self
+            }
+
+            /// Enables strikethrough with a custom color.
+            pub const fn strikethrough_with_color(mut self, strikethrough_color: C) -> Self {
+                self.style.strikethrough_color = DecorationColor::Custom(strikethrough_color);
+
+                self
+            }
+
+            /// Builds the text style.
+            ///
+            /// This method can only be called after a font was set by using the
+            /// [`font`](Self::font) method. All other settings are optional and
+            /// they will be set to their default value if they are missing.
+            pub const fn build(self) -> $style<'a, C> {
+                self.style
+            }
+        }
+
+        impl<C> Default for $builder<'_, C>
+        where
+            C: PixelColor,
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<'a, C> From<&$style<'a, C>> for $builder<'a, C>
+        where
+            C: PixelColor,
+        {
+            fn from(style: &$style<'a, C>) -> Self {
+                Self { style: *style }
+            }
+        }
+    };
+}
+
+pub(crate) use text_style_builder;
+
+/// Implements [`CharacterStyle`] for a text style wrapping a `MonoTextStyle`
+/// in its `style` field by forwarding to it.
+///
+/// [`CharacterStyle`]: embedded_graphics::text::renderer::CharacterStyle
+macro_rules! forward_character_style {
+    ($style:ident) => {
+        impl<C> CharacterStyle for $style<'_, C>
+        where
+            C: PixelColor,
+        {
+            type Color = C;
+
+            fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+                self.style.set_text_color(text_color);
+            }
+
+            fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+                self.style.set_background_color(background_color);
+            }
+
+            fn set_underline_color(&mut self, underline_color: DecorationColor<Self::Color>) {
+                self.style.set_underline_color(underline_color);
+            }
+
+            fn set_strikethrough_color(
+                &mut self,
+                strikethrough_color: DecorationColor<Self::Color>,
+            ) {
+                self.style.set_strikethrough_color(strikethrough_color);
+            }
+        }
+    };
+}
+
+pub(crate) use forward_character_style;
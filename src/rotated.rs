@@ -0,0 +1,221 @@
+//! Drawing rotated text for displays mounted sideways or upside down.
+//!
+//! [`Rotated`] wraps a [`DrawTarget`] and rotates everything drawn to it
+//! around an origin. Text gets drawn to it with any text style from this crate
+//! or from e-g. Positioning, baselines, alignment, multiple lines and
+//! underline or strikethrough decorations work just like for upright text and
+//! no rotated copies of the glyph bitmaps are needed.
+//!
+//! # Example
+//!
+//! Draw "Hi" with [`FONT_6X8`](crate::FONT_6X8) rotated by 90° clockwise so
+//! that it reads from top to bottom:
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{
+//!     rotated::{Rotated, Rotation},
+//!     FONT_6X8,
+//! };
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let mut display = MockDisplay::new();
+//! let style = MonoTextStyle::new(&FONT_6X8, BinaryColor::On);
+//! let position = Point::new(7, 0);
+//!
+//! Text::with_baseline("Hi", position, style, Baseline::Top)
+//!     .draw(&mut Rotated::new(&mut display, position, Rotation::Rotate90))?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         " #######",
+//!         "    #   ",
+//!         "    #   ",
+//!         "    #   ",
+//!         " #######",
+//!         "        ",
+//!         "        ",
+//!         " #   #  ",
+//!         " ##### #",
+//!         " #      ",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+/// A clockwise rotation by a multiple of 90°.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Rotation {
+    /// No rotation.
+    #[default]
+    Rotate0,
+    /// Rotation by 90° clockwise. Text reads from top to bottom.
+    Rotate90,
+    /// Rotation by 180°. Text is upside down and reads from right to left.
+    Rotate180,
+    /// Rotation by 270° clockwise. Text reads from bottom to top.
+    Rotate270,
+}
+
+impl Rotation {
+    /// Returns the rotation undoing this one.
+    pub const fn inverse(self) -> Self {
+        match self {
+            Rotation::Rotate0 => Rotation::Rotate0,
+            Rotation::Rotate90 => Rotation::Rotate270,
+            Rotation::Rotate180 => Rotation::Rotate180,
+            Rotation::Rotate270 => Rotation::Rotate90,
+        }
+    }
+
+    /// Returns `point` rotated around `origin`.
+    pub const fn rotate_point(self, point: Point, origin: Point) -> Point {
+        let dx = point.x - origin.x;
+        let dy = point.y - origin.y;
+
+        let (dx, dy) = match self {
+            Rotation::Rotate0 => (dx, dy),
+            Rotation::Rotate90 => (-dy, dx),
+            Rotation::Rotate180 => (-dx, -dy),
+            Rotation::Rotate270 => (dy, -dx),
+        };
+
+        Point::new(origin.x + dx, origin.y + dy)
+    }
+
+    /// Returns the pixels covered by `rectangle` rotated around `origin`.
+    pub fn rotate_rectangle(self, rectangle: &Rectangle, origin: Point) -> Rectangle {
+        match rectangle.bottom_right() {
+            Some(bottom_right) => Rectangle::with_corners(
+                self.rotate_point(rectangle.top_left, origin),
+                self.rotate_point(bottom_right, origin),
+            ),
+            None => Rectangle::new(self.rotate_point(rectangle.top_left, origin), Size::zero()),
+        }
+    }
+}
+
+/// Draw target adapter rotating everything drawn around an origin.
+///
+/// A pixel drawn at `point` ends up at
+/// `rotation.rotate_point(point, origin)` on the wrapped target. Using the
+/// position of a [`Text`](embedded_graphics::text::Text) as origin rotates the
+/// text around its position.
+///
+/// The bounding box is the one of the wrapped target rotated back. So
+/// clipping and
+/// [`Alignment`](embedded_graphics::text::Alignment) relative to it work as
+/// expected.
+#[derive(Debug)]
+pub struct Rotated<'a, D> {
+    target: &'a mut D,
+    origin: Point,
+    rotation: Rotation,
+}
+
+impl<'a, D> Rotated<'a, D>
+where
+    D: DrawTarget,
+{
+    /// Creates a new adapter drawing to `target` rotated by `rotation` around
+    /// `origin`.
+    pub fn new(target: &'a mut D, origin: Point, rotation: Rotation) -> Self {
+        Self {
+            target,
+            origin,
+            rotation,
+        }
+    }
+
+    /// Returns the origin of the rotation.
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// Returns the rotation.
+    pub fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+}
+
+impl<D> DrawTarget for Rotated<'_, D>
+where
+    D: DrawTarget,
+{
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let rotation = self.rotation;
+        let origin = self.origin;
+
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(point, color)| Pixel(rotation.rotate_point(point, origin), color)),
+        )
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        if self.rotation == Rotation::Rotate0 {
+            return self.target.fill_contiguous(area, colors);
+        }
+
+        // The colors are ordered row by row of the unrotated area which are
+        // columns or reversed rows of the rotated one. So they get drawn as
+        // individual pixels.
+        let rotation = self.rotation;
+        let origin = self.origin;
+
+        self.target.draw_iter(
+            area.points()
+                .zip(colors)
+                .map(|(point, color)| Pixel(rotation.rotate_point(point, origin), color)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.rotation.rotate_rectangle(area, self.origin);
+        self.target.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.target.clear(color)
+    }
+}
+
+impl<D> Dimensions for Rotated<'_, D>
+where
+    D: DrawTarget,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.rotation
+            .inverse()
+            .rotate_rectangle(&self.target.bounding_box(), self.origin)
+    }
+}
@@ -0,0 +1,215 @@
+//! Rendering fonts at integer scale factors.
+//!
+//! e-g 0.6 shipped `Font24x32` as a pixel doubled version of `Font12x16` with
+//! a bitmap of its own. [`ScaledTextStyle`] renders any
+//! [`MonoFont`](embedded_graphics::mono_font::MonoFont) scaled by an integer
+//! factor directly from the bitmap of the original font.
+//!
+//! # Example
+//!
+//! Draw [`FONT_6X8`](crate::FONT_6X8) at twice its size:
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{scaled::ScaledTextStyle, FONT_6X8};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let mut display = MockDisplay::new();
+//! let style = ScaledTextStyle::new(MonoTextStyle::new(&FONT_6X8, BinaryColor::On), 2);
+//!
+//! Text::with_baseline("-", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         "          ",
+//!         "          ",
+//!         "          ",
+//!         "          ",
+//!         "          ",
+//!         "          ",
+//!         "##########",
+//!         "##########",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::GetPixel,
+    mono_font::{DecorationDimensions, MonoTextStyle},
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::{PointsIter, Rectangle},
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// Text style rendering a [`MonoTextStyle`] at an integer scale factor.
+///
+/// Every pixel of the original font gets drawn as a square of `scale` x
+/// `scale` pixels. Character size, spacing and decorations get scaled
+/// accordingly. The baseline ends up at the bottom row of the scaled baseline
+/// row of the original font.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ScaledTextStyle<'a, C> {
+    /// The style of the original font.
+    pub style: MonoTextStyle<'a, C>,
+
+    /// The scale factor.
+    pub scale: u32,
+}
+
+impl<'a, C> ScaledTextStyle<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a style rendering `style` scaled by `scale`.
+    pub const fn new(style: MonoTextStyle<'a, C>, scale: u32) -> Self {
+        Self { style, scale }
+    }
+
+    /// Returns the size of a scaled character.
+    pub fn character_size(&self) -> Size {
+        self.style.font.character_size * self.scale
+    }
+
+    /// Returns the scaled spacing between characters.
+    pub fn character_spacing(&self) -> u32 {
+        self.style.font.character_spacing * self.scale
+    }
+
+    /// Returns the scaled baseline.
+    pub fn baseline(&self) -> u32 {
+        (self.style.font.baseline * self.scale + self.scale).saturating_sub(1)
+    }
+
+    /// Returns the scaled strikethrough decoration dimensions.
+    pub fn strikethrough(&self) -> DecorationDimensions {
+        self.scaled_decoration(&self.style.font.strikethrough)
+    }
+
+    /// Returns the scaled underline decoration dimensions.
+    ///
+    /// The offset gets scaled like the other dimensions. So the underline of
+    /// [`FONT_12X16`](crate::FONT_12X16) scaled by two is at offset 30 while
+    /// [`FONT_24X32`](crate::FONT_24X32) draws it one pixel higher at 29.
+    pub fn underline(&self) -> DecorationDimensions {
+        self.scaled_decoration(&self.style.font.underline)
+    }
+
+    fn scaled_decoration(&self, decoration: &DecorationDimensions) -> DecorationDimensions {
+        DecorationDimensions::new(
+            decoration.offset * self.scale,
+            decoration.height * self.scale,
+        )
+    }
+
+    fn draw_glyph<D>(&self, c: char, position: Point, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let font = self.style.font;
+        let cell = render::glyph_cell(
+            &font.image,
+            font.character_size,
+            font.glyph_mapping.index(c),
+        );
+        let area = Rectangle::new(position, self.character_size());
+        let scale = self.scale as i32;
+
+        let pixels = area.points().map(|p| {
+            let offset = (p - position) / scale;
+            font.image.pixel(cell.top_left + offset) == Some(BinaryColor::On)
+        });
+
+        render::draw_binary(
+            target,
+            &area,
+            pixels,
+            self.style.text_color,
+            self.style.background_color,
+        )
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle {
+            text_color: self.style.text_color,
+            background_color: self.style.background_color,
+            underline_color: self.style.underline_color,
+            strikethrough_color: self.style.strikethrough_color,
+            character_height: self.character_size().height,
+            character_spacing: self.character_spacing(),
+            baseline: self.baseline(),
+            strikethrough: self.strikethrough(),
+            underline: self.underline(),
+        }
+    }
+}
+
+impl<C> TextRenderer for ScaledTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let width = self.character_size().width;
+
+        self.line_style()
+            .draw_chars(text.chars(), position, baseline, target, |c, p, target| {
+                self.draw_glyph(c, p, target).map(|_| width)
+            })
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.character_size().width;
+
+        self.line_style()
+            .measure_chars(text.chars(), position, baseline, |_| width)
+    }
+
+    fn line_height(&self) -> u32 {
+        self.character_size().height
+    }
+}
+
+render::forward_character_style!(ScaledTextStyle);
@@ -0,0 +1,312 @@
+//! Rendering into line buffers for displays without a framebuffer.
+//!
+//! Displays driven over SPI often get their pixels streamed row by row, for
+//! example with DMA, as there is not enough memory for a framebuffer on the
+//! MCU side. [`LineBuffer`] is a [`DrawTarget`] covering only a few rows of
+//! the display. It stores their pixels in a caller-provided buffer in the
+//! byte layout the display expects, selected by a [`PixelFormat`].
+//!
+//! Drawing a [`Text`](embedded_graphics::text::Text), or anything else, into
+//! a line buffer writes the pixels within its rows and skips all others. So
+//! moving a line buffer over the display with [`LineBuffer::set_top`] and
+//! drawing the same text for every position produces the same pixels as
+//! drawing it once into a full framebuffer. This works with all fonts and text
+//! styles from this crate.
+//!
+//! Transparent pixels are not written. Clear the line buffer with the
+//! background color before drawing for getting defined output.
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::{Rgb565, RgbColor},
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{
+//!     scanline::{LineBuffer, Rgb565Be},
+//!     FONT_6X8,
+//! };
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let style = MonoTextStyle::new(&FONT_6X8, Rgb565::WHITE);
+//! let text = Text::with_baseline("Hello!", Point::new(4, 2), style, Baseline::Top);
+//!
+//! // A single row of a 240 pixel wide display with two bytes per pixel.
+//! let mut buffer = [0u8; 240 * 2];
+//! let mut line = LineBuffer::<Rgb565Be>::new(&mut buffer, 240, 1);
+//!
+//! for y in 0..16 {
+//!     line.set_top(y);
+//!     line.clear(Rgb565::BLACK)?;
+//!     text.draw(&mut line)?;
+//!
+//!     // Send line.as_bytes() to the display.
+//!     # let _ = line.as_bytes();
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use core::{convert::Infallible, marker::PhantomData};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::{BinaryColor, IntoStorage, PixelColor, Rgb565, Rgb888, RgbColor},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// The byte layout of the pixels in a [`LineBuffer`].
+pub trait PixelFormat {
+    /// The color of the pixels.
+    type Color: PixelColor;
+
+    /// Returns the number of bytes for `rows` rows of `width` pixels.
+    fn buffer_size(width: u32, rows: u32) -> usize;
+
+    /// Sets the pixel at `x` and `row` relative to the top left corner of the
+    /// buffer with rows of `width` pixels.
+    fn set_pixel(buffer: &mut [u8], width: u32, x: u32, row: u32, color: Self::Color);
+}
+
+/// Packed binary pixels with 8 horizontally adjacent pixels per byte.
+///
+/// The leftmost pixel is the most significant bit. Every row starts with a
+/// new byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Packed1bpp;
+
+impl PixelFormat for Packed1bpp {
+    type Color = BinaryColor;
+
+    fn buffer_size(width: u32, rows: u32) -> usize {
+        width.div_ceil(8) as usize * rows as usize
+    }
+
+    fn set_pixel(buffer: &mut [u8], width: u32, x: u32, row: u32, color: Self::Color) {
+        let index = (row * width.div_ceil(8) + x / 8) as usize;
+        let mask = 0x80 >> (x % 8);
+        match color {
+            BinaryColor::On => buffer[index] |= mask,
+            BinaryColor::Off => buffer[index] &= !mask,
+        }
+    }
+}
+
+/// Binary pixels in pages of 8 rows with 8 vertically adjacent pixels per
+/// byte.
+///
+/// The top pixel is the least significant bit. This is the layout of the
+/// display memory of controllers like the SSD1306. Line buffers with this
+/// format should cover full pages and start at multiples of 8 rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct VerticalPage;
+
+impl PixelFormat for VerticalPage {
+    type Color = BinaryColor;
+
+    fn buffer_size(width: u32, rows: u32) -> usize {
+        width as usize * rows.div_ceil(8) as usize
+    }
+
+    fn set_pixel(buffer: &mut [u8], width: u32, x: u32, row: u32, color: Self::Color) {
+        let index = (row / 8 * width + x) as usize;
+        let mask = 1 << (row % 8);
+        match color {
+            BinaryColor::On => buffer[index] |= mask,
+            BinaryColor::Off => buffer[index] &= !mask,
+        }
+    }
+}
+
+/// RGB565 pixels with two bytes in big endian byte order.
+///
+/// This is the byte order displays expect over SPI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb565Be;
+
+impl PixelFormat for Rgb565Be {
+    type Color = Rgb565;
+
+    fn buffer_size(width: u32, rows: u32) -> usize {
+        width as usize * rows as usize * 2
+    }
+
+    fn set_pixel(buffer: &mut [u8], width: u32, x: u32, row: u32, color: Self::Color) {
+        let index = (row * width + x) as usize * 2;
+        buffer[index..index + 2].copy_from_slice(&color.into_storage().to_be_bytes());
+    }
+}
+
+/// RGB565 pixels with two bytes in little endian byte order.
+///
+/// This is the byte order of a `u16` buffer on little endian MCUs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb565Le;
+
+impl PixelFormat for Rgb565Le {
+    type Color = Rgb565;
+
+    fn buffer_size(width: u32, rows: u32) -> usize {
+        Rgb565Be::buffer_size(width, rows)
+    }
+
+    fn set_pixel(buffer: &mut [u8], width: u32, x: u32, row: u32, color: Self::Color) {
+        let index = (row * width + x) as usize * 2;
+        buffer[index..index + 2].copy_from_slice(&color.into_storage().to_le_bytes());
+    }
+}
+
+/// RGB888 pixels with one byte per channel in the order red, green and blue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb888Bytes;
+
+impl PixelFormat for Rgb888Bytes {
+    type Color = Rgb888;
+
+    fn buffer_size(width: u32, rows: u32) -> usize {
+        width as usize * rows as usize * 3
+    }
+
+    fn set_pixel(buffer: &mut [u8], width: u32, x: u32, row: u32, color: Self::Color) {
+        let index = (row * width + x) as usize * 3;
+        buffer[index..index + 3].copy_from_slice(&[color.r(), color.g(), color.b()]);
+    }
+}
+
+/// A draw target storing the pixels of a few rows in a caller-provided
+/// buffer.
+///
+/// The line buffer covers `rows` rows of `width` pixels starting at its top
+/// left corner. Pixels outside of it get skipped.
+#[derive(Debug)]
+pub struct LineBuffer<'a, F> {
+    buffer: &'a mut [u8],
+    area: Rectangle,
+    format: PhantomData<F>,
+}
+
+impl<'a, F: PixelFormat> LineBuffer<'a, F> {
+    /// Creates a line buffer for `rows` rows of `width` pixels at the top left
+    /// corner of the display.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is smaller than [`PixelFormat::buffer_size`] bytes.
+    pub fn new(buffer: &'a mut [u8], width: u32, rows: u32) -> Self {
+        assert!(buffer.len() >= F::buffer_size(width, rows));
+
+        Self {
+            buffer,
+            area: Rectangle::new(Point::zero(), Size::new(width, rows)),
+            format: PhantomData,
+        }
+    }
+
+    /// Returns the row of the display the line buffer starts at.
+    pub fn top(&self) -> i32 {
+        self.area.top_left.y
+    }
+
+    /// Moves the line buffer to start at row `y` of the display.
+    ///
+    /// This keeps the contents of the buffer.
+    pub fn set_top(&mut self, y: i32) {
+        self.area.top_left.y = y;
+    }
+
+    /// Moves the line buffer to start at column `x` of the display.
+    ///
+    /// This allows rendering a window of the display with a buffer for the
+    /// width of the window.
+    pub fn set_left(&mut self, x: i32) {
+        self.area.top_left.x = x;
+    }
+
+    /// Returns the pixel data for the covered rows.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[..F::buffer_size(self.area.size.width, self.area.size.height)]
+    }
+
+    /// Sets the pixel at `point` of the display if it is covered.
+    fn set(&mut self, point: Point, color: F::Color) {
+        if self.area.contains(point) {
+            let offset = point - self.area.top_left;
+            F::set_pixel(
+                self.buffer,
+                self.area.size.width,
+                offset.x as u32,
+                offset.y as u32,
+                color,
+            );
+        }
+    }
+}
+
+impl<F: PixelFormat> Dimensions for LineBuffer<'_, F> {
+    fn bounding_box(&self) -> Rectangle {
+        self.area
+    }
+}
+
+impl<F: PixelFormat> DrawTarget for LineBuffer<'_, F> {
+    type Color = F::Color;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            self.set(point, color);
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let clipped = area.intersection(&self.area);
+        let Some(bottom_right) = clipped.bottom_right() else {
+            return Ok(());
+        };
+
+        // Skip the colors for the rows above the line buffer instead of
+        // checking every pixel of them.
+        let skipped_rows = (clipped.top_left.y - area.top_left.y) as usize;
+        let mut colors = colors
+            .into_iter()
+            .skip(skipped_rows * area.size.width as usize);
+
+        for y in clipped.top_left.y..=bottom_right.y {
+            for x in area.columns() {
+                let Some(color) = colors.next() else {
+                    return Ok(());
+                };
+                self.set(Point::new(x, y), color);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let clipped = area.intersection(&self.area);
+        for y in clipped.rows() {
+            for x in clipped.columns() {
+                self.set(Point::new(x, y), color);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+//! Drawing glyphs as horizontal runs of pixels.
+//!
+//! [`MonoTextStyle`] draws glyphs with a transparent background pixel by
+//! pixel with [`DrawTarget::draw_iter`]. Many displays take far less time for
+//! filling a rectangle with [`DrawTarget::fill_solid`] than for setting the
+//! same pixels one by one, as every pixel needs its own address window.
+//!
+//! [`SpanTextStyle`] draws the same pixels as a [`MonoTextStyle`] but
+//! decomposes every glyph into horizontal runs of set pixels and fills each
+//! of them with a single call to [`DrawTarget::fill_solid`]. A background
+//! gets filled as a rectangle for the whole character cell before drawing
+//! the runs on top of it. Large fonts like [`FONT_24X32`] benefit the most.
+//!
+//! [`FONT_24X32`]: crate::FONT_24X32
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! # #[cfg(feature = "font-12x16")]
+//! use embedded_vintage_fonts::{span::SpanTextStyle, FONT_12X16};
+//!
+//! # #[cfg(not(feature = "font-12x16"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-12x16")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let style = MonoTextStyle::new(&FONT_12X16, BinaryColor::On);
+//!
+//! let mut display = MockDisplay::new();
+//! Text::with_baseline("Span", Point::zero(), SpanTextStyle::new(style), Baseline::Top)
+//!     .draw(&mut display)?;
+//!
+//! let mut expected = MockDisplay::new();
+//! Text::with_baseline("Span", Point::zero(), style, Baseline::Top).draw(&mut expected)?;
+//! assert_eq!(display, expected);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::GetPixel,
+    mono_font::MonoTextStyle,
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::Rectangle,
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// Text style drawing a [`MonoTextStyle`] with horizontal runs of pixels.
+///
+/// Text looks exactly like drawn with the original style.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SpanTextStyle<'a, C> {
+    /// The original style.
+    pub style: MonoTextStyle<'a, C>,
+}
+
+impl<'a, C> SpanTextStyle<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a style drawing text like `style` does.
+    pub const fn new(style: MonoTextStyle<'a, C>) -> Self {
+        Self { style }
+    }
+
+    /// Draws the glyph for `c` with its top left corner at `position`.
+    fn draw_glyph<D>(&self, c: char, position: Point, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let font = self.style.font;
+        let size = font.character_size;
+
+        // Draw the set pixels in the text color on top of the background or
+        // the unset pixels in the background color if the text is
+        // transparent.
+        let (on, color) = match (self.style.text_color, self.style.background_color) {
+            (Some(text_color), Some(background_color)) => {
+                target.fill_solid(&Rectangle::new(position, size), background_color)?;
+                (BinaryColor::On, text_color)
+            }
+            (Some(text_color), None) => (BinaryColor::On, text_color),
+            (None, Some(background_color)) => (BinaryColor::Off, background_color),
+            (None, None) => return Ok(()),
+        };
+
+        let cell = render::glyph_cell(&font.image, size, font.glyph_mapping.index(c));
+        let is_on = |x: u32, y: u32| {
+            font.image
+                .pixel(cell.top_left + Point::new(x as i32, y as i32))
+                .is_some_and(|pixel| pixel == on)
+        };
+
+        for y in 0..size.height {
+            let mut x = 0;
+            while x < size.width {
+                if !is_on(x, y) {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < size.width && is_on(x, y) {
+                    x += 1;
+                }
+
+                let run = Rectangle::new(
+                    position + Point::new(start as i32, y as i32),
+                    Size::new(x - start, 1),
+                );
+                target.fill_solid(&run, color)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle::from(&self.style)
+    }
+}
+
+impl<C> TextRenderer for SpanTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let width = self.style.font.character_size.width;
+
+        self.line_style()
+            .draw_chars(text.chars(), position, baseline, target, |c, p, target| {
+                self.draw_glyph(c, p, target)?;
+                Ok(width)
+            })
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.style.font.character_size.width;
+
+        self.line_style()
+            .measure_chars(text.chars(), position, baseline, |_| width)
+    }
+
+    fn line_height(&self) -> u32 {
+        self.style.font.character_size.height
+    }
+}
+
+render::forward_character_style!(SpanTextStyle);
@@ -0,0 +1,368 @@
+//! Fonts with a subset of the glyphs of the fonts from this crate.
+//!
+//! Firmware showing only a few different characters, like a clock, still
+//! links the whole glyph sheet of a font. [`vintage_subset!`] creates a
+//! [`MonoFont`] at compile time which contains only the glyphs for the given
+//! characters. Its bitmap gets generated from the original one and its
+//! [`SubsetMapping`] maps the characters to the new glyphs.
+//!
+//! All other characters get the glyph for '?' if it is part of the subset and
+//! the glyph for the first character otherwise. Characters the original font
+//! has no glyph for are rejected at compile time.
+//!
+//! [`vintage_subset!`]: crate::vintage_subset
+//!
+//! # Example
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::{MonoFont, MonoTextStyle},
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{vintage_subset, FONT_6X8};
+//!
+//! # #[cfg(feature = "font-6x8")]
+//! const CLOCK_FONT: MonoFont = vintage_subset!(FONT_6X8, "0123456789:APM ?");
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! // The bitmap holds 16 instead of 190 glyphs.
+//! assert_eq!(CLOCK_FONT.image.size(), Size::new(16 * 6, 8));
+//!
+//! let mut display = MockDisplay::new();
+//! let style = MonoTextStyle::new(&CLOCK_FONT, BinaryColor::On);
+//! Text::with_baseline("12:34", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+//!
+//! let mut expected = MockDisplay::new();
+//! let style = MonoTextStyle::new(&FONT_6X8, BinaryColor::On);
+//! Text::with_baseline("12:34", Point::zero(), style, Baseline::Top).draw(&mut expected)?;
+//! assert_eq!(display, expected);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::mapping::{Cp437Mapping, Latin1Mapping};
+use embedded_graphics::{
+    geometry::Size,
+    image::ImageRaw,
+    mono_font::{mapping::GlyphMapping, MonoFont},
+};
+
+/// Index of the replacement glyph '?' used for unsupported characters in the
+/// fonts from this crate.
+const REPLACEMENT_INDEX: usize = '?' as usize - ' ' as usize;
+
+/// Glyph mapping for the glyphs of a subset in the order of its characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubsetMapping<'a> {
+    chars: &'a str,
+    replacement: usize,
+}
+
+impl<'a> SubsetMapping<'a> {
+    /// Creates a mapping for glyphs of `chars` in the same order.
+    ///
+    /// Unsupported characters get the glyph of '?' if it is contained in
+    /// `chars` and the first one otherwise.
+    pub const fn new(chars: &'a str) -> Self {
+        let mut replacement = 0;
+        let mut chars_iter = Chars::new(chars);
+        let mut index = 0;
+        while let Some(c) = chars_iter.next() {
+            if c == '?' {
+                replacement = index;
+                break;
+            }
+            index += 1;
+        }
+
+        Self { chars, replacement }
+    }
+
+    /// Returns the number of glyphs covered by this mapping.
+    pub const fn glyph_count(&self) -> usize {
+        let mut chars = Chars::new(self.chars);
+        let mut count = 0;
+        while chars.next().is_some() {
+            count += 1;
+        }
+
+        count
+    }
+}
+
+impl GlyphMapping for SubsetMapping<'_> {
+    fn index(&self, c: char) -> usize {
+        self.chars
+            .chars()
+            .position(|glyph| glyph == c)
+            .unwrap_or(self.replacement)
+    }
+}
+
+/// Iterator over the characters of a string usable in const functions.
+struct Chars<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Chars<'a> {
+    const fn new(s: &'a str) -> Self {
+        Self {
+            bytes: s.as_bytes(),
+            position: 0,
+        }
+    }
+
+    /// Decodes the next character from the valid UTF-8 of the string.
+    const fn next(&mut self) -> Option<char> {
+        if self.position >= self.bytes.len() {
+            return None;
+        }
+
+        let first = self.bytes[self.position];
+        let (len, mut value) = match first {
+            0x00..=0x7f => (1, first as u32),
+            0xc0..=0xdf => (2, (first & 0x1f) as u32),
+            0xe0..=0xef => (3, (first & 0x0f) as u32),
+            _ => (4, (first & 0x07) as u32),
+        };
+
+        let mut i = 1;
+        while i < len {
+            value = value << 6 | (self.bytes[self.position + i] & 0x3f) as u32;
+            i += 1;
+        }
+        self.position += len;
+
+        char::from_u32(value)
+    }
+}
+
+/// The glyph mappings of the fonts from this crate.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub enum SourceMapping {
+    Latin1(Latin1Mapping),
+    Cp437(Cp437Mapping),
+}
+
+impl SourceMapping {
+    const fn glyph_index(&self, c: char) -> usize {
+        match self {
+            Self::Latin1(mapping) => mapping.glyph_index(c),
+            Self::Cp437(mapping) => mapping.glyph_index(c),
+        }
+    }
+}
+
+/// A font from this crate with its bitmap and mapping for use in constants.
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Source {
+    pub font: MonoFont<'static>,
+    pub raw: &'static [u8],
+    pub image_width: u32,
+    pub mapping: SourceMapping,
+}
+
+/// The fonts [`vintage_subset!`](crate::vintage_subset) takes glyphs from.
+#[doc(hidden)]
+pub mod sources {
+    #[allow(unused_imports)]
+    use super::{Source, SourceMapping};
+    #[allow(unused_imports)]
+    use crate::mapping::{ASCII, CP437, ISO_8859_1};
+
+    #[cfg(feature = "font-6x8")]
+    pub const FONT_6X8: Source = Source {
+        font: crate::FONT_6X8,
+        raw: crate::FONT_6X8_RAW,
+        image_width: crate::FONT_6X8_IMAGE_WIDTH,
+        mapping: SourceMapping::Latin1(ISO_8859_1),
+    };
+
+    #[cfg(feature = "font-6x8-cp437")]
+    pub const FONT_6X8_CP437: Source = Source {
+        font: crate::FONT_6X8_CP437,
+        raw: crate::FONT_6X8_CP437_RAW,
+        image_width: crate::FONT_6X8_CP437_IMAGE_WIDTH,
+        mapping: SourceMapping::Cp437(CP437),
+    };
+
+    #[cfg(feature = "font-6x12")]
+    pub const FONT_6X12: Source = Source {
+        font: crate::FONT_6X12,
+        raw: crate::FONT_6X12_RAW,
+        image_width: crate::FONT_6X12_IMAGE_WIDTH,
+        mapping: SourceMapping::Latin1(ASCII),
+    };
+
+    #[cfg(feature = "font-8x16")]
+    pub const FONT_8X16: Source = Source {
+        font: crate::FONT_8X16,
+        raw: crate::FONT_8X16_RAW,
+        image_width: crate::FONT_8X16_IMAGE_WIDTH,
+        mapping: SourceMapping::Latin1(ISO_8859_1),
+    };
+
+    #[cfg(feature = "font-8x16-cp437")]
+    pub const FONT_8X16_CP437: Source = Source {
+        font: crate::FONT_8X16_CP437,
+        raw: crate::FONT_8X16_CP437_RAW,
+        image_width: crate::FONT_8X16_CP437_IMAGE_WIDTH,
+        mapping: SourceMapping::Cp437(CP437),
+    };
+
+    #[cfg(feature = "font-12x16")]
+    pub const FONT_12X16: Source = Source {
+        font: crate::FONT_12X16,
+        raw: crate::FONT_12X16_RAW,
+        image_width: crate::FONT_12X16_IMAGE_WIDTH,
+        mapping: SourceMapping::Latin1(ISO_8859_1),
+    };
+
+    #[cfg(feature = "font-24x32")]
+    pub const FONT_24X32: Source = Source {
+        font: crate::FONT_24X32,
+        raw: crate::FONT_24X32_RAW,
+        image_width: crate::FONT_24X32_IMAGE_WIDTH,
+        mapping: SourceMapping::Latin1(ISO_8859_1),
+    };
+}
+
+/// Returns the width of the bitmap for the glyphs of `chars`.
+const fn image_width(source: &Source, chars: &str) -> u32 {
+    let count = SubsetMapping::new(chars).glyph_count();
+    assert!(count > 0, "a font subset needs at least one character");
+
+    count as u32 * source.font.character_size.width
+}
+
+/// Returns the number of bytes of the bitmap for the glyphs of `chars`.
+#[doc(hidden)]
+pub const fn subset_len(source: &Source, chars: &str) -> usize {
+    let width = image_width(source, chars);
+
+    (width.div_ceil(8) * source.font.character_size.height) as usize
+}
+
+/// Returns the bitmap with the glyphs of `chars` side by side.
+///
+/// `N` needs to be [`subset_len`] for the same parameters.
+#[doc(hidden)]
+pub const fn subset<const N: usize>(source: &Source, chars: &str) -> [u8; N] {
+    let size = source.font.character_size;
+    let source_bytes_per_row = source.image_width.div_ceil(8);
+    let glyphs_per_row = source.image_width / size.width;
+    let bytes_per_row = image_width(source, chars).div_ceil(8);
+
+    let mut data = [0; N];
+    let mut chars = Chars::new(chars);
+    let mut glyph = 0;
+    while let Some(c) = chars.next() {
+        let index = source.mapping.glyph_index(c) as u32;
+        assert!(
+            index != REPLACEMENT_INDEX as u32 || c == '?',
+            "the font has no glyph for a character of the subset"
+        );
+
+        let source_x = index % glyphs_per_row * size.width;
+        let source_y = index / glyphs_per_row * size.height;
+
+        let mut y = 0;
+        while y < size.height {
+            let mut x = 0;
+            while x < size.width {
+                let from = source_x + x;
+                let byte = source.raw[((source_y + y) * source_bytes_per_row + from / 8) as usize];
+                if byte & (0x80 >> (from % 8)) != 0 {
+                    let to = glyph * size.width + x;
+                    data[(y * bytes_per_row + to / 8) as usize] |= 0x80 >> (to % 8);
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+
+        glyph += 1;
+    }
+
+    data
+}
+
+/// Returns the font with the subset bitmap `data` and its `mapping`.
+#[doc(hidden)]
+pub const fn subset_font(
+    source: &Source,
+    data: &'static [u8],
+    mapping: &'static SubsetMapping<'static>,
+) -> MonoFont<'static> {
+    let character_size: Size = source.font.character_size;
+
+    MonoFont {
+        image: ImageRaw::new(data, mapping.glyph_count() as u32 * character_size.width),
+        character_size,
+        character_spacing: source.font.character_spacing,
+        baseline: source.font.baseline,
+        strikethrough: source.font.strikethrough,
+        underline: source.font.underline,
+        glyph_mapping: mapping,
+    }
+}
+
+/// Creates a [`MonoFont`] with the glyphs of a font from this crate for the
+/// given characters at compile time.
+///
+/// The first argument is the name of one of the monospace fonts from this
+/// crate, like `FONT_24X32`, and the second one a string with the characters
+/// of the subset. The glyphs of the new font are in the order of the
+/// characters. Characters not contained in the subset get the glyph for '?'
+/// if it is part of it and the glyph for the first character otherwise.
+///
+/// Compilation fails for an empty subset and for characters the original font
+/// has no glyph for.
+///
+/// ```rust
+/// use embedded_graphics::mono_font::{mapping::GlyphMapping, MonoFont};
+/// # #[cfg(feature = "font-24x32")]
+/// use embedded_vintage_fonts::vintage_subset;
+///
+/// # #[cfg(feature = "font-24x32")]
+/// # {
+/// const DIGITS: MonoFont = vintage_subset!(FONT_24X32, "0123456789:APM ");
+///
+/// assert_eq!(DIGITS.glyph_mapping.index('7'), 7);
+/// assert_eq!(DIGITS.glyph_mapping.index('M'), 13);
+/// // No '?' in the subset, so other characters get the glyph for '0'.
+/// assert_eq!(DIGITS.glyph_mapping.index('x'), 0);
+/// # }
+/// ```
+///
+/// [`FONT_6X12`](crate::FONT_6X12) has no glyphs for the upper half of
+/// Latin-1:
+///
+/// ```compile_fail
+/// use embedded_graphics::mono_font::MonoFont;
+/// use embedded_vintage_fonts::vintage_subset;
+///
+/// const GREETING: MonoFont = vintage_subset!(FONT_6X12, "Grüße");
+/// ```
+#[macro_export]
+macro_rules! vintage_subset {
+    ($font:ident, $chars:expr $(,)?) => {{
+        const SOURCE: $crate::subset::Source = $crate::subset::sources::$font;
+        const LEN: usize = $crate::subset::subset_len(&SOURCE, $chars);
+        const DATA: [u8; LEN] = $crate::subset::subset(&SOURCE, $chars);
+        const MAPPING: $crate::subset::SubsetMapping<'static> =
+            $crate::subset::SubsetMapping::new($chars);
+
+        $crate::subset::subset_font(&SOURCE, &DATA, &MAPPING)
+    }};
+}
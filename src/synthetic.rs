@@ -0,0 +1,309 @@
+//! Synthetic bold and italic renderings of the monospace fonts.
+//!
+//! The fonts from this crate come in a single weight. [`SyntheticTextStyle`]
+//! derives bold and italic variants from the glyphs of any
+//! [`MonoFont`](embedded_graphics::mono_font::MonoFont) at drawing time. Bold
+//! smears every set pixel to the right and italic shears the rows of a glyph
+//! to the right from bottom to top.
+//!
+//! The derived glyphs are wider than the original ones. They still advance
+//! by the width of the original font but extend into the following cell.
+//! The bounding box of a text accounts for the extra width at its end.
+//!
+//! # Example
+//!
+//! Draw a bold "I" with [`FONT_6X8`](crate::FONT_6X8):
+//!
+//! ```rust
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     mono_font::MonoTextStyle,
+//!     pixelcolor::BinaryColor,
+//!     prelude::*,
+//!     text::{Baseline, Text},
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{synthetic::SyntheticTextStyle, FONT_6X8};
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> Result<(), core::convert::Infallible> {
+//! let mut display = MockDisplay::new();
+//! let style = SyntheticTextStyle::new(MonoTextStyle::new(&FONT_6X8, BinaryColor::On)).bold();
+//!
+//! Text::with_baseline("I", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+//!
+//! assert_eq!(
+//!     display,
+//!     MockDisplay::from_pattern(&[
+//!         " ####",
+//!         "  ## ",
+//!         "  ## ",
+//!         "  ## ",
+//!         "  ## ",
+//!         "  ## ",
+//!         " ####",
+//!     ])
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::render::{self, LineStyle};
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    image::GetPixel,
+    mono_font::MonoTextStyle,
+    pixelcolor::{BinaryColor, PixelColor},
+    primitives::{PointsIter, Rectangle},
+    text::{
+        renderer::{CharacterStyle, TextMetrics, TextRenderer},
+        Baseline, DecorationColor,
+    },
+};
+
+/// The slant used by [`SyntheticTextStyle::italic`].
+pub const DEFAULT_SLANT: u32 = 4;
+
+/// Text style rendering a [`MonoTextStyle`] in synthetic bold or italic.
+///
+/// With the default values of zero for `bold` and `slant`, text looks
+/// exactly like drawn with the original style.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SyntheticTextStyle<'a, C> {
+    /// The style of the original font.
+    pub style: MonoTextStyle<'a, C>,
+
+    /// The number of pixels every set pixel gets repeated to its right.
+    ///
+    /// Zero draws the regular weight and one a typical bold.
+    pub bold: u32,
+
+    /// The number of rows per pixel of shift to the right.
+    ///
+    /// The bottom row of a glyph stays in place and the rows above get
+    /// shifted one pixel further to the right every `slant` rows. Zero draws
+    /// upright glyphs.
+    ///
+    /// Together with `bold`, glyphs must not extend beyond the following
+    /// cell. Otherwise their rightmost pixels get cut off.
+    pub slant: u32,
+}
+
+impl<'a, C> SyntheticTextStyle<'a, C>
+where
+    C: PixelColor,
+{
+    /// Creates a style drawing text like `style` does.
+    pub const fn new(style: MonoTextStyle<'a, C>) -> Self {
+        Self {
+            style,
+            bold: 0,
+            slant: 0,
+        }
+    }
+
+    /// Returns this style with bold glyphs smeared by one pixel.
+    pub const fn bold(self) -> Self {
+        Self { bold: 1, ..self }
+    }
+
+    /// Returns this style with glyphs slanted by [`DEFAULT_SLANT`].
+    pub const fn italic(self) -> Self {
+        Self {
+            slant: DEFAULT_SLANT,
+            ..self
+        }
+    }
+
+    /// Returns the width the glyphs extend beyond their cells.
+    pub fn extra_width(&self) -> u32 {
+        self.bold + self.shear(0) as u32
+    }
+
+    /// Returns the shift to the right of `row` of a glyph.
+    fn shear(&self, row: i32) -> i32 {
+        let bottom = self.style.font.character_size.height as i32 - 1;
+
+        match self.slant {
+            0 => 0,
+            slant => (bottom - row) / slant as i32,
+        }
+    }
+
+    /// Returns whether the pixel at `offset` from the top left corner of the
+    /// derived glyph from `cell` is set.
+    fn is_set(&self, cell: &Rectangle, offset: Point) -> bool {
+        let font = self.style.font;
+        let size = font.character_size;
+        if offset.y < 0 || offset.y >= size.height as i32 {
+            return false;
+        }
+
+        let x = offset.x - self.shear(offset.y);
+        (x - self.bold as i32..=x)
+            .filter(|&x| x >= 0 && x < size.width as i32)
+            .any(|x| {
+                font.image.pixel(cell.top_left + Point::new(x, offset.y)) == Some(BinaryColor::On)
+            })
+    }
+
+    /// Draws the derived glyph for `c` at `position` together with the part
+    /// of the `previous` glyph extending into the character spacing and its
+    /// cell.
+    ///
+    /// `previous` is the cell of the previous glyph within the font image and
+    /// its position.
+    fn draw_glyph<D>(
+        &self,
+        c: char,
+        position: Point,
+        previous: Option<(Rectangle, Point)>,
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let font = self.style.font;
+        let cell = render::glyph_cell(
+            &font.image,
+            font.character_size,
+            font.glyph_mapping.index(c),
+        );
+        // Start at the end of the previous cell for redrawing its glyph over
+        // the spacing filled with background in between.
+        let size = font.character_size;
+        let start = previous.map_or(position, |(_, previous)| previous + size.x_axis());
+        let area = Rectangle::new(
+            start,
+            Size::new((position.x - start.x) as u32 + size.width, size.height),
+        );
+
+        let pixels = area.points().map(|p| {
+            self.is_set(&cell, p - position)
+                || previous.is_some_and(|(cell, position)| self.is_set(&cell, p - position))
+        });
+
+        render::draw_binary(
+            target,
+            &area,
+            pixels,
+            self.style.text_color,
+            self.style.background_color,
+        )
+    }
+
+    /// Draws the part of the `last` glyph extending beyond its cell.
+    fn draw_overhang<D>(
+        &self,
+        (cell, position): (Rectangle, Point),
+        target: &mut D,
+    ) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+    {
+        let size = self.style.font.character_size;
+        let area = Rectangle::new(
+            position + size.x_axis(),
+            Size::new(self.extra_width(), size.height),
+        );
+
+        let pixels = area.points().map(|p| self.is_set(&cell, p - position));
+
+        render::draw_binary(
+            target,
+            &area,
+            pixels,
+            self.style.text_color,
+            self.style.background_color,
+        )
+    }
+
+    fn line_style(&self) -> LineStyle<C> {
+        LineStyle::from(&self.style)
+    }
+}
+
+impl<C> TextRenderer for SyntheticTextStyle<'_, C>
+where
+    C: PixelColor,
+{
+    type Color = C;
+
+    fn draw_string<D>(
+        &self,
+        text: &str,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let font = self.style.font;
+        let width = font.character_size.width;
+        let mut previous = None;
+
+        let next = self.line_style().draw_chars(
+            text.chars(),
+            position,
+            baseline,
+            target,
+            |c, p, target| {
+                self.draw_glyph(c, p, previous, target)?;
+
+                let cell = render::glyph_cell(
+                    &font.image,
+                    font.character_size,
+                    font.glyph_mapping.index(c),
+                );
+                previous = Some((cell, p));
+
+                Ok(width)
+            },
+        )?;
+
+        if let Some(last) = previous {
+            if self.extra_width() > 0 {
+                self.draw_overhang(last, target)?;
+            }
+        }
+
+        Ok(next)
+    }
+
+    fn draw_whitespace<D>(
+        &self,
+        width: u32,
+        position: Point,
+        baseline: Baseline,
+        target: &mut D,
+    ) -> Result<Point, D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        self.line_style()
+            .draw_whitespace(width, position, baseline, target)
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.style.font.character_size.width;
+
+        let mut metrics = self
+            .line_style()
+            .measure_chars(text.chars(), position, baseline, |_| width);
+        if !text.is_empty() {
+            metrics.bounding_box.size.width += self.extra_width();
+        }
+
+        metrics
+    }
+
+    fn line_height(&self) -> u32 {
+        self.style.font.character_size.height
+    }
+}
+
+render::forward_character_style!(SyntheticTextStyle);
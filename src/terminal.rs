@@ -0,0 +1,699 @@
+//! Terminal with support for a subset of ANSI/VT100 escape sequences.
+//!
+//! [`Terminal`] interprets text with escape sequences like a terminal
+//! emulator does and keeps the result in a grid of `COLUMNS` x `ROWS`
+//! characters. This grid gets drawn with a [`MonoFont`] to a [`DrawTarget`]
+//! with colors implementing [`TerminalColor`], like [`BinaryColor`] and the
+//! RGB colors from embedded-graphics.
+//!
+//! Text gets written with [`Terminal::print`] or with the `write!` macro as
+//! `Terminal` implements [`core::fmt::Write`]. Writing text does not draw
+//! anything. [`Terminal::draw`] draws the rows which changed since the last
+//! call.
+//!
+//! The supported escape sequences are:
+//!
+//! | Sequence          | Function                                        |
+//! |-------------------|-------------------------------------------------|
+//! | `ESC [ n A`       | Cursor up by `n` rows                           |
+//! | `ESC [ n B`       | Cursor down by `n` rows                         |
+//! | `ESC [ n C`       | Cursor forward by `n` columns                   |
+//! | `ESC [ n D`       | Cursor back by `n` columns                      |
+//! | `ESC [ r ; c H`   | Cursor to row `r` and column `c`, also `f`      |
+//! | `ESC [ n J`       | Erase to the end (0), start (1) or whole screen |
+//! | `ESC [ n K`       | Erase to the end (0), start (1) or whole line   |
+//! | `ESC [ ... m`     | Select graphic rendition, see below             |
+//!
+//! Select graphic rendition supports reset (0), bold (1), underline (4),
+//! inverse (7), their reset (22, 24, 27) and the 16 colors for foreground
+//! (30 to 37, 90 to 97, default 39) and background (40 to 47, 100 to 107,
+//! default 49). Bold text gets drawn in a synthetic bold like
+//! [`SyntheticTextStyle`] does. Underlines below the character cell, like
+//! the one of [`FONT_6X8`](crate::FONT_6X8), get moved to its bottom row.
+//! All other escape sequences get ignored.
+//!
+//! # Example
+//!
+//! ```rust
+//! use core::fmt::Write;
+//! use embedded_graphics::{
+//!     mock_display::MockDisplay,
+//!     pixelcolor::Rgb565,
+//! };
+//! # #[cfg(feature = "font-6x8")]
+//! use embedded_vintage_fonts::{
+//!     terminal::{Color, Terminal},
+//!     FONT_6X8,
+//! };
+//!
+//! # #[cfg(not(feature = "font-6x8"))]
+//! # fn main() {}
+//! # #[cfg(feature = "font-6x8")]
+//! # fn main() -> core::fmt::Result {
+//! let mut terminal: Terminal<10, 8> = Terminal::new(&FONT_6X8);
+//! write!(terminal, "\x1b[31mERROR\x1b[0m: {}", 42)?;
+//!
+//! let cell = terminal.cell(0, 0);
+//! assert_eq!(cell.c, 'E');
+//! assert_eq!(cell.style.foreground, Color::Red);
+//! assert_eq!(terminal.cell(5, 0).style.foreground, Color::White);
+//!
+//! let mut display = MockDisplay::<Rgb565>::new();
+//! terminal.draw(&mut display).unwrap();
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{render, synthetic::SyntheticTextStyle};
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Point, Size},
+    mono_font::{MonoFont, MonoTextStyleBuilder},
+    pixelcolor::{BinaryColor, Gray8, GrayColor, PixelColor, Rgb555, Rgb565, Rgb666, Rgb888},
+    primitives::Rectangle,
+    text::{
+        renderer::{CharacterStyle, TextRenderer},
+        Baseline,
+    },
+};
+
+/// The distance between tab stops in characters.
+pub const TAB_WIDTH: usize = 8;
+
+/// The maximum number of parameters of an escape sequence.
+///
+/// Further parameters get ignored.
+const MAX_PARAMETERS: usize = 16;
+
+/// The 16 colors of ANSI terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// Black, selected with SGR 30 and 40.
+    Black,
+    /// Red, selected with SGR 31 and 41.
+    Red,
+    /// Green, selected with SGR 32 and 42.
+    Green,
+    /// Yellow, selected with SGR 33 and 43.
+    Yellow,
+    /// Blue, selected with SGR 34 and 44.
+    Blue,
+    /// Magenta, selected with SGR 35 and 45.
+    Magenta,
+    /// Cyan, selected with SGR 36 and 46.
+    Cyan,
+    /// White, selected with SGR 37 and 47.
+    White,
+    /// Bright black, selected with SGR 90 and 100.
+    BrightBlack,
+    /// Bright red, selected with SGR 91 and 101.
+    BrightRed,
+    /// Bright green, selected with SGR 92 and 102.
+    BrightGreen,
+    /// Bright yellow, selected with SGR 93 and 103.
+    BrightYellow,
+    /// Bright blue, selected with SGR 94 and 104.
+    BrightBlue,
+    /// Bright magenta, selected with SGR 95 and 105.
+    BrightMagenta,
+    /// Bright cyan, selected with SGR 96 and 106.
+    BrightCyan,
+    /// Bright white, selected with SGR 97 and 107.
+    BrightWhite,
+}
+
+impl Color {
+    /// All colors ordered by their index.
+    pub const ALL: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+        Color::BrightBlack,
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+        Color::BrightWhite,
+    ];
+
+    /// Returns the color with `index` from 0 to 15.
+    pub fn from_index(index: u8) -> Option<Self> {
+        Self::ALL.get(index as usize).copied()
+    }
+
+    /// Returns the color as drawn by the VGA text mode.
+    pub const fn to_rgb888(self) -> Rgb888 {
+        let (r, g, b) = match self {
+            Color::Black => (0x00, 0x00, 0x00),
+            Color::Red => (0xaa, 0x00, 0x00),
+            Color::Green => (0x00, 0xaa, 0x00),
+            Color::Yellow => (0xaa, 0x55, 0x00),
+            Color::Blue => (0x00, 0x00, 0xaa),
+            Color::Magenta => (0xaa, 0x00, 0xaa),
+            Color::Cyan => (0x00, 0xaa, 0xaa),
+            Color::White => (0xaa, 0xaa, 0xaa),
+            Color::BrightBlack => (0x55, 0x55, 0x55),
+            Color::BrightRed => (0xff, 0x55, 0x55),
+            Color::BrightGreen => (0x55, 0xff, 0x55),
+            Color::BrightYellow => (0xff, 0xff, 0x55),
+            Color::BrightBlue => (0x55, 0x55, 0xff),
+            Color::BrightMagenta => (0xff, 0x55, 0xff),
+            Color::BrightCyan => (0x55, 0xff, 0xff),
+            Color::BrightWhite => (0xff, 0xff, 0xff),
+        };
+
+        Rgb888::new(r, g, b)
+    }
+}
+
+/// Pixel colors a [`Terminal`] can draw with.
+pub trait TerminalColor: PixelColor {
+    /// Returns the pixel color for the terminal color `color`.
+    fn from_terminal(color: Color) -> Self;
+
+    /// Returns the pixel color closest to the RGB color `color`.
+    fn from_rgb888(color: Rgb888) -> Self;
+}
+
+/// Black is off and all other colors are on. RGB colors are on if they are
+/// at least as bright as [`Color::BrightBlack`].
+impl TerminalColor for BinaryColor {
+    fn from_terminal(color: Color) -> Self {
+        match color {
+            Color::Black => BinaryColor::Off,
+            _ => BinaryColor::On,
+        }
+    }
+
+    fn from_rgb888(color: Rgb888) -> Self {
+        if Gray8::from(color).luma() >= 0x40 {
+            BinaryColor::On
+        } else {
+            BinaryColor::Off
+        }
+    }
+}
+
+macro_rules! impl_terminal_color {
+    ($($color:ty),*) => {
+        $(
+            impl TerminalColor for $color {
+                fn from_terminal(color: Color) -> Self {
+                    color.to_rgb888().into()
+                }
+
+                fn from_rgb888(color: Rgb888) -> Self {
+                    color.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_terminal_color!(Rgb555, Rgb565, Rgb666, Rgb888);
+
+/// The style of a character cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellStyle {
+    /// The color of the text.
+    pub foreground: Color,
+    /// The color of the background.
+    pub background: Color,
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is underlined.
+    pub underline: bool,
+    /// Whether foreground and background colors are swapped.
+    pub inverse: bool,
+}
+
+impl CellStyle {
+    /// The style after a reset with white text on a black background.
+    pub const DEFAULT: Self = Self {
+        foreground: Color::White,
+        background: Color::Black,
+        bold: false,
+        underline: false,
+        inverse: false,
+    };
+
+    /// Returns the colors for drawing text and background.
+    fn colors(&self) -> (Color, Color) {
+        if self.inverse {
+            (self.background, self.foreground)
+        } else {
+            (self.foreground, self.background)
+        }
+    }
+
+    /// Returns the text style for drawing cells with this style in `font`.
+    fn text_style<'a, C>(&self, font: &'a MonoFont<'a>) -> SyntheticTextStyle<'a, C>
+    where
+        C: TerminalColor,
+    {
+        let (text_color, background_color) = self.colors();
+        let mut builder = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(C::from_terminal(text_color))
+            .background_color(C::from_terminal(background_color));
+        if self.underline {
+            builder = builder.underline();
+        }
+
+        let text_style = SyntheticTextStyle::new(builder.build());
+        if self.bold {
+            text_style.bold()
+        } else {
+            text_style
+        }
+    }
+}
+
+impl Default for CellStyle {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A character cell of a [`Terminal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cell {
+    /// The character.
+    pub c: char,
+    /// The style of the character.
+    pub style: CellStyle,
+}
+
+impl Cell {
+    /// An erased cell with `style`.
+    const fn blank(style: CellStyle) -> Self {
+        Self { c: ' ', style }
+    }
+}
+
+/// The state of the escape sequence parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Printing characters.
+    Ground,
+    /// After an escape character.
+    Escape,
+    /// Within a control sequence, ignoring it if `ignore` is set.
+    Csi { ignore: bool },
+}
+
+/// A terminal keeping a grid of `COLUMNS` x `ROWS` characters.
+///
+/// Printable characters get put at the cursor which advances to the right
+/// and wraps to the next line at the right edge. The terminal scrolls up when
+/// the cursor moves past the bottom row. The following control characters
+/// are supported:
+///
+/// - `'\n'` moves the cursor to the start of the next line.
+/// - `'\r'` moves the cursor to the start of the current line.
+/// - `'\t'` moves the cursor to the next tab stop every [`TAB_WIDTH`]
+///   characters.
+/// - `'\u{8}'` (backspace) moves the cursor one character to the left.
+/// - `'\u{1b}'` (escape) starts an escape sequence.
+///
+/// All other control characters get ignored.
+#[derive(Debug, Clone)]
+pub struct Terminal<'a, const COLUMNS: usize, const ROWS: usize> {
+    font: &'a MonoFont<'a>,
+    cells: [[Cell; COLUMNS]; ROWS],
+    dirty: [bool; ROWS],
+    style: CellStyle,
+    column: usize,
+    row: usize,
+    state: State,
+    parameters: [u16; MAX_PARAMETERS],
+    parameter_count: usize,
+}
+
+impl<'a, const COLUMNS: usize, const ROWS: usize> Terminal<'a, COLUMNS, ROWS> {
+    /// Creates an empty terminal drawing with `font`.
+    pub fn new(font: &'a MonoFont<'a>) -> Self {
+        Self {
+            font,
+            cells: [[Cell::blank(CellStyle::DEFAULT); COLUMNS]; ROWS],
+            dirty: [true; ROWS],
+            style: CellStyle::DEFAULT,
+            column: 0,
+            row: 0,
+            state: State::Ground,
+            parameters: [0; MAX_PARAMETERS],
+            parameter_count: 0,
+        }
+    }
+
+    /// Returns the column and row of the cursor.
+    ///
+    /// The column equals `COLUMNS` after printing a character to the last
+    /// column. The line gets wrapped when printing the next character.
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.column, self.row)
+    }
+
+    /// Returns the cell at `column` and `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` or `row` are out of range.
+    pub fn cell(&self, column: usize, row: usize) -> Cell {
+        self.cells[row][column]
+    }
+
+    /// Returns the style for printing characters.
+    pub fn style(&self) -> CellStyle {
+        self.style
+    }
+
+    /// Erases all cells, moves the cursor to the top left corner and resets
+    /// the style.
+    pub fn reset(&mut self) {
+        self.style = CellStyle::DEFAULT;
+        self.erase_rows(0..ROWS);
+        self.column = 0;
+        self.row = 0;
+        self.state = State::Ground;
+    }
+
+    /// Writes `text` which may contain escape sequences.
+    pub fn print(&mut self, text: &str) {
+        text.chars().for_each(|c| self.print_char(c));
+    }
+
+    /// Writes the single character `c`.
+    pub fn print_char(&mut self, c: char) {
+        if COLUMNS == 0 || ROWS == 0 {
+            return;
+        }
+
+        match self.state {
+            State::Ground => self.ground(c),
+            State::Escape => self.escape(c),
+            State::Csi { ignore } => self.csi(c, ignore),
+        }
+    }
+
+    /// Marks all rows for getting drawn by the next call of
+    /// [`draw`](Self::draw).
+    pub fn invalidate(&mut self) {
+        self.dirty = [true; ROWS];
+    }
+
+    /// Draws the rows which changed since the last call to `target`.
+    ///
+    /// The top left corner of the grid is at the top left corner of the
+    /// bounding box of `target`.
+    pub fn draw<D>(&mut self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+        D::Color: TerminalColor,
+    {
+        let origin = target.bounding_box().top_left;
+        let cell_size = self.font.character_size;
+        let font = render::underline_within_cell(self.font);
+
+        // Bold glyphs in the last column would extend beyond the grid.
+        let grid = Rectangle::new(
+            origin,
+            cell_size.component_mul(Size::new(COLUMNS as u32, ROWS as u32)),
+        );
+        let mut target = target.clipped(&grid);
+
+        for row in 0..ROWS {
+            if !self.dirty[row] {
+                continue;
+            }
+
+            let mut column = 0;
+            while column < COLUMNS {
+                // Draw runs of characters with the same style at once.
+                let style = self.cells[row][column].style;
+                let mut buffer = [0u8; 64];
+                let mut length = 0;
+                let start = column;
+                while column < COLUMNS
+                    && self.cells[row][column].style == style
+                    && length + 4 <= buffer.len()
+                {
+                    length += self.cells[row][column]
+                        .c
+                        .encode_utf8(&mut buffer[length..])
+                        .len();
+                    column += 1;
+                }
+
+                // Only valid UTF-8 got encoded into the buffer.
+                let text = core::str::from_utf8(&buffer[..length]).unwrap_or_default();
+                let position = origin
+                    + Point::new(
+                        (start as u32 * cell_size.width) as i32,
+                        (row as u32 * cell_size.height) as i32,
+                    );
+                style
+                    .text_style(&font)
+                    .draw_string(text, position, Baseline::Top, &mut target)?;
+
+                // The background of the run covers the pixels a bold glyph
+                // before it extends into its first cell. Draw that glyph again
+                // without a background on top.
+                let previous = start.checked_sub(1).map(|column| self.cells[row][column]);
+                if let Some(previous) = previous.filter(|previous| previous.style.bold) {
+                    let mut previous_style = previous.style.text_style(&font);
+                    previous_style.set_background_color(None);
+
+                    let mut buffer = [0u8; 4];
+                    previous_style.draw_string(
+                        previous.c.encode_utf8(&mut buffer),
+                        position - cell_size.x_axis(),
+                        Baseline::Top,
+                        &mut target,
+                    )?;
+                }
+            }
+
+            self.dirty[row] = false;
+        }
+
+        Ok(())
+    }
+
+    fn ground(&mut self, c: char) {
+        match c {
+            '\u{1b}' => self.state = State::Escape,
+            '\n' => self.new_line(),
+            '\r' => self.column = 0,
+            '\t' => {
+                let stop = (self.column / TAB_WIDTH + 1) * TAB_WIDTH;
+                self.column = stop.min(COLUMNS);
+            }
+            '\u{8}' => self.column = self.column.min(COLUMNS - 1).saturating_sub(1),
+            c if c.is_control() => {}
+            c => {
+                if self.column >= COLUMNS {
+                    self.new_line();
+                }
+                self.set(
+                    self.column,
+                    self.row,
+                    Cell {
+                        c,
+                        style: self.style,
+                    },
+                );
+                self.column += 1;
+            }
+        }
+    }
+
+    fn escape(&mut self, c: char) {
+        match c {
+            '[' => {
+                self.parameters = [0; MAX_PARAMETERS];
+                self.parameter_count = 0;
+                self.state = State::Csi { ignore: false };
+            }
+            // Intermediate characters like in `ESC ( B` are followed by the
+            // final character of the sequence.
+            '\u{1b}' | ' '..='/' => {}
+            // Other escape sequences end with a single character.
+            _ => self.state = State::Ground,
+        }
+    }
+
+    fn csi(&mut self, c: char, ignore: bool) {
+        match c {
+            '0'..='9' => {
+                if self.parameter_count == 0 {
+                    self.parameter_count = 1;
+                }
+                if let Some(parameter) = self.parameters.get_mut(self.parameter_count - 1) {
+                    let digit = c as u16 - '0' as u16;
+                    *parameter = parameter.saturating_mul(10).saturating_add(digit);
+                }
+            }
+            ';' | ':' => {
+                self.parameter_count = self.parameter_count.max(1) + 1;
+            }
+            // Private sequences and intermediate characters are not
+            // supported.
+            '<'..='?' | ' '..='/' => self.state = State::Csi { ignore: true },
+            '@'..='~' => {
+                self.state = State::Ground;
+                if !ignore {
+                    self.execute(c);
+                }
+            }
+            '\u{1b}' => self.state = State::Escape,
+            c if c.is_control() => self.ground(c),
+            _ => self.state = State::Ground,
+        }
+    }
+
+    /// Returns the parameters of the current control sequence.
+    fn parameters(&self) -> &[u16] {
+        &self.parameters[..self.parameter_count.min(MAX_PARAMETERS)]
+    }
+
+    /// Returns parameter `index` or `default` if it is missing or zero.
+    fn parameter(&self, index: usize, default: u16) -> usize {
+        match self.parameters().get(index) {
+            Some(&value) if value != 0 => value as usize,
+            _ => default as usize,
+        }
+    }
+
+    /// Executes the control sequence with the final character `c`.
+    fn execute(&mut self, c: char) {
+        let column = self.column.min(COLUMNS - 1);
+
+        match c {
+            'A' => self.row = self.row.saturating_sub(self.parameter(0, 1)),
+            'B' => self.row = (self.row + self.parameter(0, 1)).min(ROWS - 1),
+            'C' => self.column = (column + self.parameter(0, 1)).min(COLUMNS - 1),
+            'D' => self.column = column.saturating_sub(self.parameter(0, 1)),
+            'H' | 'f' => {
+                self.row = self.parameter(0, 1).min(ROWS) - 1;
+                self.column = self.parameter(1, 1).min(COLUMNS) - 1;
+            }
+            'J' => match self.parameter(0, 0) {
+                0 => {
+                    self.erase_line(self.row, column..COLUMNS);
+                    self.erase_rows(self.row + 1..ROWS);
+                }
+                1 => {
+                    self.erase_rows(0..self.row);
+                    self.erase_line(self.row, 0..column + 1);
+                }
+                2 => self.erase_rows(0..ROWS),
+                _ => {}
+            },
+            'K' => match self.parameter(0, 0) {
+                0 => self.erase_line(self.row, column..COLUMNS),
+                1 => self.erase_line(self.row, 0..column + 1),
+                2 => self.erase_line(self.row, 0..COLUMNS),
+                _ => {}
+            },
+            'm' => self.select_graphic_rendition(),
+            _ => {}
+        }
+    }
+
+    fn select_graphic_rendition(&mut self) {
+        if self.parameters().is_empty() {
+            self.style = CellStyle::DEFAULT;
+            return;
+        }
+
+        let mut i = 0;
+        while let Some(&parameter) = self.parameters().get(i) {
+            let color = |base: u16| Color::from_index((parameter - base) as u8);
+
+            match parameter {
+                0 => self.style = CellStyle::DEFAULT,
+                1 => self.style.bold = true,
+                4 => self.style.underline = true,
+                7 => self.style.inverse = true,
+                22 => self.style.bold = false,
+                24 => self.style.underline = false,
+                27 => self.style.inverse = false,
+                30..=37 => self.style.foreground = color(30).unwrap_or(Color::White),
+                39 => self.style.foreground = CellStyle::DEFAULT.foreground,
+                40..=47 => self.style.background = color(40).unwrap_or(Color::Black),
+                49 => self.style.background = CellStyle::DEFAULT.background,
+                90..=97 => self.style.foreground = color(82).unwrap_or(Color::White),
+                100..=107 => self.style.background = color(92).unwrap_or(Color::Black),
+                // Skip the parameters of 256 and RGB colors which are not
+                // supported.
+                38 | 48 => match self.parameters().get(i + 1) {
+                    Some(5) => i += 2,
+                    Some(2) => i += 4,
+                    _ => {}
+                },
+                _ => {}
+            }
+
+            i += 1;
+        }
+    }
+
+    /// Sets the cell at `column` and `row`.
+    fn set(&mut self, column: usize, row: usize, cell: Cell) {
+        if self.cells[row][column] != cell {
+            self.cells[row][column] = cell;
+            self.dirty[row] = true;
+        }
+    }
+
+    /// Erases the cells in `columns` of `row` with the current background.
+    fn erase_line(&mut self, row: usize, columns: core::ops::Range<usize>) {
+        let blank = Cell::blank(CellStyle {
+            background: self.style.background,
+            ..CellStyle::DEFAULT
+        });
+
+        for column in columns {
+            self.set(column, row, blank);
+        }
+    }
+
+    fn erase_rows(&mut self, rows: core::ops::Range<usize>) {
+        for row in rows {
+            self.erase_line(row, 0..COLUMNS);
+        }
+    }
+
+    /// Moves the cursor to the start of the next line and scrolls if needed.
+    fn new_line(&mut self) {
+        self.column = 0;
+        if self.row + 1 < ROWS {
+            self.row += 1;
+            return;
+        }
+
+        self.cells.copy_within(1.., 0);
+        self.erase_rows(ROWS - 1..ROWS);
+        self.invalidate();
+    }
+}
+
+impl<const COLUMNS: usize, const ROWS: usize> core::fmt::Write for Terminal<'_, COLUMNS, ROWS> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.print(s);
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> core::fmt::Result {
+        self.print_char(c);
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+#![cfg(test)]
+
+use embedded_graphics::{
+    geometry::Point,
+    image::ImageRaw,
+    mock_display::MockDisplay,
+    mono_font::{mapping::StrGlyphMapping, DecorationDimensions, MonoFont, MonoTextStyle},
+    pixelcolor::BinaryColor,
+    prelude::*,
+    text::{Baseline, Text},
+};
+
+#[path = "../examples/bdf2raw/bdf.rs"]
+mod bdf;
+#[path = "../examples/bdf2raw/convert.rs"]
+mod convert;
+
+/// A 4 x 6 pixel font with a descent of one pixel and a glyph for 'W' which is
+/// too wide.
+const FONT: &str = "STARTFONT 2.1
+COMMENT A test font
+FONT -test-fixed-medium-r-normal--6-60-75-75-c-40-iso10646-1
+SIZE 6 75 75
+FONTBOUNDINGBOX 5 6 0 -1
+STARTPROPERTIES 3
+FONT_ASCENT 5
+FONT_DESCENT 1
+COPYRIGHT \"Public \"\"domain\"\"\"
+ENDPROPERTIES
+CHARS 5
+STARTCHAR question
+ENCODING 63
+DWIDTH 4 0
+BBX 3 5 0 0
+BITMAP
+E0
+20
+60
+00
+40
+ENDCHAR
+STARTCHAR A
+ENCODING 65
+DWIDTH 4 0
+BBX 3 5 0 0
+BITMAP
+40
+A0
+E0
+A0
+A0
+ENDCHAR
+STARTCHAR B
+ENCODING 66
+DWIDTH 4 0
+BBX 3 5 0 0
+BITMAP
+C0
+A0
+C0
+A0
+C0
+ENDCHAR
+STARTCHAR W
+ENCODING 87
+DWIDTH 6 0
+BBX 5 5 0 0
+BITMAP
+88
+88
+A8
+A8
+50
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+DWIDTH 4 0
+BBX 1 1 0 0
+BITMAP
+80
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn parse() {
+    let font: bdf::Font = FONT.parse().unwrap();
+
+    assert_eq!(
+        font.name,
+        "-test-fixed-medium-r-normal--6-60-75-75-c-40-iso10646-1"
+    );
+    assert_eq!(font.ascent(), 5);
+    assert_eq!(font.descent(), 1);
+    assert_eq!(font.properties["COPYRIGHT"], "Public \"domain\"");
+    assert_eq!(font.glyphs.len(), 5);
+    assert_eq!(font.glyphs[4].encoding, None);
+
+    let a = &font.glyphs[1];
+    assert_eq!(a.device_width, Some(4));
+    assert_eq!(
+        a.bounding_box,
+        bdf::BoundingBox {
+            width: 3,
+            height: 5,
+            x: 0,
+            y: 0
+        }
+    );
+    assert!(a.is_set(1, 0));
+    assert!(!a.is_set(0, 0));
+    assert_eq!(a.pixels().next(), Some((1, -5)));
+}
+
+#[test]
+fn parse_errors() {
+    let error = "STARTFONT 2.1\nFONTBOUNDINGBOX 5 six 0 -1\n"
+        .parse::<bdf::Font>()
+        .unwrap_err();
+    assert_eq!(error.line, 2);
+
+    let error = "STARTFONT 2.1\nSTARTCHAR A\nBBX 8 1 0 0\nBITMAP\nXY\n"
+        .parse::<bdf::Font>()
+        .unwrap_err();
+    assert_eq!(error.line, 5);
+
+    let error = "FONT foo\n".parse::<bdf::Font>().unwrap_err();
+    assert_eq!(error.line, 1);
+
+    let error = "STARTFONT 2.1\n".parse::<bdf::Font>().unwrap_err();
+    assert_eq!(error.message, "unexpected end of file");
+}
+
+#[test]
+fn mapping_string() {
+    assert_eq!(convert::mapping_string(&[]), "");
+    assert_eq!(convert::mapping_string(&['a', 'b', 'x']), "abx");
+    assert_eq!(convert::mapping_string(&['a', 'b', 'c', 'x']), "\0acx");
+    assert_eq!(convert::mapping_string(&['\0', 'a']), "\0\0\0a");
+    assert_eq!(
+        convert::mapping_string(&[' ', '!', '"', '#', 'é', '€']),
+        "\0 #é€"
+    );
+}
+
+#[test]
+fn convert() {
+    let font: bdf::Font = FONT.parse().unwrap();
+    let converted = convert::convert(&font, 2).unwrap();
+
+    assert_eq!(converted.character_size, (4, 6));
+    assert_eq!(converted.image_width, 8);
+    assert_eq!(converted.image_height(), 12);
+    assert_eq!(converted.baseline, 4);
+    assert_eq!(converted.underline, (6, 1));
+    assert_eq!(converted.strikethrough, (2, 1));
+    assert_eq!(converted.mapping, "?ABW");
+    assert_eq!(converted.replacement, 0);
+
+    assert_eq!(converted.misfits.len(), 1);
+    assert_eq!(converted.misfits[0].c, 'W');
+    assert_eq!(
+        converted.misfits[0].to_string(),
+        "'W' (W): pixels outside of the cell got clipped, advance 6 instead of 4"
+    );
+}
+
+#[test]
+fn draw_converted() {
+    let font: bdf::Font = FONT.parse().unwrap();
+    let converted = convert::convert(&font, 2).unwrap();
+
+    let mono_font = MonoFont {
+        image: ImageRaw::new(&converted.raw, converted.image_width),
+        character_size: Size::new(4, 6),
+        character_spacing: 0,
+        baseline: converted.baseline,
+        strikethrough: DecorationDimensions::new(2, 1),
+        underline: DecorationDimensions::new(6, 1),
+        glyph_mapping: &StrGlyphMapping::new(&converted.mapping, converted.replacement),
+    };
+    let style = MonoTextStyle::new(&mono_font, BinaryColor::On);
+
+    let mut display = MockDisplay::new();
+    Text::with_baseline("AB?W", Point::zero(), style, Baseline::Top)
+        .draw(&mut display)
+        .unwrap();
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            " #  ##  ### #   ",
+            "# # # #   # #   ",
+            "### ##   ## # # ",
+            "# # # #     # # ",
+            "# # ##   #   # #",
+        ])
+    );
+}
+
+#[test]
+fn rust_constant() {
+    let font: bdf::Font = FONT.parse().unwrap();
+    let converted = convert::convert(&font, 16).unwrap();
+
+    assert_eq!(
+        converted.rust_constant("FONT_4X6", "../data/font4x6_1bpp.raw"),
+        r#"pub const FONT_4X6: MonoFont = MonoFont {
+    image: ImageRaw::new(include_bytes!("../data/font4x6_1bpp.raw"), 64),
+    character_size: Size::new(4, 6),
+    character_spacing: 0,
+    baseline: 4,
+    strikethrough: DecorationDimensions::new(2, 1),
+    underline: DecorationDimensions::new(6, 1),
+    glyph_mapping: &StrGlyphMapping::new("?ABW", 0),
+};
+"#
+    );
+}
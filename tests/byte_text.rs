@@ -0,0 +1,152 @@
+#![cfg(test)]
+
+#[cfg(any(feature = "font-6x8", feature = "font-8x16-cp437"))]
+use embedded_graphics::{
+    geometry::{Dimensions, Point},
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyle,
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text, TextStyle},
+    Drawable,
+};
+#[cfg(any(feature = "font-6x8", feature = "font-8x16-cp437"))]
+use embedded_vintage_fonts::byte_text::ByteText;
+use embedded_vintage_fonts::byte_text::Encoding;
+
+/// Checks that `bytes` get drawn like `text` with the same styles.
+#[cfg(any(feature = "font-6x8", feature = "font-8x16-cp437"))]
+fn check_same_as_text(
+    bytes: &[u8],
+    encoding: Encoding,
+    text: &str,
+    character_style: MonoTextStyle<'_, BinaryColor>,
+    text_style: TextStyle,
+) {
+    let position = Point::new(30, 20);
+    let byte_text =
+        ByteText::with_text_style(bytes, encoding, position, character_style, text_style);
+    let text = Text::with_text_style(text, position, character_style, text_style);
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    let next = byte_text.draw(&mut display).unwrap();
+
+    let mut expected_display = MockDisplay::new();
+    expected_display.set_allow_overdraw(true);
+    let expected_next = text.draw(&mut expected_display).unwrap();
+
+    assert_eq!(display, expected_display);
+    assert_eq!(next, expected_next);
+    assert_eq!(byte_text.bounding_box(), text.bounding_box());
+}
+
+#[test]
+fn decode_latin1() {
+    for byte in 0..=255u8 {
+        assert_eq!(Encoding::Latin1.decode(byte) as u32, byte as u32);
+    }
+}
+
+#[test]
+fn decode_cp437() {
+    for byte in 0..0x80u8 {
+        assert_eq!(Encoding::Cp437.decode(byte) as u32, byte as u32);
+    }
+
+    assert_eq!(Encoding::Cp437.decode(0x80), 'Ç');
+    assert_eq!(Encoding::Cp437.decode(0x9e), '₧');
+    assert_eq!(Encoding::Cp437.decode(0xb0), '░');
+    assert_eq!(Encoding::Cp437.decode(0xc9), '╔');
+    assert_eq!(Encoding::Cp437.decode(0xdb), '█');
+    assert_eq!(Encoding::Cp437.decode(0xe1), 'ß');
+    assert_eq!(Encoding::Cp437.decode(0xfe), '■');
+    assert_eq!(Encoding::Cp437.decode(0xff), '\u{a0}');
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn latin1_same_as_text() {
+    use embedded_vintage_fonts::FONT_6X8;
+
+    let style = MonoTextStyle::new(&FONT_6X8, BinaryColor::On);
+
+    for byte in 0x20..=0xffu8 {
+        let mut buf = [0u8; 4];
+        let text = char::from(byte).encode_utf8(&mut buf);
+
+        check_same_as_text(
+            &[byte],
+            Encoding::Latin1,
+            text,
+            style,
+            TextStyle::with_baseline(Baseline::Top),
+        );
+    }
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn multiple_lines_same_as_text() {
+    use embedded_graphics::{
+        mono_font::MonoTextStyleBuilder,
+        text::{Alignment, LineHeight, TextStyleBuilder},
+    };
+    use embedded_vintage_fonts::FONT_6X8;
+
+    let character_style = MonoTextStyleBuilder::new()
+        .font(&FONT_6X8)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .build();
+
+    for alignment in [Alignment::Left, Alignment::Center, Alignment::Right] {
+        for baseline in [Baseline::Top, Baseline::Middle, Baseline::Alphabetic] {
+            let text_style = TextStyleBuilder::new()
+                .alignment(alignment)
+                .baseline(baseline)
+                .line_height(LineHeight::Pixels(9))
+                .build();
+
+            check_same_as_text(
+                b"Gr\xfc\xdfe\r\n\nA\xe9",
+                Encoding::Latin1,
+                "Grüße\n\nAé",
+                character_style,
+                text_style,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "font-8x16-cp437")]
+#[test]
+fn cp437_same_as_text() {
+    use embedded_vintage_fonts::FONT_8X16_CP437;
+
+    let style = MonoTextStyle::new(&FONT_8X16_CP437, BinaryColor::On);
+
+    check_same_as_text(
+        b"\xc9\xcd\xbb\n\xba\xb1\xba\n\xc8\xcd\xbc",
+        Encoding::Cp437,
+        "╔═╗\n║▒║\n╚═╝",
+        style,
+        TextStyle::with_baseline(Baseline::Top),
+    );
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn empty() {
+    use embedded_vintage_fonts::FONT_6X8;
+
+    let style = MonoTextStyle::new(&FONT_6X8, BinaryColor::On);
+    let byte_text = ByteText::new(b"", Encoding::Latin1, Point::new(3, 4), style);
+
+    let mut display = MockDisplay::new();
+    assert_eq!(byte_text.draw(&mut display), Ok(Point::new(3, 4)));
+    assert_eq!(
+        byte_text.bounding_box(),
+        Text::new("", Point::new(3, 4), style).bounding_box()
+    );
+}
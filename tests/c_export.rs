@@ -0,0 +1,335 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x8",
+        feature = "font-6x8-cp437",
+        feature = "font-6x12",
+        feature = "font-8x16",
+        feature = "font-8x16-cp437",
+        feature = "font-12x16",
+        feature = "font-24x32"
+    )
+))]
+
+use embedded_graphics::{
+    geometry::{Point, Size},
+    image::ImageRaw,
+    mono_font::{mapping::StrGlyphMapping, DecorationDimensions, MonoFont},
+};
+use embedded_vintage_fonts::{byte_text::Encoding, glyph::Glyph};
+
+#[path = "../examples/export/gfx.rs"]
+mod gfx;
+// The export example uses more of this module than the tests do.
+#[path = "../examples/export/glyphs.rs"]
+#[allow(dead_code)]
+mod glyphs;
+#[path = "../examples/export/u8g2.rs"]
+mod u8g2;
+
+/// Returns the set pixels of the glyph for `c` from `font` relative to the top
+/// left corner of the cell.
+fn expected_pixels(font: &MonoFont, c: char) -> Vec<Point> {
+    Glyph::new(font, c).pixels().collect()
+}
+
+/// Draws the glyph for `byte` like `Adafruit_GFX::drawChar` with the cursor
+/// at the baseline of the cell.
+fn draw_gfx(font: &gfx::GfxFont, baseline: u32, byte: u8) -> Vec<Point> {
+    let glyph = font.glyphs[(byte - font.first) as usize];
+    let mut offset = glyph.bitmap_offset as usize;
+    let mut bits = 0u8;
+    let mut bit = 0;
+    let mut pixels = Vec::new();
+
+    for yy in 0..glyph.height as i32 {
+        for xx in 0..glyph.width as i32 {
+            if bit & 7 == 0 {
+                bits = font.bitmap[offset];
+                offset += 1;
+            }
+            bit += 1;
+            if bits & 0x80 != 0 {
+                pixels.push(Point::new(
+                    glyph.x_offset as i32 + xx,
+                    baseline as i32 + glyph.y_offset as i32 + yy,
+                ));
+            }
+            bits <<= 1;
+        }
+    }
+
+    pixels
+}
+
+fn check_gfx(font: &MonoFont, encoding: Encoding) {
+    let gfx_font = gfx::to_gfx(font, encoding).unwrap();
+
+    assert_eq!(gfx_font.first, b' ');
+    assert_eq!(
+        gfx_font.glyphs.len(),
+        (gfx_font.last - gfx_font.first) as usize + 1
+    );
+    assert_eq!(gfx_font.y_advance as u32, font.character_size.height);
+
+    for byte in gfx_font.first..=gfx_font.last {
+        let c = encoding.decode(byte);
+
+        assert_eq!(
+            draw_gfx(&gfx_font, font.baseline, byte),
+            expected_pixels(font, c),
+            "{:?}",
+            c
+        );
+        assert_eq!(
+            gfx_font.glyphs[(byte - gfx_font.first) as usize].x_advance as u32,
+            font.character_size.width
+        );
+    }
+}
+
+/// Reads bit fields like `u8g2_font_decode_get_unsigned_bits`.
+struct BitReader<'a> {
+    data: &'a [u8],
+    bit: usize,
+}
+
+impl BitReader<'_> {
+    fn unsigned(&mut self, bits: u8) -> u32 {
+        let value = (0..bits as usize).fold(0, |value, i| {
+            let bit = self.bit + i;
+            value | u32::from(self.data[bit / 8] >> (bit % 8) & 1) << i
+        });
+        self.bit += bits as usize;
+
+        value
+    }
+
+    fn signed(&mut self, bits: u8) -> i32 {
+        self.unsigned(bits) as i32 - (1 << (bits - 1))
+    }
+}
+
+/// Looks up the glyph data for `c` like `u8g2_font_get_glyph_data`.
+fn u8g2_glyph_data(font: &[u8], c: char) -> Option<&[u8]> {
+    let word = |data: &[u8], i: usize| u16::from_be_bytes([data[i], data[i + 1]]) as usize;
+    let glyphs = &font[u8g2::HEADER_SIZE..];
+    let encoding = c as usize;
+
+    if encoding <= 0xff {
+        let mut i = if encoding >= 'a' as usize {
+            word(font, 19)
+        } else if encoding >= 'A' as usize {
+            word(font, 17)
+        } else {
+            0
+        };
+        while glyphs[i + 1] != 0 {
+            if glyphs[i] as usize == encoding {
+                return Some(&glyphs[i + 2..]);
+            }
+            i += glyphs[i + 1] as usize;
+        }
+    } else {
+        let table = word(font, 21);
+        let mut i = table;
+        let mut entry = table;
+        loop {
+            i += word(glyphs, entry);
+            let last = word(glyphs, entry + 2);
+            entry += 4;
+            if last >= encoding {
+                break;
+            }
+        }
+        loop {
+            let e = word(glyphs, i);
+            if e == 0 {
+                break;
+            }
+            if e == encoding {
+                return Some(&glyphs[i + 3..]);
+            }
+            i += glyphs[i + 2] as usize;
+        }
+    }
+
+    None
+}
+
+/// Decodes a glyph like `u8g2_font_decode_glyph` with the baseline at
+/// `baseline` and returns the set pixels and the advance.
+fn draw_u8g2(font: &[u8], glyph: &[u8], baseline: i32) -> (Vec<Point>, i32) {
+    let mut reader = BitReader {
+        data: glyph,
+        bit: 0,
+    };
+    let width = reader.unsigned(font[4]);
+    let height = reader.unsigned(font[5]);
+    let x = reader.signed(font[6]);
+    let y = reader.signed(font[7]);
+    let advance = reader.signed(font[8]);
+    let top = baseline - (height as i32 + y);
+
+    // The run lengths continue from row to row, so the runs can be decoded
+    // into a single row first.
+    let mut row = Vec::new();
+    if width > 0 {
+        loop {
+            let a = reader.unsigned(font[2]) as usize;
+            let b = reader.unsigned(font[3]) as usize;
+            loop {
+                row.extend(std::iter::repeat_n(false, a));
+                row.extend(std::iter::repeat_n(true, b));
+                if reader.unsigned(1) == 0 {
+                    break;
+                }
+            }
+            if row.len() >= (width * height) as usize {
+                break;
+            }
+        }
+    }
+    assert_eq!(row.len(), (width * height) as usize);
+
+    let pixels = row
+        .iter()
+        .enumerate()
+        .filter(|(_, &set)| set)
+        .map(|(i, _)| {
+            let i = i as i32;
+            Point::new(x + i % width as i32, top + i / width as i32)
+        })
+        .collect();
+
+    (pixels, advance)
+}
+
+fn check_u8g2(font: &MonoFont) {
+    let data = u8g2::to_u8g2(font).unwrap();
+    let ascent = font.baseline as i32 + 1;
+    let chars = glyphs::characters(font);
+
+    assert_eq!(data[9] as u32, font.character_size.width);
+    assert_eq!(data[10] as u32, font.character_size.height);
+
+    for &c in &chars {
+        let glyph = u8g2_glyph_data(&data, c).unwrap_or_else(|| panic!("{:?} is missing", c));
+        let (pixels, advance) = draw_u8g2(&data, glyph, ascent);
+
+        assert_eq!(pixels, expected_pixels(font, c), "{:?}", c);
+        assert_eq!(advance as u32, font.character_size.width);
+    }
+
+    for c in ['\0', '\u{7f}', '\u{ffff}'] {
+        assert!(u8g2_glyph_data(&data, c).is_none(), "{:?}", c);
+    }
+
+    let top_of_a = expected_pixels(font, 'A').iter().map(|p| p.y).min();
+    assert_eq!(Some(ascent - data[13] as i8 as i32), top_of_a);
+}
+
+#[test]
+fn c_headers() {
+    let font = MonoFont {
+        image: ImageRaw::new(&[0b1000_0000, 0b0100_0000], 2),
+        character_size: Size::new(2, 2),
+        character_spacing: 0,
+        baseline: 1,
+        strikethrough: DecorationDimensions::new(0, 1),
+        underline: DecorationDimensions::new(1, 1),
+        glyph_mapping: &StrGlyphMapping::new("?", 0),
+    };
+
+    assert_eq!(
+        gfx::to_gfx(&font, Encoding::Latin1)
+            .unwrap()
+            .to_c_header("test"),
+        "// test from embedded-vintage-fonts
+
+#pragma once
+
+#include <Adafruit_GFX.h>
+
+const uint8_t testBitmaps[] PROGMEM = {
+  0x90,
+};
+
+const GFXglyph testGlyphs[] PROGMEM = {
+  {     0,   2,   2,   2,    0,   -1 }, // 0x3F '?'
+};
+
+const GFXfont test PROGMEM = {
+  (uint8_t  *)testBitmaps,
+  (GFXglyph *)testGlyphs,
+  0x3F, 0x3F, 2 };
+"
+    );
+
+    assert_eq!(
+        u8g2::to_c_source("u8g2_font_test", &[1, 2, 0xff]),
+        "// u8g2_font_test from embedded-vintage-fonts
+
+#include <u8g2.h>
+
+const uint8_t u8g2_font_test[3] U8G2_FONT_SECTION(\"u8g2_font_test\") = {
+  0x01, 0x02, 0xFF,
+};
+"
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8() {
+    check_gfx(&embedded_vintage_fonts::FONT_6X8, Encoding::Latin1);
+    check_u8g2(&embedded_vintage_fonts::FONT_6X8);
+}
+
+#[test]
+#[cfg(feature = "font-6x8-cp437")]
+fn font_6x8_cp437() {
+    use embedded_vintage_fonts::mapping::CP437_GRAPHICS;
+
+    let font = embedded_vintage_fonts::FONT_6X8_CP437;
+    let gfx_font = gfx::to_gfx(&font, Encoding::Cp437).unwrap();
+
+    assert!(CP437_GRAPHICS.iter().all(|c| gfx_font.chars.contains(c)));
+    check_gfx(&font, Encoding::Cp437);
+    check_u8g2(&font);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn font_6x12() {
+    check_gfx(&embedded_vintage_fonts::FONT_6X12, Encoding::Latin1);
+    check_u8g2(&embedded_vintage_fonts::FONT_6X12);
+}
+
+#[test]
+#[cfg(feature = "font-8x16")]
+fn font_8x16() {
+    check_gfx(&embedded_vintage_fonts::FONT_8X16, Encoding::Latin1);
+    check_u8g2(&embedded_vintage_fonts::FONT_8X16);
+}
+
+#[test]
+#[cfg(feature = "font-8x16-cp437")]
+fn font_8x16_cp437() {
+    check_gfx(&embedded_vintage_fonts::FONT_8X16_CP437, Encoding::Cp437);
+    check_u8g2(&embedded_vintage_fonts::FONT_8X16_CP437);
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn font_12x16() {
+    check_gfx(&embedded_vintage_fonts::FONT_12X16, Encoding::Latin1);
+    check_u8g2(&embedded_vintage_fonts::FONT_12X16);
+}
+
+#[test]
+#[cfg(feature = "font-24x32")]
+fn font_24x32() {
+    check_gfx(&embedded_vintage_fonts::FONT_24X32, Encoding::Latin1);
+    check_u8g2(&embedded_vintage_fonts::FONT_24X32);
+}
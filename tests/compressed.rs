@@ -0,0 +1,125 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x8",
+        feature = "font-6x12",
+        feature = "font-8x16",
+        feature = "font-12x16",
+        feature = "font-24x32"
+    )
+))]
+
+use embedded_graphics::{
+    geometry::{Dimensions, Point},
+    mock_display::MockDisplay,
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::compressed::{
+    CompressedFont, CompressedTextStyle, CompressedTextStyleBuilder,
+};
+
+/// Checks that every glyph renders the same from the compressed font.
+fn check_all_glyphs(font: &MonoFont, compressed: &CompressedFont) {
+    let style = MonoTextStyle::new(font, BinaryColor::On);
+    let compressed_style = CompressedTextStyle::new(compressed, BinaryColor::On);
+
+    for c in (' '..='~').chain('¡'..='ÿ').chain(['\u{ffff}']) {
+        let mut buf = [0u8; 4];
+        let text = c.encode_utf8(&mut buf);
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut compressed_display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), compressed_style, Baseline::Top)
+            .draw(&mut compressed_display)
+            .unwrap();
+
+        assert_eq!(compressed_display, display, "{:?}", c);
+    }
+}
+
+/// Checks that background and decorations are drawn the same.
+fn check_decorations(font: &MonoFont, compressed: &CompressedFont) {
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .strikethrough()
+        .build();
+    let compressed_style = CompressedTextStyleBuilder::new()
+        .font(compressed)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .strikethrough()
+        .build();
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    let text = Text::new("$y", Point::new(1, font.baseline as i32 + 2), style);
+    text.draw(&mut display).unwrap();
+
+    let mut compressed_display = MockDisplay::new();
+    compressed_display.set_allow_overdraw(true);
+    let compressed_text = Text::new(
+        "$y",
+        Point::new(1, font.baseline as i32 + 2),
+        compressed_style,
+    );
+    compressed_text.draw(&mut compressed_display).unwrap();
+
+    assert_eq!(compressed_text.bounding_box(), text.bounding_box());
+    assert_eq!(compressed_display, display);
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn font_6x8() {
+    use embedded_vintage_fonts::{compressed, FONT_6X8};
+
+    check_all_glyphs(&FONT_6X8, &compressed::FONT_6X8);
+    check_decorations(&FONT_6X8, &compressed::FONT_6X8);
+}
+
+#[cfg(feature = "font-6x12")]
+#[test]
+fn font_6x12() {
+    use embedded_vintage_fonts::{compressed, FONT_6X12};
+
+    check_all_glyphs(&FONT_6X12, &compressed::FONT_6X12);
+    check_decorations(&FONT_6X12, &compressed::FONT_6X12);
+}
+
+#[cfg(feature = "font-8x16")]
+#[test]
+fn font_8x16() {
+    use embedded_vintage_fonts::{compressed, FONT_8X16};
+
+    check_all_glyphs(&FONT_8X16, &compressed::FONT_8X16);
+    check_decorations(&FONT_8X16, &compressed::FONT_8X16);
+}
+
+#[cfg(feature = "font-12x16")]
+#[test]
+fn font_12x16() {
+    use embedded_vintage_fonts::{compressed, FONT_12X16};
+
+    check_all_glyphs(&FONT_12X16, &compressed::FONT_12X16);
+    check_decorations(&FONT_12X16, &compressed::FONT_12X16);
+}
+
+#[cfg(feature = "font-24x32")]
+#[test]
+fn font_24x32() {
+    use embedded_vintage_fonts::{compressed, FONT_24X32};
+
+    check_all_glyphs(&FONT_24X32, &compressed::FONT_24X32);
+    check_decorations(&FONT_24X32, &compressed::FONT_24X32);
+}
@@ -0,0 +1,164 @@
+#![cfg(all(test, feature = "font-6x8"))]
+
+use core::fmt::Write;
+use embedded_graphics::{
+    draw_target::{DrawTarget, DrawTargetExt},
+    geometry::{Point, Size},
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyleBuilder,
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::{console::Console, FONT_6X8};
+
+type TestConsole = Console<'static, MockDisplay<BinaryColor>, 10, 8>;
+
+fn console() -> TestConsole {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+
+    let mut console = Console::new(display, &FONT_6X8, BinaryColor::On, BinaryColor::Off);
+    console.clear().unwrap();
+    console
+}
+
+/// Returns the display showing `lines` like a console does.
+fn expected_display(lines: &[&str]) -> MockDisplay<BinaryColor> {
+    let style = MonoTextStyleBuilder::new()
+        .font(&FONT_6X8)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .build();
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.clear(BinaryColor::Off).unwrap();
+    for (row, line) in lines.iter().enumerate() {
+        Text::with_baseline(line, Point::new(0, row as i32 * 8), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+    }
+
+    display
+}
+
+fn lines(console: &TestConsole) -> Vec<String> {
+    let (_, rows) = console.size();
+    (0..rows)
+        .map(|row| console.line(row).collect::<String>().trim_end().to_string())
+        .collect()
+}
+
+#[test]
+fn size() {
+    let console = console();
+    assert_eq!(console.size(), (10, 8));
+
+    let small: Console<'_, _, 4, 2> = Console::new(
+        MockDisplay::<BinaryColor>::new(),
+        &FONT_6X8,
+        BinaryColor::On,
+        BinaryColor::Off,
+    );
+    assert_eq!(small.size(), (4, 2));
+}
+
+#[test]
+fn wrap() {
+    let mut console = console();
+    console.print("0123456789").unwrap();
+    assert_eq!(console.cursor(), (10, 0));
+
+    console.print("AB").unwrap();
+    assert_eq!(console.cursor(), (2, 1));
+    assert_eq!(lines(&console)[..2], ["0123456789", "AB"]);
+    assert_eq!(console.release(), expected_display(&["0123456789", "AB"]));
+}
+
+#[test]
+fn new_line_after_full_line() {
+    let mut console = console();
+    console.print("0123456789\nA").unwrap();
+
+    assert_eq!(lines(&console)[..3], ["0123456789", "A", ""]);
+}
+
+#[test]
+fn scroll() {
+    let mut console = console();
+    for i in 0..10 {
+        writeln!(console, "line {}", i).unwrap();
+    }
+    write!(console, "last").unwrap();
+
+    let expected = [
+        "line 3", "line 4", "line 5", "line 6", "line 7", "line 8", "line 9", "last",
+    ];
+    assert_eq!(console.cursor(), (4, 7));
+    assert_eq!(lines(&console), expected);
+    assert_eq!(console.release(), expected_display(&expected));
+}
+
+#[test]
+fn control_characters() {
+    let mut console = console();
+    console.print("abc\rX\n").unwrap();
+    console.print("a\tb\n").unwrap();
+    console.print("abc\u{8}\u{8}d\u{1b}\n").unwrap();
+    console.print("\u{8}x").unwrap();
+
+    let expected = ["Xbc", "a       b", "ad", "x"];
+    assert_eq!(lines(&console)[..4], expected);
+    assert_eq!(console.release(), expected_display(&expected));
+}
+
+#[test]
+fn tab_at_end_of_line() {
+    let mut console = console();
+    console.print("012345678\tA").unwrap();
+
+    assert_eq!(lines(&console)[..2], ["012345678", "A"]);
+}
+
+#[test]
+fn no_room_for_characters() {
+    let mut console: Console<'_, _, 0, 0> = Console::new(
+        MockDisplay::<BinaryColor>::new(),
+        &FONT_6X8,
+        BinaryColor::On,
+        BinaryColor::Off,
+    );
+    console.print("Hello\nWorld").unwrap();
+
+    assert_eq!(console.size(), (0, 0));
+    assert_eq!(console.release(), MockDisplay::new());
+}
+
+#[test]
+fn offset_target() {
+    let mut display = MockDisplay::new();
+    {
+        let clipped = display.clipped(&Rectangle::new(Point::new(4, 8), Size::new(20, 20)));
+        let mut console: Console<'_, _, 10, 8> =
+            Console::new(clipped, &FONT_6X8, BinaryColor::On, BinaryColor::Off);
+        assert_eq!(console.size(), (3, 2));
+        console.print("ab").unwrap();
+    }
+
+    let mut expected = MockDisplay::new();
+    Text::with_baseline(
+        "ab",
+        Point::new(4, 8),
+        MonoTextStyleBuilder::new()
+            .font(&FONT_6X8)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build(),
+        Baseline::Top,
+    )
+    .draw(&mut expected)
+    .unwrap();
+    assert_eq!(display, expected);
+}
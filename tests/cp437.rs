@@ -0,0 +1,150 @@
+#![cfg(all(test, any(feature = "font-6x8-cp437", feature = "font-8x16-cp437")))]
+
+#[cfg(any(
+    all(feature = "font-6x8", feature = "font-6x8-cp437"),
+    all(feature = "font-8x16", feature = "font-8x16-cp437")
+))]
+use embedded_graphics::mono_font::MonoFont;
+use embedded_graphics::{
+    geometry::Point,
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyle,
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+
+/// Checks that the Latin-1 glyphs are the same as the ones from `original`.
+#[cfg(any(
+    all(feature = "font-6x8", feature = "font-6x8-cp437"),
+    all(feature = "font-8x16", feature = "font-8x16-cp437")
+))]
+fn check_latin1_glyphs(font: &MonoFont, original: &MonoFont) {
+    let style = MonoTextStyle::new(font, BinaryColor::On);
+    let original_style = MonoTextStyle::new(original, BinaryColor::On);
+
+    for c in (' '..='~').chain('¡'..='ÿ').chain(['\u{ffff}']) {
+        let mut buf = [0u8; 4];
+        let text = c.encode_utf8(&mut buf);
+
+        let mut display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+            .draw(&mut display)
+            .unwrap();
+
+        let mut original_display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), original_style, Baseline::Top)
+            .draw(&mut original_display)
+            .unwrap();
+
+        assert_eq!(display, original_display, "{:?}", c);
+    }
+}
+
+#[cfg(all(feature = "font-6x8", feature = "font-6x8-cp437"))]
+#[test]
+fn font_6x8_latin1() {
+    check_latin1_glyphs(
+        &embedded_vintage_fonts::FONT_6X8_CP437,
+        &embedded_vintage_fonts::FONT_6X8,
+    );
+}
+
+#[cfg(all(feature = "font-8x16", feature = "font-8x16-cp437"))]
+#[test]
+fn font_8x16_latin1() {
+    check_latin1_glyphs(
+        &embedded_vintage_fonts::FONT_8X16_CP437,
+        &embedded_vintage_fonts::FONT_8X16,
+    );
+}
+
+#[cfg(feature = "font-6x8-cp437")]
+#[test]
+fn font_6x8_frame() -> Result<(), core::convert::Infallible> {
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_6X8_CP437, BinaryColor::On);
+    let mut display = MockDisplay::new();
+
+    Text::with_baseline("╔╤\n╟┼\n░▓", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "            ",
+            "            ",
+            " ###########",
+            " #          ",
+            " # #########",
+            " # #    #   ",
+            " # #    #   ",
+            " # #    #   ",
+            " # #    #   ",
+            " # #    #   ",
+            " # #    #   ",
+            " # #########",
+            " # #    #   ",
+            " # #    #   ",
+            " # #    #   ",
+            " # #    #   ",
+            "# # #  # # #",
+            "      ######",
+            " # # ## # # ",
+            "      ######",
+            "# # #  # # #",
+            "      ######",
+            " # # ## # # ",
+            "      ######",
+        ])
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "font-8x16-cp437")]
+#[test]
+fn font_8x16_frame() -> Result<(), core::convert::Infallible> {
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_8X16_CP437, BinaryColor::On);
+    let mut display = MockDisplay::new();
+
+    Text::with_baseline("╔╕\n╙┼", Point::zero(), style, Baseline::Top).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "                ",
+            "                ",
+            "                ",
+            "                ",
+            "                ",
+            "                ",
+            " ############   ",
+            " ##        ##   ",
+            " ##  ########   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ##  ##    ##   ",
+            " ###############",
+            "           ##   ",
+            "           ##   ",
+            "           ##   ",
+            "           ##   ",
+            "           ##   ",
+            "           ##   ",
+            "           ##   ",
+            "           ##   ",
+        ])
+    );
+
+    Ok(())
+}
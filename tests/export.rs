@@ -0,0 +1,223 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x8",
+        feature = "font-6x8-cp437",
+        feature = "font-6x12",
+        feature = "font-8x16",
+        feature = "font-8x16-cp437",
+        feature = "font-12x16",
+        feature = "font-24x32"
+    )
+))]
+
+use embedded_graphics::{
+    geometry::Size,
+    image::ImageRaw,
+    mono_font::{mapping::StrGlyphMapping, DecorationDimensions, MonoFont},
+};
+use embedded_vintage_fonts::glyph::Glyph;
+
+#[path = "../examples/bdf2raw/bdf.rs"]
+mod bdf;
+// The examples use more of these modules than the tests do.
+#[path = "../examples/bdf2raw/convert.rs"]
+#[allow(dead_code)]
+mod convert;
+#[path = "../examples/export/bdf.rs"]
+mod export_bdf;
+#[path = "../examples/export/psf.rs"]
+mod export_psf;
+#[path = "../examples/export/glyphs.rs"]
+#[allow(dead_code)]
+mod glyphs;
+
+/// Checks that `font` exported as BDF and imported again has the same glyphs
+/// and metrics.
+fn check_bdf_round_trip(font: &MonoFont) {
+    let exported = export_bdf::to_bdf(font, "test");
+    let imported: bdf::Font = exported.parse().unwrap();
+    let converted = convert::convert(&imported, 16).unwrap();
+
+    assert_eq!(converted.misfits, []);
+    assert_eq!(
+        Size::from(converted.character_size),
+        font.character_size,
+        "character size"
+    );
+    assert_eq!(converted.baseline, font.baseline, "baseline");
+    assert_eq!(
+        DecorationDimensions::new(converted.underline.0, converted.underline.1),
+        font.underline,
+        "underline"
+    );
+    assert_eq!(
+        DecorationDimensions::new(converted.strikethrough.0, converted.strikethrough.1),
+        font.strikethrough,
+        "strikethrough"
+    );
+
+    let mapping = StrGlyphMapping::new(&converted.mapping, converted.replacement);
+    let round_trip = MonoFont {
+        image: ImageRaw::new(&converted.raw, converted.image_width),
+        glyph_mapping: &mapping,
+        ..*font
+    };
+
+    let chars = glyphs::characters(font);
+    assert_eq!(imported.glyphs.len(), chars.len());
+    for c in chars.into_iter().chain(['\u{fffd}', '\0']) {
+        assert!(
+            Glyph::new(font, c)
+                .rows()
+                .eq(Glyph::new(&round_trip, c).rows()),
+            "{:?}",
+            c
+        );
+    }
+}
+
+/// The bitmap of a PSF2 glyph and its characters from the Unicode table.
+type PsfGlyph = (Vec<u8>, Vec<char>);
+
+/// A minimal PSF2 reader returning the glyph size and the glyphs.
+fn read_psf2(psf: &[u8]) -> (Size, Vec<PsfGlyph>) {
+    let field = |i: usize| u32::from_le_bytes(psf[4 + 4 * i..8 + 4 * i].try_into().unwrap());
+
+    assert_eq!(psf[..4], export_psf::PSF2_MAGIC);
+    assert_eq!(field(0), 0, "version");
+    assert_eq!(field(2), export_psf::PSF2_HAS_UNICODE_TABLE, "flags");
+
+    let header_size = field(1) as usize;
+    let length = field(3) as usize;
+    let glyph_size = field(4) as usize;
+    let size = Size::new(field(6), field(5));
+    assert_eq!(
+        glyph_size,
+        size.width.div_ceil(8) as usize * size.height as usize
+    );
+
+    let (bitmaps, table) = psf[header_size..].split_at(length * glyph_size);
+    let entries = table
+        .split(|&b| b == export_psf::PSF2_SEPARATOR)
+        .map(|entry| {
+            std::str::from_utf8(entry)
+                .unwrap()
+                .chars()
+                .collect::<Vec<_>>()
+        });
+
+    let glyphs: Vec<_> = bitmaps
+        .chunks(glyph_size)
+        .map(<[u8]>::to_vec)
+        .zip(entries)
+        .collect();
+    assert_eq!(glyphs.len(), length);
+    assert_eq!(table.last(), Some(&export_psf::PSF2_SEPARATOR));
+
+    (size, glyphs)
+}
+
+/// Checks that the PSF2 export of `font` has the glyph of every supported
+/// character and keeps the Latin-1 characters at their codes.
+fn check_psf2(font: &MonoFont) {
+    let (size, glyphs) = read_psf2(&export_psf::to_psf2(font));
+
+    assert_eq!(size, font.character_size);
+    assert_eq!(glyphs.len(), 256);
+
+    let chars = glyphs::characters(font);
+    for &c in &chars {
+        let (position, (bitmap, _)) = glyphs
+            .iter()
+            .enumerate()
+            .find(|(_, (_, entry))| entry.contains(&c))
+            .unwrap_or_else(|| panic!("{:?} is missing", c));
+
+        assert_eq!(bitmap, &glyphs::glyph_bytes(font, c).concat(), "{:?}", c);
+        if (c as u32) < 256 {
+            assert_eq!(position, c as usize, "{:?}", c);
+        }
+    }
+
+    assert!(glyphs['?' as usize].1.contains(&'\u{fffd}'));
+    assert_eq!(
+        glyphs.iter().map(|(_, entry)| entry.len()).sum::<usize>(),
+        chars.len() + 1
+    );
+}
+
+#[test]
+fn glyph_bytes() {
+    let font = MonoFont {
+        image: ImageRaw::new(&[0b1010_1010, 0b1100_0000, 0b0101_0101, 0b0100_0000], 10),
+        character_size: Size::new(10, 2),
+        character_spacing: 0,
+        baseline: 1,
+        strikethrough: DecorationDimensions::new(0, 1),
+        underline: DecorationDimensions::new(1, 1),
+        glyph_mapping: &StrGlyphMapping::new("?", 0),
+    };
+
+    assert_eq!(
+        glyphs::glyph_bytes(&font, '?'),
+        [[0b1010_1010, 0b1100_0000], [0b0101_0101, 0b0100_0000]]
+    );
+    assert_eq!(glyphs::characters(&font), ['?']);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8() {
+    check_bdf_round_trip(&embedded_vintage_fonts::FONT_6X8);
+    check_psf2(&embedded_vintage_fonts::FONT_6X8);
+}
+
+#[test]
+#[cfg(feature = "font-6x8-cp437")]
+fn font_6x8_cp437() {
+    use embedded_vintage_fonts::mapping::CP437_GRAPHICS;
+
+    let font = embedded_vintage_fonts::FONT_6X8_CP437;
+
+    assert!(CP437_GRAPHICS
+        .iter()
+        .all(|c| glyphs::characters(&font).contains(c)));
+    check_bdf_round_trip(&font);
+    check_psf2(&font);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn font_6x12() {
+    check_bdf_round_trip(&embedded_vintage_fonts::FONT_6X12);
+    check_psf2(&embedded_vintage_fonts::FONT_6X12);
+}
+
+#[test]
+#[cfg(feature = "font-8x16")]
+fn font_8x16() {
+    check_bdf_round_trip(&embedded_vintage_fonts::FONT_8X16);
+    check_psf2(&embedded_vintage_fonts::FONT_8X16);
+}
+
+#[test]
+#[cfg(feature = "font-8x16-cp437")]
+fn font_8x16_cp437() {
+    check_bdf_round_trip(&embedded_vintage_fonts::FONT_8X16_CP437);
+    check_psf2(&embedded_vintage_fonts::FONT_8X16_CP437);
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn font_12x16() {
+    check_bdf_round_trip(&embedded_vintage_fonts::FONT_12X16);
+    check_psf2(&embedded_vintage_fonts::FONT_12X16);
+}
+
+#[test]
+#[cfg(feature = "font-24x32")]
+fn font_24x32() {
+    check_bdf_round_trip(&embedded_vintage_fonts::FONT_24X32);
+    check_psf2(&embedded_vintage_fonts::FONT_24X32);
+}
@@ -0,0 +1,347 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x8",
+        feature = "font-6x12",
+        feature = "font-8x16-cp437",
+        feature = "font-12x16",
+        feature = "font-24x32"
+    )
+))]
+
+use embedded_graphics::{
+    geometry::{Dimensions, Point},
+    mock_display::MockDisplay,
+    mono_font::{MonoFont, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::{
+    external::{container_len, write_container, ExternalFont, ExternalTextStyle, FontStorage},
+    mapping::CP437_GRAPHICS,
+};
+
+/// An in-memory stand-in for an external flash counting the reads.
+struct Memory {
+    data: Vec<u8>,
+    reads: usize,
+    fail_from: Option<u32>,
+}
+
+impl Memory {
+    fn new(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            reads: 0,
+            fail_from: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ReadError(u32);
+
+impl FontStorage for Memory {
+    type Error = ReadError;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        self.reads += 1;
+
+        let start = offset as usize;
+        match self.data.get(start..start + bytes.len()) {
+            Some(data) if self.fail_from.is_none_or(|fail_from| offset < fail_from) => {
+                bytes.copy_from_slice(data);
+                Ok(())
+            }
+            _ => Err(ReadError(offset)),
+        }
+    }
+}
+
+fn container(font: &MonoFont) -> Vec<u8> {
+    let mut data = vec![0xa5; container_len(font)];
+    assert_eq!(write_container(font, &mut data), Ok(data.len()));
+
+    data
+}
+
+fn draw<S>(text: &str, style: S) -> MockDisplay<BinaryColor>
+where
+    S: embedded_graphics::text::renderer::TextRenderer<Color = BinaryColor>,
+{
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.set_allow_out_of_bounds_drawing(true);
+    Text::with_baseline(text, Point::new(1, 2), style, Baseline::Top)
+        .draw(&mut display)
+        .unwrap();
+
+    display
+}
+
+/// Checks that every character renders the same from the container.
+fn check_font(font: &MonoFont) {
+    let external = ExternalFont::new(Memory::new(container(font)), 0).unwrap();
+
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .strikethrough()
+        .build();
+    let mut external_style = ExternalTextStyle::new(&external, BinaryColor::On);
+    external_style.background_color = Some(BinaryColor::Off);
+    external_style.underline_color = embedded_graphics::text::DecorationColor::TextColor;
+    external_style.strikethrough_color = embedded_graphics::text::DecorationColor::TextColor;
+
+    let chars = (' '..='~').chain('¡'..='ÿ').chain(CP437_GRAPHICS);
+    for c in chars.chain(['\u{a0}', '€', '\u{ffff}', '\u{1f600}']) {
+        let mut buf = [0u8; 4];
+        let text = c.encode_utf8(&mut buf);
+
+        assert_eq!(draw(text, external_style), draw(text, style), "{:?}", c);
+    }
+
+    let text = Text::new("Ab", Point::zero(), style);
+    let external_text = Text::new("Ab", Point::zero(), external_style);
+    assert_eq!(external_text.bounding_box(), text.bounding_box());
+    assert!(external.take_error().is_none());
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8() {
+    check_font(&embedded_vintage_fonts::FONT_6X8);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn font_6x12() {
+    check_font(&embedded_vintage_fonts::FONT_6X12);
+}
+
+#[test]
+#[cfg(feature = "font-8x16-cp437")]
+fn font_8x16_cp437() {
+    check_font(&embedded_vintage_fonts::FONT_8X16_CP437);
+
+    let external = ExternalFont::new(
+        Memory::new(container(&embedded_vintage_fonts::FONT_8X16_CP437)),
+        0,
+    )
+    .unwrap();
+    assert_eq!(external.header().range_count, 19);
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn font_12x16() {
+    check_font(&embedded_vintage_fonts::FONT_12X16);
+}
+
+#[test]
+#[cfg(feature = "font-24x32")]
+fn font_24x32() {
+    check_font(&embedded_vintage_fonts::FONT_24X32);
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn header() {
+    use embedded_graphics::{geometry::Size, mono_font::DecorationDimensions};
+    use embedded_vintage_fonts::external::{HEADER_LEN, MAGIC, RANGE_LEN};
+
+    let font = &embedded_vintage_fonts::FONT_12X16;
+    let data = container(font);
+    assert_eq!(data[..4], MAGIC);
+
+    let external = ExternalFont::new(Memory::new(data.clone()), 0).unwrap();
+    let header = external.header();
+    assert_eq!(header.character_size, Size::new(12, 16));
+    assert_eq!(header.character_spacing, 0);
+    assert_eq!(header.baseline, 13);
+    assert_eq!(header.strikethrough, DecorationDimensions::new(7, 2));
+    assert_eq!(header.underline, DecorationDimensions::new(15, 2));
+    assert_eq!(header.range_count, 2);
+    assert_eq!(header.glyph_count, 190);
+    assert_eq!(header.replacement_glyph, u16::from(b'?' - b' '));
+    assert_eq!(header.row_len(), 2);
+    assert_eq!(header.glyph_len(), 32);
+    assert_eq!(header.container_len(), data.len());
+    assert_eq!(data.len(), HEADER_LEN + 2 * RANGE_LEN + 190 * 32);
+
+    for c in ['A', 'ÿ', '€'] {
+        assert_eq!(
+            usize::from(external.glyph_index(c)),
+            font.glyph_mapping.index(c)
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn container_at_offset() {
+    let font = &embedded_vintage_fonts::FONT_6X8;
+    let mut data = vec![0xff; 100];
+    data.extend(container(font));
+
+    let external = ExternalFont::new(Memory::new(data), 100).unwrap();
+    let style = ExternalTextStyle::new(&external, BinaryColor::On);
+    let mono_style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .build();
+    assert_eq!(draw("Offset", style), draw("Offset", mono_style));
+
+    // Header and two ranges on loading and one read per character.
+    assert_eq!(external.release().reads, 1 + 2 + 6);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn load_errors() {
+    use embedded_vintage_fonts::external::{LoadError, HEADER_LEN};
+
+    let font = &embedded_vintage_fonts::FONT_6X8;
+
+    let mut memory = Memory::new(container(font));
+    memory.fail_from = Some(HEADER_LEN as u32);
+    let error = ExternalFont::new(memory, 0).unwrap_err();
+    assert_eq!(error, LoadError::Storage(ReadError(HEADER_LEN as u32)));
+
+    let mut data = container(font);
+    data[0] = b'X';
+    let error = ExternalFont::new(Memory::new(data), 0).unwrap_err();
+    assert_eq!(error, LoadError::InvalidMagic);
+
+    let mut data = container(font);
+    data[4] = 2;
+    let error = ExternalFont::new(Memory::new(data), 0).unwrap_err();
+    assert_eq!(error, LoadError::UnsupportedVersion(2));
+
+    let mut data = container(font);
+    data[22] = 33;
+    let error = ExternalFont::new(Memory::new(data), 0).unwrap_err();
+    assert_eq!(error, LoadError::TooManyRanges);
+
+    let mut data = container(font);
+    data[6] = 65;
+    let error = ExternalFont::new(Memory::new(data), 0).unwrap_err();
+    assert_eq!(error, LoadError::GlyphTooLarge);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn storage_errors_while_drawing() {
+    use embedded_vintage_fonts::external::{HEADER_LEN, RANGE_LEN};
+
+    let font = &embedded_vintage_fonts::FONT_6X8;
+    let glyphs_offset = (HEADER_LEN + 2 * RANGE_LEN) as u32;
+
+    let mut memory = Memory::new(container(font));
+    memory.fail_from = Some(glyphs_offset);
+    let external = ExternalFont::new(memory, 0).unwrap();
+
+    let mut style = ExternalTextStyle::new(&external, BinaryColor::On);
+    style.background_color = Some(BinaryColor::Off);
+    let mono_style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .build();
+
+    // Failing glyphs get drawn blank and the first error gets kept.
+    assert_eq!(draw("AB", style), draw("  ", mono_style));
+    assert_eq!(
+        external.take_error(),
+        Some(ReadError(glyphs_offset + 33 * 8))
+    );
+    assert_eq!(external.take_error(), None);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn write_errors() {
+    use embedded_graphics::geometry::Size;
+    use embedded_vintage_fonts::external::WriteError;
+
+    let font = &embedded_vintage_fonts::FONT_6X8;
+
+    let mut data = vec![0; container_len(font) - 1];
+    assert_eq!(
+        write_container(font, &mut data),
+        Err(WriteError::BufferTooSmall)
+    );
+
+    let wide = MonoFont {
+        character_size: Size::new(0x10000, 8),
+        ..*font
+    };
+    assert_eq!(
+        write_container(&wide, &mut data),
+        Err(WriteError::Unsupported)
+    );
+}
+
+/// A flash only accepting reads aligned to 4 bytes.
+#[cfg(feature = "embedded-storage")]
+struct AlignedFlash(Vec<u8>);
+
+#[cfg(feature = "embedded-storage")]
+impl embedded_storage::nor_flash::ErrorType for AlignedFlash {
+    type Error = embedded_storage::nor_flash::NorFlashErrorKind;
+}
+
+#[cfg(feature = "embedded-storage")]
+impl embedded_storage::nor_flash::ReadNorFlash for AlignedFlash {
+    const READ_SIZE: usize = 4;
+
+    fn read(&mut self, offset: u32, bytes: &mut [u8]) -> Result<(), Self::Error> {
+        embedded_storage::nor_flash::check_read(self, offset, bytes.len())?;
+        bytes.copy_from_slice(&self.0[offset as usize..][..bytes.len()]);
+        Ok(())
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.len()
+    }
+}
+
+#[test]
+#[cfg(all(feature = "embedded-storage", feature = "font-6x12"))]
+fn nor_flash() {
+    use embedded_storage::nor_flash::NorFlashErrorKind;
+    use embedded_vintage_fonts::external::NorFlashStorage;
+
+    let font = &embedded_vintage_fonts::FONT_6X12;
+
+    // Place the container at an unaligned offset and pad the flash to whole
+    // words.
+    let mut data = vec![0xff; 3];
+    data.extend(container(font));
+    data.resize(data.len().next_multiple_of(4), 0xff);
+    let len = data.len() as u32;
+
+    let external = ExternalFont::new(NorFlashStorage::new(AlignedFlash(data)), 3).unwrap();
+    let style = ExternalTextStyle::new(&external, BinaryColor::On);
+    let mono_style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .build();
+    assert_eq!(draw("~Flash~", style), draw("~Flash~", mono_style));
+    assert_eq!(external.take_error(), None);
+
+    let mut storage = external.release();
+    let mut bytes = [0; 3];
+    assert_eq!(storage.read(len - 3, &mut bytes), Ok(()));
+    assert_eq!(
+        storage.read(len - 2, &mut bytes),
+        Err(NorFlashErrorKind::OutOfBounds)
+    );
+    assert_eq!(
+        storage.read(len + 1, &mut bytes),
+        Err(NorFlashErrorKind::OutOfBounds)
+    );
+}
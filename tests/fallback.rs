@@ -0,0 +1,156 @@
+#![cfg(test)]
+
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16"
+))]
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyleBuilder};
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16",
+    feature = "font-24x32"
+))]
+use embedded_graphics::{
+    geometry::{Dimensions, Point},
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyle,
+    pixelcolor::BinaryColor,
+    text::Text,
+    Drawable,
+};
+use embedded_vintage_fonts::fallback::transliterate;
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16",
+    feature = "font-24x32"
+))]
+use embedded_vintage_fonts::fallback::FallbackTextStyle;
+
+/// Checks that `text` gets drawn like `expected` with the plain font style.
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16",
+    feature = "font-24x32"
+))]
+fn check_drawn_as(text: &str, expected: &str, style: MonoTextStyle<'_, BinaryColor>) {
+    let position = Point::new(1, style.font.baseline as i32 + 1);
+    let fallback_text = Text::new(text, position, FallbackTextStyle::new(style));
+    let expected_text = Text::new(expected, position, style);
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    let next = fallback_text.draw(&mut display).unwrap();
+
+    let mut expected_display = MockDisplay::new();
+    expected_display.set_allow_overdraw(true);
+    let expected_next = expected_text.draw(&mut expected_display).unwrap();
+
+    assert_eq!(display, expected_display, "{:?}", text);
+    assert_eq!(next, expected_next, "{:?}", text);
+    assert_eq!(
+        fallback_text.bounding_box(),
+        expected_text.bounding_box(),
+        "{:?}",
+        text
+    );
+}
+
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16"
+))]
+fn check_font(font: &MonoFont) {
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .build();
+
+    check_drawn_as("Łź", "Lz", style);
+    check_drawn_as("“Hi”", "\"Hi\"", style);
+    check_drawn_as("a–b", "a-b", style);
+    check_drawn_as("a—", "a--", style);
+    check_drawn_as("…", "...", style);
+    check_drawn_as("3€", "3EUR", style);
+    check_drawn_as("Œ", "OE", style);
+    check_drawn_as("?\u{1f4a3}", "??", style);
+}
+
+#[test]
+fn transliterations() {
+    assert_eq!(transliterate('Ł'), Some("L"));
+    assert_eq!(transliterate('ő'), Some("o"));
+    assert_eq!(transliterate('ß'), Some("ss"));
+    assert_eq!(transliterate('ſ'), Some("s"));
+    assert_eq!(transliterate('€'), Some("EUR"));
+    assert_eq!(transliterate('…'), Some("..."));
+    assert_eq!(transliterate('a'), None);
+    assert_eq!(transliterate('Ɓ'), None);
+}
+
+#[test]
+fn all_replacements_are_ascii() {
+    for c in ('\u{0}'..='\u{2fff}').filter_map(|c| transliterate(c).map(|r| (c, r))) {
+        let (c, replacement) = c;
+        assert!(!replacement.is_empty(), "{:?}", c);
+        assert!(
+            replacement.chars().all(|r| (' '..='~').contains(&r)),
+            "{:?}",
+            c
+        );
+    }
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn font_6x8() {
+    check_font(&embedded_vintage_fonts::FONT_6X8);
+
+    // Latin-1 is supported by FONT_6X8 and gets drawn as it is.
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_6X8, BinaryColor::On);
+    check_drawn_as("Łódź", "Lódz", style);
+    check_drawn_as("Straße", "Straße", style);
+}
+
+#[cfg(feature = "font-6x12")]
+#[test]
+fn font_6x12() {
+    check_font(&embedded_vintage_fonts::FONT_6X12);
+
+    // Latin-1 is not supported by FONT_6X12 and gets transliterated too.
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_6X12, BinaryColor::On);
+    check_drawn_as("Łódź", "Lodz", style);
+    check_drawn_as("Straße«", "Strasse<<", style);
+}
+
+#[cfg(feature = "font-8x16")]
+#[test]
+fn font_8x16() {
+    check_font(&embedded_vintage_fonts::FONT_8X16);
+}
+
+#[cfg(feature = "font-12x16")]
+#[test]
+fn font_12x16() {
+    check_font(&embedded_vintage_fonts::FONT_12X16);
+}
+
+#[cfg(feature = "font-24x32")]
+#[test]
+fn font_24x32() {
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_24X32, BinaryColor::On);
+
+    check_drawn_as("Ł", "L", style);
+    check_drawn_as("–”", "-\"", style);
+}
@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "font-12x16"))]
 
 mod common;
 use embedded_vintage_fonts::FONT_12X16;
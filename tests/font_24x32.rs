@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "font-24x32"))]
 
 mod common;
 use embedded_vintage_fonts::FONT_24X32;
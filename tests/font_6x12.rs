@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "font-6x12"))]
 
 mod common;
 use embedded_graphics::{
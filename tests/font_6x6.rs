@@ -0,0 +1,280 @@
+#![cfg(all(test, feature = "font-6x6"))]
+
+use embedded_graphics::{
+    geometry::{Dimensions, Point, Size},
+    mock_display::MockDisplay,
+    pixelcolor::BinaryColor,
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+    transform::Transform,
+    Drawable,
+};
+use embedded_vintage_fonts::{
+    proportional::{ProportionalTextStyle, ProportionalTextStyleBuilder},
+    FONT_6X6,
+};
+
+const HEIGHT: u32 = FONT_6X6.character_size.height;
+const HELLO_WORLD: &str = "Hello World!";
+const HELLO_WORLD_WIDTH: u32 = 51;
+
+fn baseline_point() -> Point {
+    Point::new(0, FONT_6X6.baseline as i32)
+}
+
+#[test]
+fn text_dimensions() {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let hello = Text::new(HELLO_WORLD, Point::zero(), style);
+    let empty = Text::new("", Point::zero(), style);
+
+    assert_eq!(
+        hello.bounding_box().size,
+        Size::new(HELLO_WORLD_WIDTH, HEIGHT)
+    );
+    assert_eq!(empty.bounding_box().size, Size::new(0, 0));
+}
+
+#[test]
+fn text_corners() {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let hello = Text::new(HELLO_WORLD, baseline_point(), style).translate(Point::new(5, -20));
+    let empty = Text::new("", Point::zero(), style).translate(Point::new(10, 20));
+
+    assert_eq!(hello.bounding_box().top_left, Point::new(5, -20));
+    assert_eq!(
+        hello.bounding_box().bottom_right(),
+        Some(Point::new(
+            HELLO_WORLD_WIDTH as i32 + 5 - 1,
+            HEIGHT as i32 - 20 - 1
+        ))
+    );
+    assert_eq!(empty.bounding_box().top_left, Point::new(10, 20));
+    assert_eq!(empty.bounding_box().bottom_right(), None);
+}
+
+#[test]
+fn correct_m() -> Result<(), core::convert::Infallible> {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let mut display = MockDisplay::new();
+    Text::new("Mm", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "#   #      ",
+            "## ##  # # ",
+            "# # # # # #",
+            "#   # #   #",
+            "#   # #   #",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn correct_inverse_colored_m() -> Result<(), core::convert::Infallible> {
+    let mut display = MockDisplay::new();
+    let style = ProportionalTextStyleBuilder::new()
+        .font(&FONT_6X6)
+        .text_color(BinaryColor::Off)
+        .background_color(BinaryColor::On)
+        .build();
+    Text::new("Mm", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            ".###.######",
+            "..#..##.#.#",
+            ".#.#.#.#.#.",
+            ".###.#.###.",
+            ".###.#.###.",
+            "###########",
+        ])
+    );
+
+    Ok(())
+}
+
+// tests if black on white has really the same behavior as white on black
+#[test]
+fn compare_inverse_colored_m() -> Result<(), core::convert::Infallible> {
+    let mut display_inverse = MockDisplay::new();
+    let style_inverse = ProportionalTextStyleBuilder::new()
+        .font(&FONT_6X6)
+        .text_color(BinaryColor::Off)
+        .background_color(BinaryColor::On)
+        .build();
+    Text::new("Mm", baseline_point(), style_inverse).draw(&mut display_inverse)?;
+
+    let mut display_normal = MockDisplay::new();
+    let style_normal = ProportionalTextStyleBuilder::new()
+        .font(&FONT_6X6)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .build();
+    Text::new("Mm", baseline_point(), style_normal).draw(&mut display_normal)?;
+
+    let rect = display_inverse.affected_area();
+    assert!(!rect.is_zero_sized());
+    for y in rect.rows() {
+        for x in rect.columns() {
+            let p = Point::new(x, y);
+
+            let inverse_color = display_inverse.get_pixel(p);
+            let normal_color = display_normal.get_pixel(p);
+
+            assert_eq!(inverse_color, normal_color.map(|c| c.invert()));
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn correct_i() -> Result<(), core::convert::Infallible> {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let mut display = MockDisplay::new();
+    Text::new("Ii", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "# #", //
+            "#  ", //
+            "# #", //
+            "# #", //
+            "# #", //
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn correct_ascii_borders() -> Result<(), core::convert::Infallible> {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let mut display = MockDisplay::new();
+    Text::new(" ~", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "       ", //
+            "   ## #", //
+            "  #  # ", //
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn correct_dollar_y() -> Result<(), core::convert::Infallible> {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let mut display = MockDisplay::new();
+    Text::new("$y", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            " #### #  #",
+            "# #   #  #",
+            "##### ####",
+            "  # #    #",
+            "####  ### ",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn correct_latin1() -> Result<(), core::convert::Infallible> {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let mut display = MockDisplay::new();
+    Text::new("¡€¿", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "#   ##   #  ",
+            "   #        ",
+            "# ###   ##  ",
+            "#  #   #   #",
+            "#   ##  ### ",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dont_panic() -> Result<(), core::convert::Infallible> {
+    let two_question_marks = MockDisplay::from_pattern(&[
+        " ###   ### ",
+        "#   # #   #",
+        "  ##    ## ",
+        "           ",
+        "  #     #  ",
+    ]);
+
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+
+    let mut display = MockDisplay::new();
+    Text::new("\0\x7F", baseline_point(), style).draw(&mut display)?;
+    assert_eq!(display, two_question_marks);
+
+    let mut display = MockDisplay::new();
+    Text::new("\u{A0}À", baseline_point(), style).draw(&mut display)?;
+    assert_eq!(display, two_question_marks);
+
+    let mut display = MockDisplay::new();
+    Text::new("Ā💣", baseline_point(), style).draw(&mut display)?;
+    assert_eq!(display, two_question_marks);
+
+    Ok(())
+}
+
+#[test]
+fn no_fill_doesnt_hang() -> Result<(), core::convert::Infallible> {
+    let style = ProportionalTextStyle::new(&FONT_6X6, BinaryColor::On);
+    let mut display = MockDisplay::new();
+    Text::new(" ", baseline_point(), style).draw(&mut display)?;
+
+    assert_eq!(display, MockDisplay::new());
+
+    Ok(())
+}
+
+#[test]
+fn alignment_and_baseline() -> Result<(), core::convert::Infallible> {
+    let character_style = ProportionalTextStyleBuilder::new()
+        .font(&FONT_6X6)
+        .text_color(BinaryColor::On)
+        .underline()
+        .build();
+    let text_style = TextStyleBuilder::new()
+        .alignment(Alignment::Right)
+        .baseline(Baseline::Top)
+        .build();
+
+    let mut display = MockDisplay::new();
+    Text::with_text_style("Ii", Point::new(6, 0), character_style, text_style)
+        .draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "    # #", //
+            "    #  ", //
+            "    # #", //
+            "    # #", //
+            "    # #", //
+            "    ###", //
+        ])
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "font-6x8"))]
 
 mod common;
 use embedded_graphics::{
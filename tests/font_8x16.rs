@@ -1,4 +1,4 @@
-#![cfg(test)]
+#![cfg(all(test, feature = "font-8x16"))]
 
 mod common;
 use embedded_vintage_fonts::FONT_8X16;
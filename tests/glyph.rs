@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16",
+    feature = "font-24x32"
+))]
+use embedded_graphics::{
+    geometry::Point,
+    image::Image,
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyleBuilder,
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_graphics::{
+    geometry::{OriginDimensions, Size},
+    mono_font::MonoFont,
+};
+use embedded_vintage_fonts::glyph::Glyph;
+
+/// Checks that every glyph drawn as an image looks like the character drawn
+/// as text and that rows and pixels agree.
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16",
+    feature = "font-24x32"
+))]
+fn check_glyphs(font: &MonoFont) {
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .build();
+
+    for c in (' '..='~').chain('¡'..='ÿ').chain(['\u{ffff}']) {
+        let mut buf = [0u8; 4];
+        let text = c.encode_utf8(&mut buf);
+        let glyph = Glyph::new(font, c);
+
+        assert_eq!(glyph.size(), font.character_size);
+
+        let mut display = MockDisplay::new();
+        Image::new(&glyph, Point::zero())
+            .draw(&mut display)
+            .unwrap();
+
+        let mut text_display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+            .draw(&mut text_display)
+            .unwrap();
+
+        assert_eq!(display, text_display, "{:?}", c);
+
+        let width = font.character_size.width as i32;
+        let from_rows: Vec<Point> = glyph
+            .rows()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                (0..width)
+                    .filter(move |x| row & (1 << (width - 1 - x)) != 0)
+                    .map(move |x| Point::new(x, y as i32))
+            })
+            .collect();
+        assert_eq!(glyph.pixels().collect::<Vec<_>>(), from_rows, "{:?}", c);
+    }
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn font_6x8() {
+    check_glyphs(&embedded_vintage_fonts::FONT_6X8);
+}
+
+#[cfg(feature = "font-6x12")]
+#[test]
+fn font_6x12() {
+    check_glyphs(&embedded_vintage_fonts::FONT_6X12);
+}
+
+#[cfg(feature = "font-8x16")]
+#[test]
+fn font_8x16() {
+    check_glyphs(&embedded_vintage_fonts::FONT_8X16);
+}
+
+#[cfg(feature = "font-12x16")]
+#[test]
+fn font_12x16() {
+    check_glyphs(&embedded_vintage_fonts::FONT_12X16);
+}
+
+#[cfg(feature = "font-24x32")]
+#[test]
+fn font_24x32() {
+    check_glyphs(&embedded_vintage_fonts::FONT_24X32);
+}
+
+#[cfg(feature = "font-6x8")]
+#[test]
+fn sub_image() -> Result<(), core::convert::Infallible> {
+    use embedded_graphics::{image::ImageDrawableExt, primitives::Rectangle};
+
+    let glyph = Glyph::new(&embedded_vintage_fonts::FONT_6X8, 'T');
+    let mut display = MockDisplay::new();
+
+    let area = Rectangle::new(Point::new(1, 0), Size::new(3, 2));
+    Image::new(&glyph.sub_image(&area), Point::new(1, 1)).draw(&mut display)?;
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&[
+            "    ", //
+            " ###", " .#.",
+        ])
+    );
+
+    Ok(())
+}
+
+#[test]
+fn wide_rows() {
+    use embedded_graphics::{
+        image::ImageRaw,
+        mono_font::{mapping::StrGlyphMapping, DecorationDimensions},
+    };
+
+    // A single 40 x 2 pixel glyph with pixels set at both ends of its rows.
+    const DATA: [u8; 10] = [
+        0xff, 0x00, 0x00, 0x00, 0x0f, //
+        0x80, 0x00, 0x00, 0x01, 0x01,
+    ];
+    let font = MonoFont {
+        image: ImageRaw::new(&DATA, 40),
+        glyph_mapping: &StrGlyphMapping::new(" ", 0),
+        character_size: Size::new(40, 2),
+        character_spacing: 0,
+        baseline: 1,
+        strikethrough: DecorationDimensions::default_strikethrough(2),
+        underline: DecorationDimensions::default_underline(2),
+    };
+    let glyph = Glyph::new(&font, ' ');
+
+    assert_eq!(glyph.size(), Size::new(40, 2));
+    assert_eq!(glyph.rows().collect::<Vec<_>>(), [0xff00_0000, 0x8000_0001]);
+}
+
+#[cfg(feature = "font-6x6")]
+#[test]
+fn proportional() {
+    use embedded_vintage_fonts::FONT_6X6;
+
+    for c in (' '..='~').chain('¡'..='¿') {
+        let glyph = FONT_6X6.glyph(c);
+
+        assert_eq!(
+            glyph.size(),
+            Size::new(FONT_6X6.glyph_width(c), FONT_6X6.character_size.height),
+            "{:?}",
+            c
+        );
+    }
+
+    let glyph = FONT_6X6.glyph('i');
+    assert_eq!(glyph.size(), Size::new(1, 6));
+    assert_eq!(
+        glyph.rows().collect::<Vec<_>>(),
+        [0b1, 0b0, 0b1, 0b1, 0b1, 0b0]
+    );
+}
@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::{BinaryColor, Rgb565, Rgb888},
+    primitives::Rectangle,
+};
+use embedded_vintage_fonts::scanline::{
+    LineBuffer, Packed1bpp, PixelFormat, Rgb565Be, Rgb565Le, Rgb888Bytes, VerticalPage,
+};
+
+#[test]
+fn pixel_formats() {
+    let mut buffer = [0; 6];
+    Packed1bpp::set_pixel(&mut buffer, 10, 0, 0, BinaryColor::On);
+    Packed1bpp::set_pixel(&mut buffer, 10, 9, 0, BinaryColor::On);
+    Packed1bpp::set_pixel(&mut buffer, 10, 2, 1, BinaryColor::On);
+    assert_eq!(buffer, [0x80, 0x40, 0x20, 0x00, 0x00, 0x00]);
+    Packed1bpp::set_pixel(&mut buffer, 10, 0, 0, BinaryColor::Off);
+    assert_eq!(buffer[0], 0x00);
+
+    let mut buffer = [0; 4];
+    VerticalPage::set_pixel(&mut buffer, 2, 1, 0, BinaryColor::On);
+    VerticalPage::set_pixel(&mut buffer, 2, 1, 7, BinaryColor::On);
+    VerticalPage::set_pixel(&mut buffer, 2, 0, 9, BinaryColor::On);
+    assert_eq!(buffer, [0x00, 0x81, 0x02, 0x00]);
+    assert_eq!(VerticalPage::buffer_size(2, 9), 4);
+
+    let mut buffer = [0; 4];
+    Rgb565Be::set_pixel(&mut buffer, 2, 1, 0, Rgb565::new(0x1f, 0, 0x01));
+    assert_eq!(buffer, [0x00, 0x00, 0xf8, 0x01]);
+    Rgb565Le::set_pixel(&mut buffer, 2, 0, 0, Rgb565::new(0x1f, 0, 0x01));
+    assert_eq!(buffer, [0x01, 0xf8, 0xf8, 0x01]);
+
+    let mut buffer = [0; 6];
+    Rgb888Bytes::set_pixel(&mut buffer, 1, 0, 1, Rgb888::new(1, 2, 3));
+    assert_eq!(buffer, [0, 0, 0, 1, 2, 3]);
+}
+
+#[test]
+fn window() {
+    let mut buffer = [0; 2];
+    let mut line = LineBuffer::<Packed1bpp>::new(&mut buffer, 16, 1);
+    line.set_left(8);
+    line.set_top(4);
+    assert_eq!(line.top(), 4);
+    assert_eq!(
+        line.bounding_box(),
+        Rectangle::new(Point::new(8, 4), Size::new(16, 1))
+    );
+
+    line.fill_solid(
+        &Rectangle::new(Point::new(0, 0), Size::new(10, 10)),
+        BinaryColor::On,
+    )
+    .unwrap();
+    assert_eq!(line.as_bytes(), [0xc0, 0x00]);
+}
+
+#[test]
+#[should_panic]
+fn buffer_too_small() {
+    let mut buffer = [0; 3];
+    LineBuffer::<Rgb565Be>::new(&mut buffer, 2, 1);
+}
@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use embedded_graphics::mono_font::mapping::{GlyphMapping, StrGlyphMapping};
+use embedded_vintage_fonts::mapping::{ASCII, CP437, CP437_GRAPHICS, ISO_8859_1};
+
+// The StrGlyphMappings the fonts used before switching to the arithmetic
+// mappings from this crate.
+const STR_ISO_8859_1: StrGlyphMapping =
+    StrGlyphMapping::new("\0\u{20}\u{7e}\0\u{a1}\u{ff}", '?' as usize - ' ' as usize);
+const STR_ASCII: StrGlyphMapping =
+    StrGlyphMapping::new("\0\u{20}\u{7e}", '?' as usize - ' ' as usize);
+
+/// Returns the characters worth comparing: everything up to the end of the
+/// CJK block plus some samples from the supplementary planes.
+fn test_chars() -> impl Iterator<Item = char> {
+    (0..=0x9fff)
+        .chain([0x1f4a3, 0x10ffff])
+        .filter_map(char::from_u32)
+}
+
+#[test]
+fn iso_8859_1_same_as_str_mapping() {
+    for c in test_chars() {
+        assert_eq!(ISO_8859_1.index(c), STR_ISO_8859_1.index(c), "{:?}", c);
+    }
+}
+
+#[test]
+fn ascii_same_as_str_mapping() {
+    for c in test_chars() {
+        assert_eq!(ASCII.index(c), STR_ASCII.index(c), "{:?}", c);
+    }
+}
+
+#[test]
+fn borders() {
+    let replacement = '?' as usize - ' ' as usize;
+
+    assert_eq!(ISO_8859_1.index('\u{1f}'), replacement);
+    assert_eq!(ISO_8859_1.index(' '), 0);
+    assert_eq!(ISO_8859_1.index('~'), 94);
+    assert_eq!(ISO_8859_1.index('\u{7f}'), replacement);
+    assert_eq!(ISO_8859_1.index('\u{a0}'), replacement);
+    assert_eq!(ISO_8859_1.index('¡'), 95);
+    assert_eq!(ISO_8859_1.index('ÿ'), 189);
+    assert_eq!(ISO_8859_1.index('Ā'), replacement);
+
+    assert_eq!(ASCII.index('~'), 94);
+    assert_eq!(ASCII.index('¡'), replacement);
+    assert_eq!(ASCII.index('ÿ'), replacement);
+}
+
+#[test]
+fn cp437_same_as_iso_8859_1_outside_graphics() {
+    for c in test_chars().filter(|c| !('\u{2500}'..='\u{259f}').contains(c)) {
+        assert_eq!(CP437.index(c), ISO_8859_1.index(c), "{:?}", c);
+    }
+}
+
+#[test]
+fn cp437_graphics() {
+    let replacement = '?' as usize - ' ' as usize;
+
+    for (i, c) in CP437_GRAPHICS.iter().enumerate() {
+        assert_eq!(CP437.index(*c), 190 + i, "{:?}", c);
+    }
+    assert_eq!(CP437.glyph_count(), 190 + CP437_GRAPHICS.len());
+
+    // Characters from the same Unicode blocks which are not part of code
+    // page 437.
+    assert_eq!(CP437.index('━'), replacement);
+    assert_eq!(CP437.index('╭'), replacement);
+    assert_eq!(CP437.index('▁'), replacement);
+    assert_eq!(CP437.index('▟'), replacement);
+}
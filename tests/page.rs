@@ -0,0 +1,130 @@
+#![cfg(all(test, any(feature = "font-6x8", feature = "font-8x16")))]
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    mono_font::{MonoFont, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::{
+    page::PageFont,
+    scanline::{LineBuffer, VerticalPage},
+};
+
+const WIDTH: u32 = 128;
+const PAGES: u32 = 8;
+
+/// Returns the buffer with `text` drawn by `MonoTextStyle` at column `x` and
+/// page `page`.
+fn expected(font: &MonoFont, text: &str, x: i32, page: i32, text_color: BinaryColor) -> Vec<u8> {
+    let mut buffer = vec![0x5a; (WIDTH * PAGES) as usize];
+    let mut line = LineBuffer::<VerticalPage>::new(&mut buffer, WIDTH, PAGES * 8);
+    line.clear(BinaryColor::Off).unwrap();
+
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(text_color)
+        .background_color(text_color.invert())
+        .build();
+    Text::with_baseline(text, Point::new(x, page * 8), style, Baseline::Top)
+        .draw(&mut line)
+        .unwrap();
+
+    buffer
+}
+
+fn check(font: &PageFont, mono_font: &MonoFont, text: &str, x: i32, page: i32) {
+    for text_color in [BinaryColor::On, BinaryColor::Off] {
+        let mut buffer = vec![0; (WIDTH * PAGES) as usize];
+        let next = font.draw_text(text, x, page, text_color, &mut buffer, WIDTH);
+
+        assert_eq!(
+            buffer,
+            expected(mono_font, text, x, page, text_color),
+            "{:?} at {}, {}",
+            text,
+            x,
+            page
+        );
+        assert_eq!(
+            next,
+            x + text.chars().count() as i32 * font.character_size.width as i32
+        );
+    }
+}
+
+/// Checks all glyphs and clipping at all edges of the buffer.
+fn check_font(font: &PageFont, mono_font: &MonoFont) {
+    assert_eq!(font.character_size, mono_font.character_size);
+
+    let chars = (' '..='~').chain('¡'..='ÿ').collect::<Vec<_>>();
+    let row_length = (WIDTH / font.character_size.width) as usize;
+    for (page, row) in chars.chunks(row_length).enumerate() {
+        let text = row.iter().collect::<String>();
+        check(font, mono_font, &text, 0, page as i32 % PAGES as i32);
+    }
+
+    for (x, page) in [
+        (-3, 0),
+        (WIDTH as i32 - 10, 2),
+        (5, -1),
+        (5, PAGES as i32 - 1),
+    ] {
+        check(font, mono_font, "Clip", x, page);
+    }
+    check(font, mono_font, "Gone", -100, 0);
+    check(font, mono_font, "Gone", WIDTH as i32, 0);
+    check(font, mono_font, "Gone", 0, PAGES as i32);
+    check(font, mono_font, "\u{2603}", 0, 0);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8() {
+    check_font(
+        &embedded_vintage_fonts::page::FONT_6X8,
+        &embedded_vintage_fonts::FONT_6X8,
+    );
+}
+
+#[test]
+#[cfg(feature = "font-8x16")]
+fn font_8x16() {
+    check_font(
+        &embedded_vintage_fonts::page::FONT_8X16,
+        &embedded_vintage_fonts::FONT_8X16,
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn glyph() {
+    let font = embedded_vintage_fonts::page::FONT_6X8;
+
+    assert_eq!(font.pages(), 1);
+    assert_eq!(font.glyph('H'), [0x7f, 0x08, 0x08, 0x08, 0x7f, 0x00]);
+    assert_eq!(font.glyph('\u{2603}'), font.glyph('?'));
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn empty_buffer() {
+    let font = embedded_vintage_fonts::page::FONT_6X8;
+
+    assert_eq!(font.draw_text("a", 0, 0, BinaryColor::On, &mut [], 0), 6);
+    assert_eq!(
+        font.draw_text("a", 0, 0, BinaryColor::On, &mut [0; 5], 6),
+        6
+    );
+}
+
+#[test]
+#[cfg(feature = "font-8x16")]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", embedded_vintage_fonts::page::FONT_8X16),
+        "PageFont { data: [u8; 3040], character_size: Size { width: 8, height: 16 }, .. }"
+    );
+}
@@ -0,0 +1,303 @@
+#![cfg(all(test, feature = "ratatui", feature = "font-6x8"))]
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyleBuilder,
+    pixelcolor::{BinaryColor, Rgb888},
+    primitives::Rectangle,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::{
+    ratatui::DrawTargetBackend,
+    terminal::{self, TerminalColor},
+    FONT_6X8,
+};
+use ratatui_core::{
+    backend::{Backend, ClearType},
+    buffer::Cell,
+    layout::{Position, Size},
+    style::{Color, Style, Stylize},
+    terminal::Terminal,
+    text::Line,
+};
+
+type TestBackend<C> = DrawTargetBackend<'static, MockDisplay<C>>;
+
+fn backend<C: TerminalColor>() -> TestBackend<C> {
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    DrawTargetBackend::new(display, &FONT_6X8)
+}
+
+fn cell(symbol: &'static str, style: Style) -> Cell {
+    let mut cell = Cell::new(symbol);
+    cell.set_style(style);
+    cell
+}
+
+/// Draws `cell` to the top left corner of a display cleared to off.
+fn draw_cell(cell: &Cell) -> MockDisplay<BinaryColor> {
+    let mut backend = backend();
+    backend.clear().unwrap();
+    backend.draw([(0, 0, cell)].into_iter()).unwrap();
+    backend.release()
+}
+
+/// Returns a display cleared to off with `text` drawn to the top left corner.
+fn expected_display(
+    text: &str,
+    text_color: BinaryColor,
+    background_color: BinaryColor,
+    underline: bool,
+    strikethrough: bool,
+) -> MockDisplay<BinaryColor> {
+    let mut builder = MonoTextStyleBuilder::new()
+        .font(&FONT_6X8)
+        .text_color(text_color)
+        .background_color(background_color);
+    if underline {
+        builder = builder.underline();
+    }
+    if strikethrough {
+        builder = builder.strikethrough();
+    }
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.clear(BinaryColor::Off).unwrap();
+    Text::with_baseline(text, Point::zero(), builder.build(), Baseline::Top)
+        .draw(&mut display)
+        .unwrap();
+    display
+}
+
+#[test]
+fn size() {
+    let mut backend = backend::<BinaryColor>();
+
+    assert_eq!(backend.size().unwrap(), Size::new(10, 8));
+    let window = backend.window_size().unwrap();
+    assert_eq!(window.columns_rows, Size::new(10, 8));
+    assert_eq!(window.pixels, Size::new(64, 64));
+}
+
+#[test]
+fn draw_plain_cell() {
+    let display = draw_cell(&Cell::new("A"));
+
+    let expected = expected_display("A", BinaryColor::On, BinaryColor::Off, false, false);
+    assert_eq!(display, expected);
+}
+
+#[test]
+fn reversed() {
+    let display = draw_cell(&cell("A", Style::new().reversed()));
+
+    let expected = expected_display("A", BinaryColor::Off, BinaryColor::On, false, false);
+    assert_eq!(display, expected);
+}
+
+#[test]
+fn hidden() {
+    let display = draw_cell(&cell("A", Style::new().hidden()));
+
+    let expected = expected_display(" ", BinaryColor::On, BinaryColor::Off, false, false);
+    assert_eq!(display, expected);
+}
+
+#[test]
+fn crossed_out() {
+    let display = draw_cell(&cell("a", Style::new().crossed_out()));
+
+    let expected = expected_display("a", BinaryColor::On, BinaryColor::Off, false, true);
+    assert_eq!(display, expected);
+}
+
+#[test]
+fn underlined() {
+    let display = draw_cell(&cell("a", Style::new().underlined()));
+
+    // The underline of the font is below the cell and gets moved into it.
+    let mut expected = expected_display("a", BinaryColor::On, BinaryColor::Off, false, false);
+    for x in 0..6 {
+        expected.set_pixel(Point::new(x, 7), Some(BinaryColor::On));
+    }
+    assert_eq!(display, expected);
+}
+
+#[test]
+fn bold_is_clipped_to_cell() {
+    let mut backend = backend::<BinaryColor>();
+    backend
+        .draw([(1, 0, &cell("I", Style::new().bold()))].into_iter())
+        .unwrap();
+    let display = backend.release();
+
+    // The vertical bar of 'I' is in the third column of the regular glyph.
+    assert_eq!(display.get_pixel(Point::new(7, 1)), Some(BinaryColor::Off));
+    assert_eq!(display.get_pixel(Point::new(8, 1)), Some(BinaryColor::On));
+    assert_eq!(display.get_pixel(Point::new(9, 1)), Some(BinaryColor::On));
+    assert_eq!(display.get_pixel(Point::new(10, 1)), Some(BinaryColor::Off));
+    assert_eq!(
+        display.affected_area(),
+        Rectangle::new(Point::new(6, 0), FONT_6X8.character_size)
+    );
+}
+
+#[test]
+fn cells_outside_are_ignored() {
+    let mut backend = backend::<BinaryColor>();
+    let cell = Cell::new("A");
+    backend
+        .draw([(10, 0, &cell), (0, 8, &cell)].into_iter())
+        .unwrap();
+
+    assert_eq!(backend.release(), MockDisplay::new());
+}
+
+#[test]
+fn colors() {
+    let mut backend = backend::<Rgb888>();
+    let cells = [
+        cell("|", Style::new()),
+        cell("|", Style::new().fg(Color::Red).bg(Color::LightBlue)),
+        cell(
+            "|",
+            Style::new().fg(Color::Indexed(196)).bg(Color::Indexed(244)),
+        ),
+        cell(
+            "|",
+            Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Indexed(9)),
+        ),
+    ];
+    backend
+        .draw(
+            cells
+                .iter()
+                .enumerate()
+                .map(|(x, cell)| (x as u16, 0, cell)),
+        )
+        .unwrap();
+    let display = backend.release();
+
+    // The top pixel of '|' in the 6 x 8 font and the pixel left to it.
+    let colors = |x: i32| {
+        (
+            display.get_pixel(Point::new(x * 6 + 2, 0)).unwrap(),
+            display.get_pixel(Point::new(x * 6 + 1, 0)).unwrap(),
+        )
+    };
+    assert_eq!(
+        colors(0),
+        (
+            terminal::Color::White.to_rgb888(),
+            terminal::Color::Black.to_rgb888()
+        )
+    );
+    assert_eq!(
+        colors(1),
+        (
+            terminal::Color::Red.to_rgb888(),
+            terminal::Color::BrightBlue.to_rgb888()
+        )
+    );
+    assert_eq!(
+        colors(2),
+        (Rgb888::new(0xff, 0x00, 0x00), Rgb888::new(0x80, 0x80, 0x80))
+    );
+    assert_eq!(
+        colors(3),
+        (Rgb888::new(1, 2, 3), terminal::Color::BrightRed.to_rgb888())
+    );
+}
+
+#[test]
+fn clear_region() {
+    let cleared = |clear_type| {
+        let mut backend = backend::<BinaryColor>();
+        backend.target_mut().clear(BinaryColor::On).unwrap();
+        backend.set_cursor_position(Position::new(3, 1)).unwrap();
+        backend.clear_region(clear_type).unwrap();
+        let display = backend.release();
+
+        // Sample the top left pixel of every cell.
+        (0..8)
+            .map(|row| {
+                (0..10)
+                    .map(
+                        |column| match display.get_pixel(Point::new(column * 6, row * 8)) {
+                            Some(BinaryColor::Off) => ' ',
+                            _ => '#',
+                        },
+                    )
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let full = "##########";
+    let empty = "          ";
+    assert_eq!(cleared(ClearType::All), [empty; 8]);
+    assert_eq!(
+        cleared(ClearType::AfterCursor)[..3],
+        [full, "###       ", empty]
+    );
+    assert_eq!(
+        cleared(ClearType::BeforeCursor)[..3],
+        [empty, "    ######", full]
+    );
+    assert_eq!(cleared(ClearType::CurrentLine)[..3], [full, empty, full]);
+    assert_eq!(
+        cleared(ClearType::UntilNewLine)[..3],
+        [full, "###       ", full]
+    );
+}
+
+#[test]
+fn cursor() {
+    let mut backend = backend::<BinaryColor>();
+    assert_eq!(backend.get_cursor_position().unwrap(), Position::ORIGIN);
+    assert!(backend.cursor_visible());
+
+    backend.set_cursor_position((4, 2)).unwrap();
+    backend.hide_cursor().unwrap();
+    assert_eq!(backend.get_cursor_position().unwrap(), Position::new(4, 2));
+    assert!(!backend.cursor_visible());
+
+    backend.show_cursor().unwrap();
+    assert!(backend.cursor_visible());
+    assert_eq!(backend.release(), MockDisplay::new());
+}
+
+#[test]
+fn terminal() {
+    let mut terminal = Terminal::new(backend::<BinaryColor>()).unwrap();
+    terminal.clear().unwrap();
+    terminal
+        .draw(|frame| {
+            frame.render_widget(
+                Line::from(vec!["Hi".into(), " you".reversed()]),
+                frame.area(),
+            )
+        })
+        .unwrap();
+
+    let mut expected = expected_display("Hi", BinaryColor::On, BinaryColor::Off, false, false);
+    Text::with_baseline(
+        " you",
+        Point::new(12, 0),
+        MonoTextStyleBuilder::new()
+            .font(&FONT_6X8)
+            .text_color(BinaryColor::Off)
+            .background_color(BinaryColor::On)
+            .build(),
+        Baseline::Top,
+    )
+    .draw(&mut expected)
+    .unwrap();
+
+    assert_eq!(terminal.backend().target(), &expected);
+}
@@ -0,0 +1,34 @@
+#![cfg(test)]
+
+use std::path::Path;
+
+#[path = "../examples/png2raw/convert.rs"]
+mod convert;
+
+/// The glyph sheets which have a PNG and a raw file in `data/`.
+const GLYPH_SHEETS: [&str; 7] = [
+    "font6x6",
+    "font6x8",
+    "font6x8_cp437",
+    "font6x12",
+    "font8x16",
+    "font8x16_cp437",
+    "font12x16",
+];
+
+#[test]
+fn raw_data_matches_png() {
+    let data = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+
+    for name in GLYPH_SHEETS {
+        let (_, converted) = convert::png_to_raw(&data.join(format!("{}.png", name))).unwrap();
+        let raw = std::fs::read(data.join(format!("{}_1bpp.raw", name))).unwrap();
+
+        assert!(
+            converted == raw,
+            "data/{}_1bpp.raw differs from data/{}.png, regenerate it with the png2raw example",
+            name,
+            name
+        );
+    }
+}
@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+use embedded_graphics::mono_font::mapping::GlyphMapping;
+use embedded_vintage_fonts::registry::{Coverage, Font, ParseFontError};
+
+/// Index of the replacement glyph '?' used for unsupported characters.
+const REPLACEMENT_INDEX: usize = '?' as usize - ' ' as usize;
+
+fn has_glyph(mapping: &dyn GlyphMapping, c: char) -> bool {
+    c == '?' || mapping.index(c) != REPLACEMENT_INDEX
+}
+
+#[test]
+fn names_round_trip() {
+    for font in Font::iter() {
+        assert_eq!(font.to_string().parse(), Ok(font));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "font-6x8", feature = "font-6x8-cp437"))]
+fn parse_name_variants() {
+    for name in ["Font6x8", "FONT_6X8", "font-6x8", " font6x8 "] {
+        assert_eq!(name.parse(), Ok(Font::Font6x8), "{:?}", name);
+    }
+    for name in ["Font6x8Cp437", "FONT_6X8_CP437", "font6x8_cp437"] {
+        assert_eq!(name.parse(), Ok(Font::Font6x8Cp437), "{:?}", name);
+    }
+}
+
+#[test]
+fn parse_unknown_name() {
+    for name in ["", "Font", "Font7x9", "Font6x8x"] {
+        assert_eq!(name.parse::<Font>(), Err(ParseFontError), "{:?}", name);
+    }
+}
+
+#[test]
+fn all_fonts_ordered_by_size() {
+    let sizes: Vec<_> = Font::iter()
+        .map(|font| {
+            let size = font.character_size();
+            (size.height, size.width)
+        })
+        .collect();
+
+    let mut sorted = sizes.clone();
+    sorted.sort();
+    assert_eq!(sizes, sorted);
+    assert_eq!(Font::iter().count(), Font::ALL.len());
+}
+
+#[test]
+fn coverage_matches_glyph_mappings() {
+    let chars = (0..=0x2fff).filter_map(char::from_u32);
+
+    for font in Font::iter() {
+        let coverage = font.coverage();
+        for c in chars.clone().filter(|c| !c.is_control()) {
+            let has_glyph = match font.mono_font() {
+                Some(mono_font) => has_glyph(mono_font.glyph_mapping, c),
+                // The glyph of '¤' shows '€' in the 6 x 6 pixel font.
+                #[cfg(feature = "font-6x6")]
+                None => c != '¤' && has_glyph(embedded_vintage_fonts::FONT_6X6.glyph_mapping, c),
+                #[cfg(not(feature = "font-6x6"))]
+                None => unreachable!(),
+            };
+            assert_eq!(coverage.contains(c), has_glyph, "{} {:?}", font, c);
+        }
+    }
+}
+
+#[test]
+fn coverage_contains_all() {
+    assert!(Coverage::Ascii.contains_all("Hello!"));
+    assert!(!Coverage::Ascii.contains_all("Grüße"));
+    assert!(Coverage::Latin1.contains_all("Grüße"));
+    assert!(!Coverage::Latin1.contains_all("╔═╗"));
+    assert!(Coverage::Cp437.contains_all("╔═╗ Grüße"));
+    assert!(Coverage::Latin1Symbols.contains_all("10 €"));
+    assert!(!Coverage::Latin1Symbols.contains_all("Grüße"));
+}
+
+#[test]
+#[cfg(all(feature = "font-6x6", feature = "font-6x12"))]
+fn text_size() {
+    use embedded_graphics::geometry::Size;
+
+    assert_eq!(Font::Font6x12.text_size(""), Size::new(0, 12));
+    assert_eq!(Font::Font6x12.text_size("abc"), Size::new(18, 12));
+    assert_eq!(Font::Font6x12.text_size("abc\nde\n"), Size::new(18, 36));
+
+    // 'i' is two pixels wide plus one pixel spacing before the next glyph.
+    let i_width = embedded_vintage_fonts::FONT_6X6.glyph_width('i');
+    assert_eq!(Font::Font6x6.text_size("ii"), Size::new(2 * i_width + 1, 6));
+}
+
+#[test]
+#[cfg(all(
+    feature = "font-6x6",
+    feature = "font-6x8",
+    feature = "font-6x8-cp437",
+    feature = "font-6x12",
+    feature = "font-8x16",
+    feature = "font-12x16",
+    feature = "font-24x32"
+))]
+fn largest_fitting() {
+    use embedded_graphics::geometry::Size;
+
+    let largest = |width, height| Font::largest_fitting("Hello", Size::new(width, height));
+
+    assert_eq!(largest(120, 32), Some(Font::Font24x32));
+    assert_eq!(largest(119, 32), Some(Font::Font12x16));
+    assert_eq!(largest(60, 31), Some(Font::Font12x16));
+    assert_eq!(largest(59, 16), Some(Font::Font8x16));
+    assert_eq!(largest(39, 15), Some(Font::Font6x12));
+    assert_eq!(largest(30, 11), Some(Font::Font6x8));
+    assert_eq!(largest(29, 7), Some(Font::Font6x6));
+    assert_eq!(largest(10, 10), None);
+    assert_eq!(
+        Font::largest_fitting("a\nb\nc", Size::new(100, 30)),
+        Some(Font::Font6x8)
+    );
+}
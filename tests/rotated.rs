@@ -0,0 +1,257 @@
+#![cfg(test)]
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    mock_display::MockDisplay,
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+};
+#[cfg(any(feature = "font-6x6", feature = "font-6x8", feature = "font-12x16"))]
+use embedded_graphics::{
+    primitives::PointsIter,
+    text::{renderer::TextRenderer, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::rotated::{Rotated, Rotation};
+
+const ROTATIONS: [Rotation; 4] = [
+    Rotation::Rotate0,
+    Rotation::Rotate90,
+    Rotation::Rotate180,
+    Rotation::Rotate270,
+];
+
+/// The position of the text in the middle of the mock display.
+#[cfg(any(feature = "font-6x6", feature = "font-6x8", feature = "font-12x16"))]
+const POSITION: Point = Point::new(32, 32);
+
+/// Returns the pixels of `display` rotated by `rotation` around `origin`.
+#[cfg(any(feature = "font-6x6", feature = "font-6x8", feature = "font-12x16"))]
+fn rotate_display(
+    display: &MockDisplay<BinaryColor>,
+    rotation: Rotation,
+    origin: Point,
+) -> MockDisplay<BinaryColor> {
+    let mut rotated = MockDisplay::new();
+    for point in display.bounding_box().points() {
+        if let Some(color) = display.get_pixel(point) {
+            rotated.set_pixel(rotation.rotate_point(point, origin), Some(color));
+        }
+    }
+
+    rotated
+}
+
+/// Checks that drawing `text` rotated results in the upright text rotated.
+#[cfg(any(feature = "font-6x6", feature = "font-6x8", feature = "font-12x16"))]
+fn check_rotated_text<S>(text: Text<'_, S>)
+where
+    S: TextRenderer<Color = BinaryColor>,
+{
+    let mut upright = MockDisplay::new();
+    upright.set_allow_overdraw(true);
+    text.draw(&mut upright).unwrap();
+    assert!(!upright.affected_area().is_zero_sized());
+
+    for rotation in ROTATIONS {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let mut target = Rotated::new(&mut display, text.position, rotation);
+        text.draw(&mut target).unwrap();
+
+        assert_eq!(
+            display,
+            rotate_display(&upright, rotation, text.position),
+            "{:?}",
+            rotation
+        );
+
+        let bounding_box = rotation.rotate_rectangle(&text.bounding_box(), text.position);
+        let affected_area = display.affected_area();
+        assert_eq!(
+            bounding_box.intersection(&affected_area),
+            affected_area,
+            "{:?}",
+            rotation
+        );
+    }
+}
+
+#[test]
+fn rotate_point() {
+    let origin = Point::new(10, 20);
+    let point = Point::new(13, 21);
+
+    assert_eq!(Rotation::Rotate0.rotate_point(point, origin), point);
+    assert_eq!(
+        Rotation::Rotate90.rotate_point(point, origin),
+        Point::new(9, 23)
+    );
+    assert_eq!(
+        Rotation::Rotate180.rotate_point(point, origin),
+        Point::new(7, 19)
+    );
+    assert_eq!(
+        Rotation::Rotate270.rotate_point(point, origin),
+        Point::new(11, 17)
+    );
+
+    for rotation in ROTATIONS {
+        assert_eq!(
+            rotation
+                .inverse()
+                .rotate_point(rotation.rotate_point(point, origin), origin),
+            point
+        );
+    }
+}
+
+#[test]
+fn rotate_rectangle() {
+    let rectangle = Rectangle::new(Point::new(2, 3), Size::new(4, 2));
+
+    assert_eq!(
+        Rotation::Rotate90.rotate_rectangle(&rectangle, Point::zero()),
+        Rectangle::new(Point::new(-4, 2), Size::new(2, 4))
+    );
+    assert_eq!(
+        Rotation::Rotate180.rotate_rectangle(&rectangle, Point::zero()),
+        Rectangle::new(Point::new(-5, -4), Size::new(4, 2))
+    );
+    assert_eq!(
+        Rotation::Rotate270.rotate_rectangle(&rectangle, Point::zero()),
+        Rectangle::new(Point::new(3, -5), Size::new(2, 4))
+    );
+    assert!(Rotation::Rotate90
+        .rotate_rectangle(&Rectangle::zero(), Point::zero())
+        .is_zero_sized());
+}
+
+#[test]
+fn bounding_box() {
+    let mut display = MockDisplay::<BinaryColor>::new();
+
+    let target = Rotated::new(&mut display, Point::new(63, 0), Rotation::Rotate90);
+    assert_eq!(
+        target.bounding_box(),
+        Rectangle::new(Point::new(63, 0), Size::new(64, 64))
+    );
+
+    let target = Rotated::new(&mut display, Point::new(10, 20), Rotation::Rotate180);
+    assert_eq!(
+        target.bounding_box(),
+        Rectangle::new(Point::new(-43, -23), Size::new(64, 64))
+    );
+}
+
+#[test]
+fn fill_solid() {
+    let mut display = MockDisplay::new();
+    let mut target = Rotated::new(&mut display, Point::new(3, 0), Rotation::Rotate90);
+
+    target
+        .fill_solid(
+            &Rectangle::new(Point::new(3, 0), Size::new(3, 2)),
+            BinaryColor::On,
+        )
+        .unwrap();
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&["  ##", "  ##", "  ##"])
+    );
+}
+
+#[test]
+fn fill_contiguous() {
+    let mut display = MockDisplay::new();
+    let mut target = Rotated::new(&mut display, Point::new(2, 2), Rotation::Rotate180);
+
+    target
+        .fill_contiguous(
+            &Rectangle::new(Point::new(1, 1), Size::new(2, 2)),
+            [
+                BinaryColor::On,
+                BinaryColor::Off,
+                BinaryColor::Off,
+                BinaryColor::Off,
+            ],
+        )
+        .unwrap();
+
+    assert_eq!(
+        display,
+        MockDisplay::from_pattern(&["    ", "    ", "  ..", "  .#"])
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn mono_text_style() {
+    use embedded_graphics::{mono_font::MonoTextStyleBuilder, text::Baseline};
+
+    let style = MonoTextStyleBuilder::new()
+        .font(&embedded_vintage_fonts::FONT_6X8)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .strikethrough()
+        .build();
+
+    for baseline in [
+        Baseline::Top,
+        Baseline::Middle,
+        Baseline::Alphabetic,
+        Baseline::Bottom,
+    ] {
+        check_rotated_text(Text::with_baseline("Hi_", POSITION, style, baseline));
+    }
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn multiple_lines() {
+    use embedded_graphics::{
+        mono_font::MonoTextStyle,
+        text::{Alignment, Baseline, TextStyleBuilder},
+    };
+
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_6X8, BinaryColor::On);
+    let text_style = TextStyleBuilder::new()
+        .alignment(Alignment::Right)
+        .baseline(Baseline::Top)
+        .build();
+
+    check_rotated_text(Text::with_text_style(
+        "Hey\nyo", POSITION, style, text_style,
+    ));
+}
+
+#[test]
+#[cfg(feature = "font-6x6")]
+fn proportional_text_style() {
+    let style = embedded_vintage_fonts::proportional::ProportionalTextStyle::new(
+        &embedded_vintage_fonts::FONT_6X6,
+        BinaryColor::On,
+    );
+
+    check_rotated_text(Text::new("Hi!", POSITION, style));
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn scaled_text_style() {
+    use embedded_graphics::mono_font::MonoTextStyleBuilder;
+
+    let style = embedded_vintage_fonts::scaled::ScaledTextStyle::new(
+        MonoTextStyleBuilder::new()
+            .font(&embedded_vintage_fonts::FONT_12X16)
+            .text_color(BinaryColor::On)
+            .underline()
+            .build(),
+        2,
+    );
+
+    check_rotated_text(Text::new("A", POSITION, style));
+}
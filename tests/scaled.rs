@@ -0,0 +1,131 @@
+#![cfg(all(test, feature = "font-12x16", feature = "font-24x32"))]
+
+use embedded_graphics::{
+    geometry::{Dimensions, Point},
+    mock_display::MockDisplay,
+    mono_font::{DecorationDimensions, MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::{scaled::ScaledTextStyle, FONT_12X16, FONT_24X32};
+
+#[test]
+fn scaled_12x16_is_24x32() -> Result<(), core::convert::Infallible> {
+    let scaled = ScaledTextStyle::new(MonoTextStyle::new(&FONT_12X16, BinaryColor::On), 2);
+    let original = MonoTextStyle::new(&FONT_24X32, BinaryColor::On);
+
+    for c in (' '..='~').chain('¡'..='ÿ') {
+        let mut buf = [0u8; 4];
+        let text = c.encode_utf8(&mut buf);
+
+        let mut scaled_display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), scaled, Baseline::Top)
+            .draw(&mut scaled_display)?;
+
+        let mut original_display = MockDisplay::new();
+        Text::with_baseline(text, Point::zero(), original, Baseline::Top)
+            .draw(&mut original_display)?;
+
+        assert_eq!(scaled_display, original_display, "{:?}", c);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn scaled_12x16_metrics_match_24x32() {
+    let scaled = ScaledTextStyle::new(MonoTextStyle::new(&FONT_12X16, BinaryColor::On), 2);
+
+    assert_eq!(scaled.character_size(), FONT_24X32.character_size);
+    assert_eq!(scaled.character_spacing(), FONT_24X32.character_spacing);
+    assert_eq!(scaled.baseline(), FONT_24X32.baseline);
+    assert_eq!(scaled.strikethrough(), FONT_24X32.strikethrough);
+
+    // The scaled underline is one pixel lower.
+    assert_eq!(scaled.underline(), DecorationDimensions::new(30, 4));
+    assert_eq!(FONT_24X32.underline, DecorationDimensions::new(29, 4));
+}
+
+#[test]
+fn scaled_12x16_with_background_and_strikethrough() -> Result<(), core::convert::Infallible> {
+    let scaled = ScaledTextStyle::new(
+        MonoTextStyleBuilder::new()
+            .font(&FONT_12X16)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .strikethrough()
+            .build(),
+        2,
+    );
+    let original = MonoTextStyleBuilder::new()
+        .font(&FONT_24X32)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .strikethrough()
+        .build();
+
+    let mut scaled_display = MockDisplay::new();
+    scaled_display.set_allow_overdraw(true);
+    let scaled_text = Text::new("$y", Point::new(5, 30), scaled);
+    scaled_text.draw(&mut scaled_display)?;
+
+    let mut original_display = MockDisplay::new();
+    original_display.set_allow_overdraw(true);
+    let original_text = Text::new("$y", Point::new(5, 30), original);
+    original_text.draw(&mut original_display)?;
+
+    assert_eq!(scaled_text.bounding_box(), original_text.bounding_box());
+    assert_eq!(scaled_display, original_display);
+
+    Ok(())
+}
+
+#[test]
+fn scaled_12x16_with_underline() -> Result<(), core::convert::Infallible> {
+    let scaled = ScaledTextStyle::new(
+        MonoTextStyleBuilder::new()
+            .font(&FONT_12X16)
+            .text_color(BinaryColor::On)
+            .underline()
+            .build(),
+        2,
+    );
+
+    // FONT_24X32 with the underline moved to the scaled offset.
+    let font = MonoFont {
+        underline: DecorationDimensions::new(30, 4),
+        ..FONT_24X32
+    };
+    let original = MonoTextStyleBuilder::new()
+        .font(&font)
+        .text_color(BinaryColor::On)
+        .underline()
+        .build();
+
+    let mut scaled_display = MockDisplay::new();
+    scaled_display.set_allow_overdraw(true);
+    let scaled_text = Text::new("$y", Point::new(5, 27), scaled);
+    scaled_text.draw(&mut scaled_display)?;
+
+    let mut original_display = MockDisplay::new();
+    original_display.set_allow_overdraw(true);
+    let original_text = Text::new("$y", Point::new(5, 27), original);
+    original_text.draw(&mut original_display)?;
+
+    assert_eq!(scaled_text.bounding_box(), original_text.bounding_box());
+    assert_eq!(scaled_display, original_display);
+
+    // Drawn with the underline of FONT_24X32, the pixels differ.
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    let style = MonoTextStyleBuilder::new()
+        .font(&FONT_24X32)
+        .text_color(BinaryColor::On)
+        .underline()
+        .build();
+    Text::new("$y", Point::new(5, 27), style).draw(&mut display)?;
+    assert_ne!(scaled_display, display);
+
+    Ok(())
+}
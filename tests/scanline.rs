@@ -0,0 +1,188 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x6",
+        feature = "font-6x8",
+        feature = "font-6x12",
+        feature = "font-8x16",
+        feature = "font-12x16"
+    )
+))]
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::Point,
+    mock_display::MockDisplay,
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::scanline::{LineBuffer, PixelFormat};
+
+const SIZE: u32 = 64;
+
+/// Renders `drawable` to a 64 x 64 pixel display with a line buffer of
+/// `rows` rows moved over it and returns the bytes sent to the display.
+fn render<F, D>(drawable: &D, rows: u32, background: F::Color) -> Vec<u8>
+where
+    F: PixelFormat,
+    D: Drawable<Color = F::Color>,
+{
+    let mut buffer = vec![0xa5; F::buffer_size(SIZE, rows)];
+    let mut line = LineBuffer::<F>::new(&mut buffer, SIZE, rows);
+
+    let mut bytes = Vec::new();
+    for top in (0..SIZE).step_by(rows as usize) {
+        line.set_top(top as i32);
+        line.clear(background).unwrap();
+        drawable.draw(&mut line).unwrap();
+        bytes.extend_from_slice(line.as_bytes());
+    }
+
+    bytes
+}
+
+/// Returns the bytes of a framebuffer with `drawable` drawn to it.
+fn expected<F, D>(drawable: &D, background: F::Color) -> Vec<u8>
+where
+    F: PixelFormat,
+    D: Drawable<Color = F::Color>,
+{
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.set_allow_out_of_bounds_drawing(true);
+    drawable.draw(&mut display).unwrap();
+
+    let mut bytes = vec![0; F::buffer_size(SIZE, SIZE)];
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let color = display
+                .get_pixel(Point::new(x as i32, y as i32))
+                .unwrap_or(background);
+            F::set_pixel(&mut bytes, SIZE, x, y, color);
+        }
+    }
+
+    bytes
+}
+
+/// Checks rendering `text` with line buffers of `rows`.
+fn check_rows<F, S>(text: &str, position: Point, style: S, background: F::Color, rows: &[u32])
+where
+    F: PixelFormat,
+    S: TextRenderer<Color = F::Color>,
+{
+    let text = Text::with_baseline(text, position, style, Baseline::Top);
+    let expected = expected::<F, _>(&text, background);
+
+    for &rows in rows {
+        assert_eq!(
+            render::<F, _>(&text, rows, background),
+            expected,
+            "{}",
+            rows
+        );
+    }
+}
+
+/// Checks rendering `text` with line buffers of single rows, pages of 8 rows
+/// and bands not dividing the text height.
+fn check<F, S>(text: &str, position: Point, style: S, background: F::Color)
+where
+    F: PixelFormat,
+    S: TextRenderer<Color = F::Color>,
+{
+    check_rows::<F, S>(text, position, style, background, &[1, 8, 16]);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn mono_packed() {
+    use embedded_graphics::{mono_font::MonoTextStyleBuilder, pixelcolor::BinaryColor};
+    use embedded_vintage_fonts::scanline::Packed1bpp;
+
+    let style = MonoTextStyleBuilder::new()
+        .font(&embedded_vintage_fonts::FONT_6X8)
+        .text_color(BinaryColor::On)
+        .underline()
+        .build();
+
+    check::<Packed1bpp, _>("Hello", Point::new(3, 5), style, BinaryColor::Off);
+    check::<Packed1bpp, _>("Hello", Point::new(-4, -3), style, BinaryColor::Off);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn mono_vertical_page() {
+    use embedded_graphics::{mono_font::MonoTextStyleBuilder, pixelcolor::BinaryColor};
+    use embedded_vintage_fonts::scanline::VerticalPage;
+
+    let style = MonoTextStyleBuilder::new()
+        .font(&embedded_vintage_fonts::FONT_6X8)
+        .text_color(BinaryColor::Off)
+        .background_color(BinaryColor::On)
+        .build();
+
+    // Vertical pages only work with line buffers of full pages.
+    check_rows::<VerticalPage, _>("Page", Point::new(1, 3), style, BinaryColor::Off, &[8, 16]);
+}
+
+#[test]
+#[cfg(feature = "font-8x16")]
+fn mono_rgb565() {
+    use embedded_graphics::{
+        mono_font::MonoTextStyleBuilder,
+        pixelcolor::{Rgb565, RgbColor},
+    };
+    use embedded_vintage_fonts::scanline::{Rgb565Be, Rgb565Le};
+
+    let style = MonoTextStyleBuilder::new()
+        .font(&embedded_vintage_fonts::FONT_8X16)
+        .text_color(Rgb565::YELLOW)
+        .background_color(Rgb565::BLUE)
+        .strikethrough_with_color(Rgb565::RED)
+        .build();
+
+    check::<Rgb565Be, _>("RGB", Point::new(2, 20), style, Rgb565::BLACK);
+    check::<Rgb565Le, _>("RGB", Point::new(2, 20), style, Rgb565::BLACK);
+}
+
+#[test]
+#[cfg(feature = "font-6x6")]
+fn proportional() {
+    use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+    use embedded_vintage_fonts::{proportional::ProportionalTextStyle, scanline::Rgb888Bytes};
+
+    let style = ProportionalTextStyle::new(&embedded_vintage_fonts::FONT_6X6, Rgb888::GREEN);
+
+    check::<Rgb888Bytes, _>("Width", Point::new(5, 9), style, Rgb888::BLACK);
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn scaled() {
+    use embedded_graphics::{mono_font::MonoTextStyle, pixelcolor::BinaryColor};
+    use embedded_vintage_fonts::{scaled::ScaledTextStyle, scanline::Packed1bpp};
+
+    let style = ScaledTextStyle::new(
+        MonoTextStyle::new(&embedded_vintage_fonts::FONT_12X16, BinaryColor::On),
+        2,
+    );
+
+    check::<Packed1bpp, _>("Ab", Point::new(0, 10), style, BinaryColor::Off);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn synthetic() {
+    use embedded_graphics::{mono_font::MonoTextStyle, pixelcolor::BinaryColor};
+    use embedded_vintage_fonts::{scanline::Packed1bpp, synthetic::SyntheticTextStyle};
+
+    let style = SyntheticTextStyle::new(MonoTextStyle::new(
+        &embedded_vintage_fonts::FONT_6X12,
+        BinaryColor::On,
+    ))
+    .bold()
+    .italic();
+
+    check::<Packed1bpp, _>("Slant", Point::new(0, 0), style, BinaryColor::Off);
+}
@@ -0,0 +1,214 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x8",
+        feature = "font-6x12",
+        feature = "font-8x16-cp437",
+        feature = "font-12x16",
+        feature = "font-24x32"
+    )
+))]
+
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    mock_display::MockDisplay,
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable, Pixel,
+};
+use embedded_vintage_fonts::span::SpanTextStyle;
+
+/// A draw target counting the calls to its methods and the pixels drawn one
+/// by one.
+#[derive(Default)]
+struct CountingDisplay {
+    display: MockDisplay<BinaryColor>,
+    pixels: usize,
+    fill_contiguous: usize,
+    fill_solid: usize,
+}
+
+impl CountingDisplay {
+    fn new() -> Self {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display.set_allow_out_of_bounds_drawing(true);
+
+        Self {
+            display,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the number of calls with pixels drawn one by one counted as a
+    /// call each.
+    #[cfg(feature = "font-24x32")]
+    fn calls(&self) -> usize {
+        self.pixels + self.fill_contiguous + self.fill_solid
+    }
+}
+
+impl OriginDimensions for CountingDisplay {
+    fn size(&self) -> Size {
+        self.display.size()
+    }
+}
+
+impl DrawTarget for CountingDisplay {
+    type Color = BinaryColor;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for pixel in pixels {
+            self.pixels += 1;
+            self.display.draw_iter([pixel])?;
+        }
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.fill_contiguous += 1;
+        self.display.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.fill_solid += 1;
+        self.display.fill_solid(area, color)
+    }
+}
+
+fn draw<S>(style: S, text: &str) -> CountingDisplay
+where
+    S: TextRenderer<Color = BinaryColor>,
+{
+    let mut display = CountingDisplay::new();
+    Text::with_baseline(text, Point::new(1, 2), style, Baseline::Top)
+        .draw(&mut display)
+        .unwrap();
+
+    display
+}
+
+/// Checks that `SpanTextStyle` draws the same pixels as `style` and returns
+/// the displays drawn with both.
+fn check(style: MonoTextStyle<'_, BinaryColor>, text: &str) -> (CountingDisplay, CountingDisplay) {
+    let mono = draw(style, text);
+    let span = draw(SpanTextStyle::new(style), text);
+
+    assert_eq!(span.display, mono.display, "{:?}", text);
+    assert_eq!(span.pixels, 0);
+    assert_eq!(span.fill_contiguous, 0);
+
+    (mono, span)
+}
+
+fn check_font(font: &MonoFont) {
+    let builder = MonoTextStyleBuilder::new().font(font);
+    let text = "Hi?\u{a7}";
+
+    check(builder.text_color(BinaryColor::On).build(), text);
+    check(
+        builder
+            .text_color(BinaryColor::Off)
+            .background_color(BinaryColor::On)
+            .underline()
+            .build(),
+        text,
+    );
+    check(
+        builder
+            .background_color(BinaryColor::On)
+            .strikethrough_with_color(BinaryColor::Off)
+            .build(),
+        text,
+    );
+    check(builder.build(), text);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8() {
+    check_font(&embedded_vintage_fonts::FONT_6X8);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn font_6x12() {
+    check_font(&embedded_vintage_fonts::FONT_6X12);
+}
+
+#[test]
+#[cfg(feature = "font-8x16-cp437")]
+fn font_8x16_cp437() {
+    check_font(&embedded_vintage_fonts::FONT_8X16_CP437);
+    check(
+        MonoTextStyle::new(&embedded_vintage_fonts::FONT_8X16_CP437, BinaryColor::On),
+        "\u{2554}\u{2550}\u{2592}",
+    );
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn font_12x16() {
+    check_font(&embedded_vintage_fonts::FONT_12X16);
+}
+
+#[test]
+#[cfg(feature = "font-24x32")]
+fn font_24x32() {
+    check_font(&embedded_vintage_fonts::FONT_24X32);
+}
+
+#[test]
+#[cfg(feature = "font-24x32")]
+fn fewer_calls() {
+    use embedded_graphics::primitives::PointsIter;
+
+    let style = MonoTextStyle::new(&embedded_vintage_fonts::FONT_24X32, BinaryColor::On);
+    let (mono, span) = check(style, "AB");
+
+    // MonoTextStyle draws every set pixel of the glyphs on its own.
+    let area = mono.display.affected_area();
+    let set_pixels = area
+        .points()
+        .filter(|&p| mono.display.get_pixel(p) == Some(BinaryColor::On))
+        .count();
+    assert_eq!(mono.pixels, set_pixels);
+    assert_eq!(mono.calls(), set_pixels);
+
+    // One call per run of set pixels instead.
+    assert!(
+        span.calls() * 4 < mono.calls(),
+        "{} {}",
+        span.calls(),
+        mono.calls()
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn measure_like_original() {
+    let style = MonoTextStyleBuilder::new()
+        .font(&embedded_vintage_fonts::FONT_6X8)
+        .text_color(BinaryColor::On)
+        .underline()
+        .build();
+
+    for baseline in [Baseline::Top, Baseline::Middle, Baseline::Alphabetic] {
+        assert_eq!(
+            SpanTextStyle::new(style).measure_string("abc", Point::new(3, 4), baseline),
+            style.measure_string("abc", Point::new(3, 4), baseline)
+        );
+    }
+    assert_eq!(SpanTextStyle::new(style).line_height(), style.line_height());
+}
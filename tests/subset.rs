@@ -0,0 +1,143 @@
+#![cfg(test)]
+
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16-cp437",
+    feature = "font-24x32"
+))]
+use embedded_graphics::{
+    geometry::{OriginDimensions, Point, Size},
+    mock_display::MockDisplay,
+    mono_font::{MonoFont, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    text::{Baseline, Text},
+    Drawable,
+};
+
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16-cp437",
+    feature = "font-24x32"
+))]
+fn draw(text: &str, font: &MonoFont) -> MockDisplay<BinaryColor> {
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .build();
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    display.set_allow_out_of_bounds_drawing(true);
+    Text::with_baseline(text, Point::new(1, 2), style, Baseline::Top)
+        .draw(&mut display)
+        .unwrap();
+
+    display
+}
+
+/// Checks that every character of `chars` renders the same with `subset` as
+/// with `font`.
+#[cfg(any(
+    feature = "font-6x8",
+    feature = "font-6x12",
+    feature = "font-8x16-cp437",
+    feature = "font-24x32"
+))]
+fn check_subset(font: &MonoFont, subset: &MonoFont, chars: &str) {
+    let count = chars.chars().count() as u32;
+    assert_eq!(
+        subset.image.size(),
+        Size::new(
+            count * font.character_size.width,
+            font.character_size.height
+        )
+    );
+
+    for c in chars.chars() {
+        let mut buf = [0u8; 4];
+        let text = c.encode_utf8(&mut buf);
+
+        assert_eq!(draw(text, subset), draw(text, font), "{:?}", c);
+    }
+}
+
+#[test]
+#[cfg(feature = "font-24x32")]
+fn font_24x32_clock() {
+    const CHARS: &str = "0123456789:APM ";
+    const CLOCK: MonoFont = embedded_vintage_fonts::vintage_subset!(FONT_24X32, CHARS);
+
+    check_subset(&embedded_vintage_fonts::FONT_24X32, &CLOCK, CHARS);
+    assert_eq!(CLOCK.baseline, embedded_vintage_fonts::FONT_24X32.baseline);
+    assert_eq!(
+        CLOCK.underline,
+        embedded_vintage_fonts::FONT_24X32.underline
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8_latin1() {
+    const CHARS: &str = "?Grüße, ¡Olé!";
+    const FONT: MonoFont = embedded_vintage_fonts::vintage_subset!(FONT_6X8, CHARS);
+
+    check_subset(&embedded_vintage_fonts::FONT_6X8, &FONT, CHARS);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn font_6x12_ascii() {
+    const CHARS: &str = "~abc XYZ";
+    const FONT: MonoFont = embedded_vintage_fonts::vintage_subset!(FONT_6X12, CHARS);
+
+    check_subset(&embedded_vintage_fonts::FONT_6X12, &FONT, CHARS);
+}
+
+#[test]
+#[cfg(feature = "font-8x16-cp437")]
+fn font_8x16_cp437_box_drawing() {
+    const CHARS: &str = "╔═╗║╚╝░▒▓█ OK";
+    const FONT: MonoFont = embedded_vintage_fonts::vintage_subset!(FONT_8X16_CP437, CHARS);
+
+    check_subset(&embedded_vintage_fonts::FONT_8X16_CP437, &FONT, CHARS);
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn replacement_glyph() {
+    const WITH_QUESTION_MARK: MonoFont = embedded_vintage_fonts::vintage_subset!(FONT_6X8, "01?");
+    const WITHOUT_QUESTION_MARK: MonoFont = embedded_vintage_fonts::vintage_subset!(FONT_6X8, "01");
+
+    // Other characters get the glyph for '?' if it is part of the subset and
+    // the one for the first character otherwise.
+    assert_eq!(
+        draw("x€", &WITH_QUESTION_MARK),
+        draw("??", &embedded_vintage_fonts::FONT_6X8)
+    );
+    assert_eq!(
+        draw("x€", &WITHOUT_QUESTION_MARK),
+        draw("00", &embedded_vintage_fonts::FONT_6X8)
+    );
+}
+
+#[test]
+fn mapping() {
+    use embedded_graphics::mono_font::mapping::GlyphMapping;
+    use embedded_vintage_fonts::subset::SubsetMapping;
+
+    let mapping = SubsetMapping::new("aäb?€");
+    assert_eq!(mapping.glyph_count(), 5);
+    assert_eq!(mapping.index('a'), 0);
+    assert_eq!(mapping.index('ä'), 1);
+    assert_eq!(mapping.index('€'), 4);
+    assert_eq!(mapping.index('c'), 3);
+
+    let mapping = SubsetMapping::new("xyz");
+    assert_eq!(mapping.glyph_count(), 3);
+    assert_eq!(mapping.index('z'), 2);
+    assert_eq!(mapping.index('?'), 0);
+}
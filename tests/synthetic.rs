@@ -0,0 +1,228 @@
+#![cfg(all(
+    test,
+    any(
+        feature = "font-6x8",
+        feature = "font-6x12",
+        feature = "font-8x16",
+        feature = "font-12x16"
+    )
+))]
+
+use embedded_graphics::{
+    geometry::{Dimensions, Point, Size},
+    mock_display::MockDisplay,
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+    text::{renderer::TextRenderer, Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::synthetic::SyntheticTextStyle;
+
+const TEXT: &str = "Hl,M";
+
+fn draw<S>(style: S, text: &str) -> MockDisplay<BinaryColor>
+where
+    S: TextRenderer<Color = BinaryColor>,
+{
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    Text::with_baseline(text, Point::zero(), style, Baseline::Top)
+        .draw(&mut display)
+        .unwrap();
+
+    display
+}
+
+/// Returns the set pixels of `display`.
+fn set_pixels(display: &MockDisplay<BinaryColor>) -> Vec<Point> {
+    display
+        .bounding_box()
+        .points()
+        .filter(|&p| display.get_pixel(p) == Some(BinaryColor::On))
+        .collect()
+}
+
+fn check_regular(font: &MonoFont) {
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .underline()
+        .strikethrough()
+        .build();
+
+    assert_eq!(
+        draw(SyntheticTextStyle::new(style), TEXT),
+        draw(style, TEXT)
+    );
+    assert_eq!(
+        SyntheticTextStyle::new(style).measure_string(TEXT, Point::zero(), Baseline::Top),
+        style.measure_string(TEXT, Point::zero(), Baseline::Top)
+    );
+}
+
+/// Checks that bold text has every pixel of the regular text set and the
+/// pixel right next to it.
+fn check_bold(font: &MonoFont) {
+    let style = MonoTextStyle::new(font, BinaryColor::On);
+    let regular = set_pixels(&draw(style, TEXT));
+    let mut expected: Vec<Point> = regular
+        .iter()
+        .flat_map(|&p| [p, p + Point::new(1, 0)])
+        .collect();
+    expected.sort_by_key(|p| (p.y, p.x));
+    expected.dedup();
+
+    assert_eq!(
+        set_pixels(&draw(SyntheticTextStyle::new(style).bold(), TEXT)),
+        expected
+    );
+}
+
+/// Checks that the bounding box of synthetic text contains all of its pixels
+/// even with a background color.
+fn check_bounding_box(font: &MonoFont) {
+    let style = MonoTextStyleBuilder::new()
+        .font(font)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off)
+        .build();
+    let synthetic = SyntheticTextStyle::new(style).bold().italic();
+    let text = Text::with_baseline(TEXT, Point::zero(), synthetic, Baseline::Top);
+
+    let extra = synthetic.extra_width();
+    assert!(extra > 1);
+    assert_eq!(
+        text.bounding_box(),
+        Rectangle::new(
+            Point::zero(),
+            Size::new(
+                TEXT.len() as u32 * font.character_size.width + extra,
+                font.character_size.height
+            )
+        )
+    );
+    assert_eq!(draw(synthetic, TEXT).affected_area(), text.bounding_box());
+
+    // The glyphs extending into the following cell do not get overdrawn by
+    // its background.
+    let mut transparent = synthetic;
+    transparent.style.background_color = None;
+    assert_eq!(
+        set_pixels(&draw(synthetic, TEXT)),
+        set_pixels(&draw(transparent, TEXT))
+    );
+
+    assert_eq!(
+        synthetic
+            .measure_string(TEXT, Point::zero(), Baseline::Top)
+            .next_position,
+        Point::new((TEXT.len() as u32 * font.character_size.width) as i32, 0)
+    );
+    assert_eq!(
+        Text::new("", Point::zero(), synthetic).bounding_box().size,
+        Size::zero()
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn italic() {
+    let style = SyntheticTextStyle::new(MonoTextStyle::new(
+        &embedded_vintage_fonts::FONT_6X8,
+        BinaryColor::On,
+    ))
+    .italic();
+
+    assert_eq!(style.extra_width(), 1);
+    assert_eq!(
+        draw(style, "l"),
+        MockDisplay::from_pattern(&["  ##", "   #", "   #", "   #", "  # ", "  # ", " ###",])
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn custom_bold_and_slant() {
+    let mut style = SyntheticTextStyle::new(MonoTextStyle::new(
+        &embedded_vintage_fonts::FONT_6X8,
+        BinaryColor::On,
+    ));
+    style.bold = 2;
+    style.slant = 2;
+
+    assert_eq!(style.extra_width(), 5);
+    assert_eq!(
+        draw(style, "."),
+        MockDisplay::from_pattern(&[
+            "      ", "      ", "      ", "      ", "      ", "  ####", " #### ",
+        ])
+    );
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn character_spacing() {
+    let font = MonoFont {
+        character_spacing: 1,
+        ..embedded_vintage_fonts::FONT_6X8
+    };
+    let mut style = SyntheticTextStyle::new(
+        MonoTextStyleBuilder::new()
+            .font(&font)
+            .text_color(BinaryColor::On)
+            .background_color(BinaryColor::Off)
+            .build(),
+    );
+    style.bold = 4;
+
+    // The first glyph extends over the spacing into the following cell.
+    let expected = MockDisplay::from_pattern(&[
+        ".................",
+        ".................",
+        ".................",
+        ".................",
+        ".................",
+        ".######.######...",
+        ".######.######...",
+        ".................",
+    ]);
+    assert_eq!(draw(style, ".."), expected);
+
+    let mut transparent = style;
+    transparent.style.background_color = None;
+    assert_eq!(set_pixels(&draw(transparent, "..")), set_pixels(&expected));
+}
+
+#[test]
+#[cfg(feature = "font-6x8")]
+fn font_6x8() {
+    check_regular(&embedded_vintage_fonts::FONT_6X8);
+    check_bold(&embedded_vintage_fonts::FONT_6X8);
+    check_bounding_box(&embedded_vintage_fonts::FONT_6X8);
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn font_6x12() {
+    check_regular(&embedded_vintage_fonts::FONT_6X12);
+    check_bold(&embedded_vintage_fonts::FONT_6X12);
+    check_bounding_box(&embedded_vintage_fonts::FONT_6X12);
+}
+
+#[test]
+#[cfg(feature = "font-8x16")]
+fn font_8x16() {
+    check_regular(&embedded_vintage_fonts::FONT_8X16);
+    check_bold(&embedded_vintage_fonts::FONT_8X16);
+    check_bounding_box(&embedded_vintage_fonts::FONT_8X16);
+}
+
+#[test]
+#[cfg(feature = "font-12x16")]
+fn font_12x16() {
+    check_regular(&embedded_vintage_fonts::FONT_12X16);
+    check_bold(&embedded_vintage_fonts::FONT_12X16);
+    check_bounding_box(&embedded_vintage_fonts::FONT_12X16);
+}
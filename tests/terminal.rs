@@ -0,0 +1,329 @@
+#![cfg(all(test, feature = "font-6x8"))]
+
+use core::fmt::Write;
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Point, Size},
+    mock_display::MockDisplay,
+    mono_font::MonoTextStyleBuilder,
+    pixelcolor::{BinaryColor, Rgb888, RgbColor},
+    primitives::Rectangle,
+    text::{Baseline, Text},
+    Drawable,
+};
+use embedded_vintage_fonts::{
+    terminal::{Cell, CellStyle, Color, Terminal, TerminalColor},
+    FONT_6X8,
+};
+
+type TestTerminal = Terminal<'static, 10, 4>;
+
+fn terminal(text: &str) -> TestTerminal {
+    let mut terminal = Terminal::new(&FONT_6X8);
+    terminal.print(text);
+    terminal
+}
+
+fn line(terminal: &TestTerminal, row: usize) -> String {
+    (0..10)
+        .map(|column| terminal.cell(column, row).c)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn lines(terminal: &TestTerminal) -> Vec<String> {
+    (0..4).map(|row| line(terminal, row)).collect()
+}
+
+fn draw<C>(terminal: &mut TestTerminal) -> MockDisplay<C>
+where
+    C: TerminalColor,
+{
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    terminal.draw(&mut display).unwrap();
+    display
+}
+
+#[test]
+fn wrap_and_scroll() {
+    let mut terminal = terminal("0123456789");
+    assert_eq!(terminal.cursor(), (10, 0));
+
+    terminal.print("ab\ncd\nef\ngh");
+    assert_eq!(lines(&terminal), ["ab", "cd", "ef", "gh"]);
+    assert_eq!(terminal.cursor(), (2, 3));
+}
+
+#[test]
+fn control_characters() {
+    let terminal = terminal("abc\rX\na\tb\nabc\u{8}\u{8}d\u{7}");
+
+    assert_eq!(lines(&terminal), ["Xbc", "a       b", "adc", ""]);
+}
+
+#[test]
+fn cursor_movement() {
+    let terminal = terminal("\x1b[3;4Ha\x1b[2Ab\x1b[5Dc\x1b[Bd\x1b[99Ce\x1b[99A\x1b[99Df");
+
+    assert_eq!(lines(&terminal), ["f   b", " d       e", "   a", ""]);
+}
+
+#[test]
+fn cursor_position_defaults() {
+    let terminal = terminal("abc\ndef\x1b[Hx\x1b[;3fy\x1b[0;0Hz");
+
+    assert_eq!(lines(&terminal), ["zby", "def", "", ""]);
+    assert_eq!(terminal.cursor(), (1, 0));
+}
+
+#[test]
+fn erase_line() {
+    let fill = "0123456789\r\n0123456789\r\n0123456789\r\n0123456789";
+    let terminal = terminal(&format!(
+        "{}\x1b[1;4H\x1b[K\x1b[2;4H\x1b[1K\x1b[3;4H\x1b[2K",
+        fill
+    ));
+
+    assert_eq!(lines(&terminal), ["012", "    456789", "", "0123456789"]);
+}
+
+#[test]
+fn erase_screen() {
+    let fill = "0123456789\r\n0123456789\r\n0123456789\r\n0123456789";
+
+    let end = terminal(&format!("{}\x1b[2;4H\x1b[J", fill));
+    assert_eq!(lines(&end), ["0123456789", "012", "", ""]);
+
+    let start = terminal(&format!("{}\x1b[2;4H\x1b[1J", fill));
+    assert_eq!(
+        lines(&start),
+        ["", "    456789", "0123456789", "0123456789"]
+    );
+
+    let all = terminal(&format!("{}\x1b[2;4H\x1b[2J", fill));
+    assert_eq!(lines(&all), ["", "", "", ""]);
+    assert_eq!(all.cursor(), (3, 1));
+}
+
+#[test]
+fn select_graphic_rendition() {
+    let terminal = terminal(
+        "\x1b[31;42ma\x1b[1;4;7mb\x1b[22;24;27mc\x1b[0md\x1b[93;104me\x1b[39;49mf\x1b[38;5;1;1mg\x1b[mh",
+    );
+
+    let style = |c: usize| terminal.cell(c, 0).style;
+    assert_eq!(
+        style(0),
+        CellStyle {
+            foreground: Color::Red,
+            background: Color::Green,
+            ..CellStyle::DEFAULT
+        }
+    );
+    assert_eq!(
+        style(1),
+        CellStyle {
+            foreground: Color::Red,
+            background: Color::Green,
+            bold: true,
+            underline: true,
+            inverse: true,
+        }
+    );
+    assert_eq!(style(2), style(0));
+    assert_eq!(style(3), CellStyle::DEFAULT);
+    assert_eq!(
+        style(4),
+        CellStyle {
+            foreground: Color::BrightYellow,
+            background: Color::BrightBlue,
+            ..CellStyle::DEFAULT
+        }
+    );
+    assert_eq!(style(5), CellStyle::DEFAULT);
+    assert_eq!(
+        style(6),
+        CellStyle {
+            bold: true,
+            ..CellStyle::DEFAULT
+        }
+    );
+    assert_eq!(style(7), CellStyle::DEFAULT);
+    assert_eq!(line(&terminal, 0), "abcdefgh");
+}
+
+#[test]
+fn erase_with_background_color() {
+    let terminal = terminal("\x1b[44;1mab\x1b[1G\x1b[K");
+
+    // The unsupported sequence `ESC [ 1 G` is ignored.
+    assert_eq!(
+        terminal.cell(2, 0),
+        Cell {
+            c: ' ',
+            style: CellStyle {
+                background: Color::Blue,
+                ..CellStyle::DEFAULT
+            }
+        }
+    );
+    assert_eq!(terminal.cell(1, 0).c, 'b');
+}
+
+#[test]
+fn ignored_sequences() {
+    let mut terminal = terminal("a\x1b[?25lb\x1b(Bc\x1b7d\x1b[");
+    terminal.print("1;2");
+    terminal.print("me");
+
+    assert_eq!(line(&terminal, 0), "abcde");
+    assert!(terminal.cell(4, 0).style.bold);
+}
+
+#[test]
+fn fmt_write() {
+    let mut terminal = terminal("");
+    write!(terminal, "\x1b[{}m{}", 35, 42).unwrap();
+
+    assert_eq!(line(&terminal, 0), "42");
+    assert_eq!(terminal.cell(1, 0).style.foreground, Color::Magenta);
+}
+
+#[test]
+fn draw_binary() {
+    let mut terminal = terminal("Hi\r\n\x1b[7mHo\x1b[0m \x1b[4m_");
+    let display = draw::<BinaryColor>(&mut terminal);
+
+    let style = MonoTextStyleBuilder::new()
+        .font(&FONT_6X8)
+        .text_color(BinaryColor::On)
+        .background_color(BinaryColor::Off);
+    let mut expected = MockDisplay::new();
+    expected.set_allow_overdraw(true);
+    expected.clear(BinaryColor::Off).unwrap();
+    Text::with_baseline("Hi", Point::zero(), style.build(), Baseline::Top)
+        .draw(&mut expected)
+        .unwrap();
+    let inverse = MonoTextStyleBuilder::new()
+        .font(&FONT_6X8)
+        .text_color(BinaryColor::Off)
+        .background_color(BinaryColor::On)
+        .build();
+    Text::with_baseline("Ho", Point::new(0, 8), inverse, Baseline::Top)
+        .draw(&mut expected)
+        .unwrap();
+    Text::with_baseline("_", Point::new(18, 8), style.build(), Baseline::Top)
+        .draw(&mut expected)
+        .unwrap();
+    // The underline of the font is below the cell and gets moved into it.
+    expected
+        .fill_solid(
+            &Rectangle::new(Point::new(18, 15), Size::new(6, 1)),
+            BinaryColor::On,
+        )
+        .unwrap();
+
+    // The mock display is larger than the 60 x 32 pixels of the terminal.
+    for y in 0..32 {
+        for x in 0..60 {
+            let point = Point::new(x, y);
+            assert_eq!(
+                display.get_pixel(point),
+                expected.get_pixel(point),
+                "{:?}",
+                point
+            );
+        }
+    }
+    assert_eq!(display.affected_area().size.width, 60);
+}
+
+#[test]
+fn draw_rgb() {
+    let mut terminal = terminal("\x1b[91;44m|");
+    let display = draw::<Rgb888>(&mut terminal);
+
+    // The top pixel of '|' in the 6 x 8 font and the pixel left to it.
+    assert_eq!(
+        display.get_pixel(Point::new(2, 0)),
+        Some(Rgb888::new(0xff, 0x55, 0x55))
+    );
+    assert_eq!(
+        display.get_pixel(Point::new(1, 0)),
+        Some(Rgb888::new(0x00, 0x00, 0xaa))
+    );
+    assert_eq!(
+        display.get_pixel(Point::new(6, 0)),
+        Some(Color::Black.to_rgb888())
+    );
+    assert_eq!(Rgb888::from_terminal(Color::BrightWhite), Rgb888::WHITE);
+}
+
+#[test]
+fn draw_changed_rows_only() {
+    let mut terminal = terminal("a\r\nb");
+    draw::<BinaryColor>(&mut terminal);
+
+    let display = draw::<BinaryColor>(&mut terminal);
+    assert_eq!(display, MockDisplay::new());
+
+    terminal.print("c");
+    let display = draw::<BinaryColor>(&mut terminal);
+    assert_eq!(display.affected_area().top_left, Point::new(0, 8));
+    assert_eq!(display.affected_area().size.height, 8);
+
+    terminal.invalidate();
+    let display = draw::<BinaryColor>(&mut terminal);
+    assert_eq!(display.affected_area().size.height, 32);
+}
+
+#[test]
+fn bold() {
+    let mut terminal = terminal("\x1b[1mI");
+    let display = draw::<BinaryColor>(&mut terminal);
+
+    // The vertical bar of 'I' is in the third column of the regular glyph.
+    assert_eq!(display.get_pixel(Point::new(1, 1)), Some(BinaryColor::Off));
+    assert_eq!(display.get_pixel(Point::new(2, 1)), Some(BinaryColor::On));
+    assert_eq!(display.get_pixel(Point::new(3, 1)), Some(BinaryColor::On));
+    assert_eq!(display.get_pixel(Point::new(4, 1)), Some(BinaryColor::Off));
+}
+
+#[test]
+#[cfg(feature = "font-6x12")]
+fn bold_overhang() {
+    use embedded_vintage_fonts::FONT_6X12;
+
+    // The top bar of 'T' in the 6 x 12 font reaches the last column of its
+    // cell, so the bold glyph extends into the following cell.
+    let mut terminal: Terminal<3, 1> = Terminal::new(&FONT_6X12);
+    terminal.print("\x1b[1mT\x1b[0m.\x1b[1mT");
+
+    let mut display = MockDisplay::new();
+    display.set_allow_overdraw(true);
+    terminal.draw(&mut display).unwrap();
+
+    // The plain '.' keeps the overhang of the bold 'T' before it.
+    assert_eq!(display.get_pixel(Point::new(5, 1)), Some(BinaryColor::On));
+    assert_eq!(display.get_pixel(Point::new(6, 1)), Some(BinaryColor::On));
+    assert_eq!(display.get_pixel(Point::new(7, 1)), Some(BinaryColor::Off));
+
+    // The overhang of the 'T' in the last column gets clipped to the grid.
+    assert_eq!(display.get_pixel(Point::new(17, 1)), Some(BinaryColor::On));
+    assert_eq!(
+        display.affected_area(),
+        Rectangle::new(Point::zero(), Size::new(18, 12))
+    );
+}
+
+#[test]
+fn reset() {
+    let mut terminal = terminal("\x1b[31mabc");
+    terminal.reset();
+    terminal.print("d");
+
+    assert_eq!(lines(&terminal), ["d", "", "", ""]);
+    assert_eq!(terminal.style(), CellStyle::DEFAULT);
+}